@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+
+// low precision positions after Meeus, good to a few arcminutes for the sun and about
+// 0.3° for the moon, plenty for planning and logging
+
+// right ascension and declination in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equatorial {
+    pub ra: f64,
+    pub dec: f64,
+}
+
+// altitude and azimuth in degrees, azimuth measured from north through east
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Horizontal {
+    pub altitude: f64,
+    pub azimuth: f64,
+}
+
+pub fn julian_day(time: &DateTime<Utc>) -> f64 {
+    time.timestamp_millis() as f64 / 86_400_000.0 + 2_440_587.5
+}
+
+// julian centuries since J2000
+fn centuries(time: &DateTime<Utc>) -> f64 {
+    (julian_day(time) - 2_451_545.0) / 36_525.0
+}
+
+fn obliquity(t: f64) -> f64 {
+    23.439_291 - 0.013_004_2 * t
+}
+
+fn ecliptic_to_equatorial(longitude: f64, latitude: f64, t: f64) -> Equatorial {
+    let (lambda, beta, epsilon) = (longitude.to_radians(), latitude.to_radians(), obliquity(t).to_radians());
+
+    let ra = (lambda.sin() * epsilon.cos() - beta.tan() * epsilon.sin()).atan2(lambda.cos());
+    let dec = (beta.sin() * epsilon.cos() + beta.cos() * epsilon.sin() * lambda.sin()).asin();
+    Equatorial {
+        ra: ra.to_degrees().rem_euclid(360.0),
+        dec: dec.to_degrees(),
+    }
+}
+
+// local mean sidereal time in degrees
+pub fn local_sidereal_time(time: &DateTime<Utc>, longitude: f64) -> f64 {
+    let d = julian_day(time) - 2_451_545.0;
+    let t = d / 36_525.0;
+    (280.460_618_37 + 360.985_647_366_29 * d + 0.000_387_933 * t * t + longitude).rem_euclid(360.0)
+}
+
+// hour angle in hours between -12 and 12, negative east of the meridian
+pub fn hour_angle(position: &Equatorial, time: &DateTime<Utc>, longitude: f64) -> f64 {
+    ((local_sidereal_time(time, longitude) - position.ra + 180.0).rem_euclid(360.0) - 180.0) / 15.0
+}
+
+pub fn to_horizontal(position: &Equatorial, time: &DateTime<Utc>, latitude: f64, longitude: f64) -> Horizontal {
+    let h = (hour_angle(position, time, longitude) * 15.0).to_radians();
+    let (phi, delta) = (latitude.to_radians(), position.dec.to_radians());
+
+    let altitude = (phi.sin() * delta.sin() + phi.cos() * delta.cos() * h.cos()).asin();
+    let azimuth = h.sin().atan2(h.cos() * phi.sin() - delta.tan() * phi.cos());
+    Horizontal {
+        altitude: altitude.to_degrees(),
+        azimuth: (azimuth.to_degrees() + 180.0).rem_euclid(360.0),
+    }
+}
+
+// angular distance in degrees
+pub fn separation(a: &Equatorial, b: &Equatorial) -> f64 {
+    let (ra1, dec1, ra2, dec2) = (a.ra.to_radians(), a.dec.to_radians(), b.ra.to_radians(), b.dec.to_radians());
+    let cos = dec1.sin() * dec2.sin() + dec1.cos() * dec2.cos() * (ra1 - ra2).cos();
+    cos.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+pub fn sun_position(time: &DateTime<Utc>) -> Equatorial {
+    let t = centuries(time);
+    let mean_longitude = 280.466_46 + 36_000.769_83 * t;
+    let g = (357.529_11 + 35_999.050_29 * t).to_radians();
+    let longitude = mean_longitude + 1.914_602 * g.sin() + 0.019_993 * (2.0 * g).sin();
+
+    ecliptic_to_equatorial(longitude, 0.0, t)
+}
+
+struct MoonArguments {
+    // mean elongation, sun and moon mean anomaly, argument of latitude in radians
+    d: f64,
+    m: f64,
+    m_moon: f64,
+    f: f64,
+}
+
+fn moon_arguments(t: f64) -> MoonArguments {
+    MoonArguments {
+        d: (297.850_2 + 445_267.111_5 * t).to_radians(),
+        m: (357.529_1 + 35_999.050_3 * t).to_radians(),
+        m_moon: (134.963_4 + 477_198.867_6 * t).to_radians(),
+        f: (93.272_1 + 483_202.017_5 * t).to_radians(),
+    }
+}
+
+// geocentric position of the moon
+pub fn moon_position(time: &DateTime<Utc>) -> Equatorial {
+    let t = centuries(time);
+    let MoonArguments { d, m, m_moon, f } = moon_arguments(t);
+
+    let longitude = 218.316_4 + 481_267.881_2 * t
+        + 6.289 * m_moon.sin()
+        + 1.274 * (2.0 * d - m_moon).sin()
+        + 0.658 * (2.0 * d).sin()
+        + 0.214 * (2.0 * m_moon).sin()
+        - 0.186 * m.sin()
+        - 0.114 * (2.0 * f).sin();
+    let latitude = 5.128 * f.sin()
+        + 0.281 * (m_moon + f).sin()
+        + 0.278 * (m_moon - f).sin()
+        + 0.173 * (2.0 * d - f).sin();
+
+    ecliptic_to_equatorial(longitude, latitude, t)
+}
+
+// horizontal parallax of the moon in degrees
+fn moon_parallax(time: &DateTime<Utc>) -> f64 {
+    let MoonArguments { d, m_moon, .. } = moon_arguments(centuries(time));
+    0.950_8 + 0.051_8 * m_moon.cos() + 0.009_5 * (2.0 * d - m_moon).cos() + 0.007_8 * (2.0 * d).cos()
+        + 0.002_8 * (2.0 * m_moon).cos()
+}
+
+// the moon as seen from the site, corrected for parallax which lowers it by up to a degree
+pub fn moon_horizontal(time: &DateTime<Utc>, latitude: f64, longitude: f64) -> Horizontal {
+    let mut horizontal = to_horizontal(&moon_position(time), time, latitude, longitude);
+    horizontal.altitude -= moon_parallax(time) * horizontal.altitude.to_radians().cos();
+    horizontal
+}
+
+// illuminated fraction of the moon disk between 0 and 1
+pub fn moon_illumination(time: &DateTime<Utc>) -> f64 {
+    let elongation = separation(&sun_position(time), &moon_position(time)).to_radians();
+    (1.0 - elongation.cos()) / 2.0
+}
+
+// relative air mass after Pickering (2002), accurate down to the horizon. none below it
+pub fn airmass(altitude: f64) -> Option<f64> {
+    if altitude <= 0.0 {
+        return None;
+    }
+    let angle = altitude + 244.0 / (165.0 + 47.0 * altitude.powf(1.1));
+    Some(1.0 / angle.to_radians().sin())
+}
+
+// mean of angles in degrees that wraps around 360, like right ascensions near 0h
+pub fn mean_angle(angles: &[f64]) -> Option<f64> {
+    if angles.is_empty() {
+        return None;
+    }
+    let (sin, cos) = angles
+        .iter()
+        .fold((0.0, 0.0), |(sin, cos), a| (sin + a.to_radians().sin(), cos + a.to_radians().cos()));
+    Some(sin.atan2(cos).to_degrees().rem_euclid(360.0))
+}
@@ -97,10 +97,12 @@ pub async fn classify_calibration_frames(
 
     // Create the directory and copy the files
     fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    let mut new_paths = vec![];
     for frame in paths {
         let mut new_path = path.clone();
         new_path.push(frame.file_name().ok_or("Couldn't get file_name")?);
         fs::copy(&frame, &new_path).map_err(|e| e.to_string())?;
+        new_paths.push(new_path.to_string_lossy().to_string());
     }
 
     if frames.calibration_type == CalibrationType::DARK {
@@ -110,7 +112,7 @@ pub async fn classify_calibration_frames(
             camera_id: Uuid::new_v4(),
             total_subs: frames.total_subs,
             gain: frames.gain,
-            frames: vec![],
+            frames: new_paths,
             calibration_type: frames.calibration_type,
             camera_temp: frames.camera_temp.unwrap(),
             sub_length: frames.sub_length.unwrap(),
@@ -125,7 +127,7 @@ pub async fn classify_calibration_frames(
             camera_id: Uuid::new_v4(),
             total_subs: frames.total_subs,
             gain: frames.gain,
-            frames: vec![],
+            frames: new_paths,
             calibration_type: frames.calibration_type,
         };
         bias_frames.insert(new_bias_frame.id, new_bias_frame);
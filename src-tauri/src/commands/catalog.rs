@@ -0,0 +1,91 @@
+use crate::file_store;
+use crate::models::catalog::{CatalogEntry, EquipmentCatalog};
+use crate::models::equipment::EquipmentType;
+use crate::models::state::AppState;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+#[tauri::command]
+pub fn search_equipment_catalog(
+    state: State<Mutex<AppState>>,
+    query: String,
+    equipment_type: Option<EquipmentType>,
+) -> Result<Vec<CatalogEntry>, String> {
+    let state = state.lock().unwrap();
+    Ok(state
+        .equipment_catalog
+        .search(&query, equipment_type)
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+// creates an equipment item from the catalog entry, overrides replace single spec values
+#[tauri::command]
+pub fn add_catalog_item(
+    state: State<Mutex<AppState>>,
+    key: String,
+    overrides: Option<Map<String, Value>>,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let path = state.preferences.storage.root_directory.clone();
+
+    let entry = state
+        .equipment_catalog
+        .entry(&key)
+        .ok_or("Catalog entry not found.")?;
+    let item = entry
+        .to_equipment(overrides.unwrap_or_default())
+        .map_err(|e| e.to_string())?;
+
+    let view_name = item.item().view_name();
+    let duplicates = state.equipment_list.duplicates(item.equipment_type(), &view_name);
+    if duplicates.iter().any(|candidate| candidate.similarity == 1.0) {
+        return Err(format!("Duplicate equipment item found: {}", view_name));
+    }
+
+    let id = *item.item().id();
+    let equipment_type = item.equipment_type();
+    state.equipment_list.insert(item);
+
+    if let Err(err) = state.equipment_list.save(&path) {
+        // Revert the change if save fails
+        state.equipment_list.remove(equipment_type, &id);
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// replaces the bundled catalog with a newer one from a local file
+#[tauri::command]
+pub fn import_equipment_catalog(
+    app_handle: AppHandle,
+    state: State<Mutex<AppState>>,
+    path: PathBuf,
+) -> Result<u32, String> {
+    let mut state = state.lock().unwrap();
+
+    let catalog: EquipmentCatalog = file_store::load(&path).map_err(|e| e.to_string())?;
+    if catalog.version <= state.equipment_catalog.version {
+        return Err(format!(
+            "Catalog version {} is not newer than the current version {}.",
+            catalog.version, state.equipment_catalog.version
+        ));
+    }
+    for entry in &catalog.entries {
+        entry
+            .to_equipment(Map::new())
+            .map_err(|e| format!("Invalid catalog entry {}: {}", entry.key, e))?;
+    }
+
+    catalog
+        .save(app_handle.path().app_data_dir().unwrap())
+        .map_err(|e| e.to_string())?;
+    let version = catalog.version;
+    state.equipment_catalog = catalog;
+
+    Ok(version)
+}
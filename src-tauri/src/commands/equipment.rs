@@ -1,54 +1,395 @@
 use std::sync::Mutex;
+use chrono::NaiveDate;
+use serde_json::Value;
 use tauri::State;
-use crate::models::equipment::{EquipmentItem, Telescope};
+use uuid::Uuid;
+use crate::models::equipment::{
+    DuplicateCandidate, Equipment, EquipmentType, HeaderAlias, MatchRule, Telescope,
+};
+use crate::models::filters::FilterAlias;
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::ingest::AmbiguousMatches;
+use crate::models::lifecycle::{EquipmentStatus, MaintenanceEntry};
+use crate::models::optics::{OpticsMetrics, SensorMetrics};
 use crate::models::state::AppState;
 
+// an item whose name only differs in case, whitespace or punctuation is a duplicate,
+// similar names are returned so they can be merged instead
 #[tauri::command]
 pub fn check_equipment_duplicate(
     state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
     view_name: String,
-) -> Result<(), String> {
+) -> Result<Vec<DuplicateCandidate>, String> {
     let state = state.lock().unwrap();
 
-    let mut equipment_items: Vec<&dyn EquipmentItem> = Vec::new();
-
-    equipment_items.extend(state.equipment_list.telescopes.values().map(|t| t as &dyn EquipmentItem));
-    equipment_items.extend(state.equipment_list.cameras.values().map(|c| c as &dyn EquipmentItem));
-    equipment_items.extend(state.equipment_list.mounts.values().map(|m| m as &dyn EquipmentItem));
-    equipment_items.extend(state.equipment_list.filters.values().map(|f| f as &dyn EquipmentItem));
-    equipment_items.extend(state.equipment_list.flatteners.values().map(|fl| fl as &dyn EquipmentItem));
+    let candidates = state.equipment_list.duplicates(equipment_type, &view_name);
 
-    if equipment_items.iter().any(|item| item.view_name() == view_name) {
+    if let Some(duplicate) = candidates.iter().find(|candidate| candidate.similarity == 1.0) {
         return Err(format!(
             "Duplicate equipment item found: {}",
-            view_name
+            duplicate.name
         ));
     }
 
-    Ok(())
+    Ok(candidates)
 }
 
 #[tauri::command]
 pub fn save_telescope(
     state: State<Mutex<AppState>>,
     telescope: Telescope,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    save_equipment(&mut state, Equipment::Telescope(telescope))
+}
+
+#[tauri::command]
+pub fn add_equipment_item(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    item: Value,
+) -> Result<(), String> {
+    let item = Equipment::from_value(equipment_type, item).map_err(|e| e.to_string())?;
+    let mut state = state.lock().unwrap();
+
+    if state.equipment_list.get(equipment_type, item.item().id()).is_some() {
+        return Err(format!("{} already exists.", item.item().view_name()));
+    }
+
+    save_equipment(&mut state, item)
+}
+
+#[tauri::command]
+pub fn update_equipment_item(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    item: Value,
+) -> Result<(), String> {
+    let item = Equipment::from_value(equipment_type, item).map_err(|e| e.to_string())?;
+    let mut state = state.lock().unwrap();
+
+    if state.equipment_list.get(equipment_type, item.item().id()).is_none() {
+        return Err(format!("{} not found.", item.item().view_name()));
+    }
+
+    save_equipment(&mut state, item)
+}
+
+// deleting an item that is still used by imaging frames requires another item to reassign them to
+#[tauri::command]
+pub fn delete_equipment_item(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    id: Uuid,
+    reassign_to: Option<Uuid>,
 ) -> Result<(), String> {
     let mut state = state.lock().unwrap();
     let path = state.preferences.storage.root_directory.clone();
 
-    let old_telescope: Option<Telescope> = state
+    let item = state
         .equipment_list
+        .get(equipment_type, &id)
+        .ok_or("Equipment item not found.")?;
+
+    let references = state.imaging_frame_list.equipment_references(equipment_type, &id);
+    let list_references = state.equipment_list.references(equipment_type, &id);
+    let old_frame_list = state.imaging_frame_list.clone();
+    let old_equipment_list = state.equipment_list.clone();
+
+    if references > 0 || list_references > 0 {
+        match reassign_to {
+            Some(to) if to != id && state.equipment_list.get(equipment_type, &to).is_some() => {
+                state.equipment_list.reassign(equipment_type, &id, &to);
+                if references > 0 {
+                    state.imaging_frame_list.reassign_equipment(equipment_type, &id, &to);
+                    ImagingFrameList::save(path.clone(), &state.imaging_frame_list).map_err(|e| {
+                        state.imaging_frame_list = old_frame_list.clone();
+                        state.equipment_list = old_equipment_list.clone();
+                        e.to_string()
+                    })?;
+                }
+            }
+            Some(_) => return Err("Equipment item to reassign to not found.".to_string()),
+            None => {
+                return Err(format!(
+                    "{} is still used by {} imaging frames and {} other equipment entries, reassign them before deleting it.",
+                    item.item().view_name(),
+                    references,
+                    list_references
+                ))
+            }
+        }
+    }
+
+    state.equipment_list.remove(equipment_type, &id);
+
+    if let Err(err) = state.equipment_list.save(&path) {
+        // Revert the change if save fails
+        state.equipment_list = old_equipment_list;
+        if references > 0 {
+            state.imaging_frame_list = old_frame_list;
+            if let Err(err) = ImagingFrameList::save(path, &state.imaging_frame_list) {
+                eprintln!("Error reverting imaging_frame_list: {}", err);
+            }
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// moves every reference from the duplicate to the kept item, which also takes over its aliases,
+// name and maintenance log, then deletes the duplicate
+#[tauri::command]
+pub fn merge_equipment_items(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    keep_id: Uuid,
+    duplicate_id: Uuid,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let path = state.preferences.storage.root_directory.clone();
+
+    if keep_id == duplicate_id {
+        return Err("Can't merge an equipment item with itself.".to_string());
+    }
+    let mut kept = state
+        .equipment_list
+        .get(equipment_type, &keep_id)
+        .ok_or("Equipment item to keep not found.")?;
+    let duplicate = state
+        .equipment_list
+        .get(equipment_type, &duplicate_id)
+        .ok_or("Duplicate equipment item not found.")?;
+
+    let duplicate = duplicate.item();
+    let mut aliases = duplicate.aliases().to_vec();
+    aliases.push(HeaderAlias {
+        pattern: duplicate.view_name(),
+        rule: MatchRule::EXACT,
+    });
+    for alias in aliases {
+        if !kept.item().aliases().contains(&alias) {
+            kept.item_mut().aliases_mut().push(alias);
+        }
+    }
+    let maintenance = &mut kept.item_mut().lifecycle_mut().maintenance;
+    maintenance.extend(duplicate.lifecycle().maintenance.iter().cloned());
+    maintenance.sort_by_key(|e| e.date);
+
+    let old_frame_list = state.imaging_frame_list.clone();
+    let old_equipment_list = state.equipment_list.clone();
+    let old_matches = state.ambiguous_matches.clone();
+
+    state.equipment_list.reassign(equipment_type, &duplicate_id, &keep_id);
+    state.equipment_list.remove(equipment_type, &duplicate_id);
+    state.equipment_list.insert(kept);
+    let references = state.imaging_frame_list.reassign_equipment(equipment_type, &duplicate_id, &keep_id);
+
+    // open matches offer the kept item instead, once
+    let mut matches_changed = false;
+    for ambiguous_match in state.ambiguous_matches.iter_mut() {
+        if ambiguous_match.equipment_type == equipment_type && ambiguous_match.candidates.contains(&duplicate_id) {
+            ambiguous_match.candidates.retain(|id| *id != duplicate_id);
+            if !ambiguous_match.candidates.contains(&keep_id) {
+                ambiguous_match.candidates.push(keep_id);
+            }
+            matches_changed = true;
+        }
+    }
+
+    if matches_changed {
+        if let Err(err) = AmbiguousMatches::save(path.clone(), &state.ambiguous_matches) {
+            // Revert the change if save fails
+            state.ambiguous_matches = old_matches;
+            state.imaging_frame_list = old_frame_list;
+            state.equipment_list = old_equipment_list;
+            return Err(err.to_string());
+        }
+    }
+
+    if references > 0 {
+        if let Err(err) = ImagingFrameList::save(path.clone(), &state.imaging_frame_list) {
+            // Revert the change if save fails
+            state.imaging_frame_list = old_frame_list;
+            state.equipment_list = old_equipment_list;
+            if matches_changed {
+                state.ambiguous_matches = old_matches;
+                if let Err(err) = AmbiguousMatches::save(path, &state.ambiguous_matches) {
+                    eprintln!("Error reverting ambiguous_matches: {}", err);
+                }
+            }
+            return Err(err.to_string());
+        }
+    }
+
+    if let Err(err) = state.equipment_list.save(&path) {
+        // Revert the change if save fails
+        state.equipment_list = old_equipment_list;
+        if references > 0 {
+            state.imaging_frame_list = old_frame_list;
+            if let Err(err) = ImagingFrameList::save(path.clone(), &state.imaging_frame_list) {
+                eprintln!("Error reverting imaging_frame_list: {}", err);
+            }
+        }
+        if matches_changed {
+            state.ambiguous_matches = old_matches;
+            if let Err(err) = AmbiguousMatches::save(path, &state.ambiguous_matches) {
+                eprintln!("Error reverting ambiguous_matches: {}", err);
+            }
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn calculate_optics(
+    state: State<Mutex<AppState>>,
+    telescope_id: Uuid,
+    flattener_id: Option<Uuid>,
+    camera_id: Option<Uuid>,
+    seeing: Option<f64>,
+) -> Result<OpticsMetrics, String> {
+    let state = state.lock().unwrap();
+    let equipment_list = &state.equipment_list;
+
+    let telescope = equipment_list
         .telescopes
-        .insert(telescope.id.clone(), telescope.clone());
+        .get(&telescope_id)
+        .ok_or("Telescope not found.")?;
+    let flattener = match flattener_id {
+        Some(id) => Some(equipment_list.flatteners.get(&id).ok_or("Flattener not found.")?),
+        None => None,
+    };
+    let camera = match camera_id {
+        Some(id) => Some(equipment_list.cameras.get(&id).ok_or("Camera not found.")?),
+        None => None,
+    };
+
+    Ok(OpticsMetrics::new(telescope, flattener, camera, seeing))
+}
+
+#[tauri::command]
+pub fn calculate_sensor_metrics(
+    state: State<Mutex<AppState>>,
+    camera_id: Uuid,
+    gain: Option<i32>,
+) -> Result<SensorMetrics, String> {
+    let state = state.lock().unwrap();
+    let camera = state.equipment_list.cameras.get(&camera_id).ok_or("Camera not found.")?;
+    Ok(SensorMetrics::new(camera, gain))
+}
+
+#[tauri::command]
+pub fn save_filter_aliases(
+    state: State<Mutex<AppState>>,
+    aliases: Vec<FilterAlias>,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let path = state.preferences.storage.root_directory.clone();
+
+    let old_aliases = std::mem::replace(&mut state.equipment_list.filter_aliases, aliases);
+
+    if let Err(err) = state.equipment_list.save(&path) {
+        // Revert the change if save fails
+        state.equipment_list.filter_aliases = old_aliases;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// retired and sold items keep resolving in old sessions but are no longer offered for new ones
+#[tauri::command]
+pub fn set_equipment_status(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    id: Uuid,
+    status: EquipmentStatus,
+    date: Option<NaiveDate>,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+
+    let mut item = state
+        .equipment_list
+        .get(equipment_type, &id)
+        .ok_or("Equipment item not found.")?;
+    let lifecycle = item.item_mut().lifecycle_mut();
+    lifecycle.status = status;
+    lifecycle.status_date = match status {
+        EquipmentStatus::ACTIVE => None,
+        _ => date,
+    };
+
+    save_equipment(&mut state, item)
+}
+
+// adds the entry or replaces the one with the same id
+#[tauri::command]
+pub fn save_maintenance_entry(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    equipment_id: Uuid,
+    entry: MaintenanceEntry,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+
+    let mut item = state
+        .equipment_list
+        .get(equipment_type, &equipment_id)
+        .ok_or("Equipment item not found.")?;
+    let maintenance = &mut item.item_mut().lifecycle_mut().maintenance;
+    match maintenance.iter_mut().find(|e| e.id == entry.id) {
+        Some(existing) => *existing = entry,
+        None => maintenance.push(entry),
+    }
+    maintenance.sort_by_key(|e| e.date);
+
+    save_equipment(&mut state, item)
+}
+
+#[tauri::command]
+pub fn delete_maintenance_entry(
+    state: State<Mutex<AppState>>,
+    equipment_type: EquipmentType,
+    equipment_id: Uuid,
+    entry_id: Uuid,
+) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+
+    let mut item = state
+        .equipment_list
+        .get(equipment_type, &equipment_id)
+        .ok_or("Equipment item not found.")?;
+    let maintenance = &mut item.item_mut().lifecycle_mut().maintenance;
+    let len = maintenance.len();
+    maintenance.retain(|e| e.id != entry_id);
+    if maintenance.len() == len {
+        return Err("Maintenance entry not found.".to_string());
+    }
+
+    save_equipment(&mut state, item)
+}
+
+// inserts the item and saves the list, reverting the change if saving fails
+fn save_equipment(state: &mut AppState, item: Equipment) -> Result<(), String> {
+    let path = state.preferences.storage.root_directory.clone();
+    let equipment_type = item.equipment_type();
+    let id = *item.item().id();
+
+    let old_item = state.equipment_list.insert(item);
 
     if let Err(err) = state.equipment_list.save(&path) {
         // Revert the change if save fails
-        match old_telescope {
+        match old_item {
             Some(old) => {
-                state.equipment_list.telescopes.insert(old.id, old);
+                state.equipment_list.insert(old);
             }
             None => {
-                state.equipment_list.telescopes.remove(&telescope.id);
+                state.equipment_list.remove(equipment_type, &id);
             }
         }
         return Err(err.to_string());
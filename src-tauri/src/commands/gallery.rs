@@ -9,10 +9,10 @@ use crate::models::state::AppState;
 // TODO: finish
 #[tauri::command]
 pub fn add_new_image(image: Image, state: State<Mutex<AppState>>) -> Result<(), String> {
-    let app_state = state.lock().unwrap();
+    // the library watcher waits for the lock, so it sees the image as known once the copy shows up
+    let mut app_state = state.lock().unwrap();
 
     let mut destination = app_state.preferences.storage.root_directory.clone();
-    drop(app_state);
     destination.push("Gallery");
     fs::create_dir_all(&destination).ok(); // TODO: log
     destination.push(String::from(&image.title) + ".png");
@@ -26,7 +26,6 @@ pub fn add_new_image(image: Image, state: State<Mutex<AppState>>) -> Result<(),
         total_exposure: 300,
     };
 
-    let mut app_state = state.lock().unwrap();
     app_state.image_list.insert(image.id, new_image);
 
     ImageList::save(
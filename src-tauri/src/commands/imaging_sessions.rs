@@ -1,11 +1,230 @@
+use crate::file_system::is_frame_file;
+use crate::image::{get_metadata, FrameMetadata};
+use crate::models::frontend::state::LogTableRow;
+use crate::models::frontend::track::TrackPoint;
+use crate::models::imaging_frames::{ImagingFrameList, LightFrame};
+use crate::models::ingest::{AmbiguousMatch, AmbiguousMatches};
+use crate::models::observing_sites::ObservingSite;
+use crate::models::state::AppState;
+use crate::night::{group_lights, group_sessions};
+use crate::weather::update_session_weather;
+use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::Mutex;
+use tauri::State;
 use uuid::Uuid;
 
+// exports one row per light frame set, or one row per imaging session if summarized
 #[tauri::command]
-pub fn export_csv(path: PathBuf) {
-    println!("{}", path.display());
-} // TODO: implement
+pub fn export_csv(
+    path: PathBuf,
+    summarized: Option<bool>,
+    state: State<Mutex<AppState>>,
+) -> Result<(), String> {
+    let app_state = state.lock().unwrap();
+
+    let mut sessions: Vec<Vec<LogTableRow>> = app_state
+        .imaging_sessions
+        .values()
+        .map(|session| LogTableRow::new(session, &app_state))
+        .filter(|rows| !rows.is_empty())
+        .collect();
+    drop(app_state);
+    sessions.sort_by_key(|rows| rows.iter().map(|r| r.date()).min());
+
+    let rows: Vec<LogTableRow> = if summarized.unwrap_or(false) {
+        sessions.iter().filter_map(|rows| LogTableRow::summarize(rows)).collect()
+    } else {
+        sessions.into_iter().flatten().collect()
+    };
+
+    let mut csv = csv_line(LogTableRow::csv_header().into_iter().map(String::from).collect());
+    for row in rows {
+        csv.push_str(&csv_line(row.csv_record()));
+    }
+
+    fs::write(path, csv).map_err(|e| e.to_string())
+}
+
+// marks subs of a light frame set as rejected or accepted, which changes its integrated subs
+#[tauri::command]
+pub fn set_subs_rejected(
+    light_frame_id: Uuid,
+    paths: Vec<String>,
+    rejected: bool,
+    state: State<Mutex<AppState>>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let light_frame = app_state
+        .imaging_frame_list
+        .light_frames
+        .get_mut(&light_frame_id)
+        .ok_or("Light frame not found")?;
+    let old_light_frame = light_frame.clone();
+
+    for sub_frame in light_frame.frames.iter_mut() {
+        if paths.contains(&sub_frame.path) {
+            sub_frame.rejected = rejected;
+        }
+    }
+    // reviewing the subs replaces the count of older logs
+    light_frame.confirm_integrated_subs();
+
+    if let Err(err) = ImagingFrameList::save(root_directory, &app_state.imaging_frame_list) {
+        // Revert the change if save fails
+        app_state.imaging_frame_list.light_frames.insert(light_frame_id, old_light_frame);
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// replaces the integrated subs of older logs by the subs of the session that aren't rejected
+#[tauri::command]
+pub fn confirm_integrated_subs(session_id: Uuid, state: State<Mutex<AppState>>) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let light_frame_ids = app_state
+        .imaging_sessions
+        .get(&session_id)
+        .ok_or("Imaging session not found")?
+        .light_frame_ids
+        .clone();
+
+    let old_frame_list = app_state.imaging_frame_list.clone();
+    for id in &light_frame_ids {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(id) {
+            light_frame.confirm_integrated_subs();
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory, &app_state.imaging_frame_list) {
+        // Revert the change if save fails
+        app_state.imaging_frame_list = old_frame_list;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+fn csv_line(values: Vec<String>) -> String {
+    let values: Vec<String> = values
+        .into_iter()
+        .map(|value| {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value
+            }
+        })
+        .collect();
+
+    values.join(",") + "\n"
+}
+
+// creates one imaging session per night, target and camera found in the folder, with a light set per filter
+#[tauri::command]
+pub async fn create_imaging_session(
+    folder: PathBuf,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<Uuid>, String> {
+    let mut files: Vec<PathBuf> = fs::read_dir(&folder)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && is_frame_file(path))
+        .collect();
+    files.sort();
+
+    // frames that are logged already are skipped, so importing a folder again only adds the new ones
+    let found = files.len();
+    {
+        let app_state = state.lock().unwrap();
+        files.retain(|path| !app_state.imaging_frame_list.contains_frame(&path.to_string_lossy()));
+    }
+    if found > 0 && files.is_empty() {
+        return Err("Every frame in the selected folder is already logged.".to_string());
+    }
+
+    // read the metadata before locking the state, skipping calibration frames
+    let mut frames = vec![];
+    let mut metadata = vec![];
+    for file in files {
+        let data: FrameMetadata = get_metadata(&file).unwrap_or_default();
+        let is_light = data
+            .image_type
+            .as_ref()
+            .map_or(true, |t| t.to_lowercase().contains("light"));
+
+        if is_light {
+            frames.push(file);
+            metadata.push(data);
+        }
+    }
+
+    if frames.is_empty() {
+        return Err("No light frames found in the selected folder.".to_string());
+    }
+
+    let mut app_state = state.lock().unwrap();
+    let mut sessions = vec![];
+    let mut ambiguous_matches: Vec<AmbiguousMatch> = vec![];
+    for sets in group_sessions(group_lights(frames, metadata)) {
+        let light_frames: Vec<LightFrame> = sets
+            .iter()
+            .map(|(frames, metadata)| LightFrame::from_metadata(frames, metadata, &app_state.equipment_list))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.to_string())?;
+        ambiguous_matches.extend(light_frames.iter().zip(&sets).flat_map(|(light_frame, (_, metadata))| {
+            AmbiguousMatch::find(light_frame, metadata, &app_state.equipment_list)
+        }));
+
+        let site_id = ObservingSite::detect(&app_state.sites, sets.iter().flat_map(|(_, metadata)| metadata));
+        sessions.push((light_frames, folder.to_string_lossy().to_string(), site_id));
+    }
+    let site_ids: Vec<Option<Uuid>> = sessions.iter().map(|(_, _, site_id)| *site_id).collect();
+
+    // the sessions are saved together, so a failure leaves none of them behind
+    let session_ids = app_state.add_imaging_sessions(sessions).map_err(|e| e.to_string())?;
+    app_state.ambiguous_matches.extend(ambiguous_matches);
+    let sited_ids: Vec<Uuid> = session_ids
+        .iter()
+        .zip(&site_ids)
+        .filter(|(_, site_id)| site_id.is_some())
+        .map(|(session_id, _)| *session_id)
+        .collect();
+
+    // the sessions already exist, unsaved matches are only lost on restart
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if let Err(err) = AmbiguousMatches::save(root_directory, &app_state.ambiguous_matches) {
+        eprintln!("Error saving ambiguous_matches: {}", err);
+    }
+    drop(app_state);
+
+    // a failed weather request doesn't stop the import, it can be fetched again later
+    for session_id in &sited_ids {
+        if let Err(err) = update_session_weather(&state, session_id) {
+            eprintln!("Error fetching weather: {}", err);
+        }
+    }
+
+    Ok(session_ids)
+}
+
+// target position and quality of every sub of the imaging session
+#[tauri::command]
+pub fn get_session_track(session_id: Uuid, state: State<Mutex<AppState>>) -> Result<Vec<TrackPoint>, String> {
+    let app_state = state.lock().unwrap();
+    let session = app_state
+        .imaging_sessions
+        .get(&session_id)
+        .ok_or("Imaging session not found")?;
+
+    Ok(TrackPoint::for_session(session, &app_state))
+}
 
 #[tauri::command]
 pub fn open_imaging_session(_id: Uuid) -> Result<(), String> {
@@ -0,0 +1,186 @@
+use crate::models::equipment::{HeaderAlias, MatchRule};
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::image::get_metadata;
+use crate::ingest::light_frame_from_group;
+use crate::models::ingest::{AmbiguousMatch, AmbiguousMatches, IngestFrameType, IngestProposals, UnclassifiedFrames};
+use crate::models::observing_sites::ObservingSite;
+use crate::models::state::AppState;
+use crate::weather::update_session_weather;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+#[tauri::command]
+pub async fn accept_ingest_proposal(state: State<'_, Mutex<AppState>>, id: Uuid) -> Result<Uuid, String> {
+    let mut group = state
+        .lock()
+        .unwrap()
+        .ingest_proposals
+        .iter()
+        .find(|g| g.id == id)
+        .ok_or("Ingest proposal not found")?
+        .clone();
+    if group.frame_type != IngestFrameType::LIGHT {
+        return Err("Calibration frames have to be classified in the calibration table.".to_string());
+    }
+
+    // proposals loaded from disk don't carry their headers, reading them is slow so do it without the lock
+    if group.metadata.len() != group.frames.len() {
+        group.metadata = group.frames.iter().map(|path| get_metadata(path).unwrap_or_default()).collect();
+    }
+
+    let mut app_state = state.lock().unwrap();
+    let index = app_state
+        .ingest_proposals
+        .iter()
+        .position(|g| g.id == id)
+        .ok_or("Ingest proposal not found")?;
+
+    let folder_dir = group.folder.to_string_lossy().to_string();
+    let light_frame = light_frame_from_group(&group, &app_state.equipment_list).map_err(|e| e.to_string())?;
+    let ambiguous_matches = AmbiguousMatch::find(&light_frame, &group.metadata, &app_state.equipment_list);
+    let site_id = ObservingSite::detect(&app_state.sites, &group.metadata);
+    let session_id = app_state
+        .add_imaging_session(vec![light_frame], folder_dir, site_id)
+        .map_err(|e| e.to_string())?;
+    app_state.ingest_proposals.remove(index);
+    app_state.ambiguous_matches.extend(ambiguous_matches);
+
+    // the session already exists, a stale proposal is only shown again after a restart
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if let Err(err) = IngestProposals::save(root_directory.clone(), &app_state.ingest_proposals) {
+        eprintln!("Error saving ingest_proposals: {}", err);
+    }
+    if let Err(err) = AmbiguousMatches::save(root_directory, &app_state.ambiguous_matches) {
+        eprintln!("Error saving ambiguous_matches: {}", err);
+    }
+    drop(app_state);
+
+    // a failed weather request doesn't stop the import, it can be fetched again later
+    if site_id.is_some() {
+        if let Err(err) = update_session_weather(&state, &session_id) {
+            eprintln!("Error fetching weather: {}", err);
+        }
+    }
+
+    Ok(session_id)
+}
+
+#[tauri::command]
+pub fn dismiss_ingest_proposal(state: State<Mutex<AppState>>, id: Uuid) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+
+    let ingest_proposals = app_state.ingest_proposals.clone();
+    app_state.ingest_proposals.retain(|g| g.id != id);
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if let Err(err) = IngestProposals::save(root_directory, &app_state.ingest_proposals) {
+        // Revert the change if save fails
+        app_state.ingest_proposals = ingest_proposals;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// settles every open match with the same header, and remembers the header as an alias if asked
+#[tauri::command]
+pub fn resolve_ambiguous_match(
+    state: State<Mutex<AppState>>,
+    id: Uuid,
+    equipment_id: Uuid,
+    remember: Option<bool>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let ambiguous_match = app_state
+        .ambiguous_matches
+        .iter()
+        .find(|m| m.id == id)
+        .ok_or("Ambiguous match not found")?
+        .clone();
+    let equipment_type = ambiguous_match.equipment_type;
+
+    let mut item = app_state
+        .equipment_list
+        .get(equipment_type, &equipment_id)
+        .ok_or("Equipment item not found.")?;
+
+    let (resolved, open): (Vec<_>, Vec<_>) = app_state.ambiguous_matches.drain(..).partition(|m| {
+        m.equipment_type == equipment_type && m.header == ambiguous_match.header
+    });
+    app_state.ambiguous_matches = open;
+
+    let old_frame_list = app_state.imaging_frame_list.clone();
+    for m in &resolved {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(&m.light_frame_id) {
+            *light_frame.equipment_id_mut(equipment_type) = equipment_id;
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+        // Revert the change if save fails
+        app_state.imaging_frame_list = old_frame_list;
+        app_state.ambiguous_matches.extend(resolved);
+        return Err(err.to_string());
+    }
+
+    // the frames are already saved, a stale match only shows up again after a restart
+    if let Err(err) = AmbiguousMatches::save(root_directory.clone(), &app_state.ambiguous_matches) {
+        eprintln!("Error saving ambiguous_matches: {}", err);
+    }
+
+    if remember.unwrap_or(false) {
+        item.item_mut().aliases_mut().push(HeaderAlias {
+            pattern: ambiguous_match.header.clone(),
+            rule: MatchRule::EXACT,
+        });
+        let old_item = app_state.equipment_list.insert(item);
+
+        if let Err(err) = app_state.equipment_list.save(&root_directory) {
+            // the frames are already saved, only the alias is lost
+            if let Some(old_item) = old_item {
+                app_state.equipment_list.insert(old_item);
+            }
+            return Err(err.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn dismiss_ambiguous_match(state: State<Mutex<AppState>>, id: Uuid) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+
+    let ambiguous_matches = app_state.ambiguous_matches.clone();
+    app_state.ambiguous_matches.retain(|m| m.id != id);
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if let Err(err) = AmbiguousMatches::save(root_directory, &app_state.ambiguous_matches) {
+        // Revert the change if save fails
+        app_state.ambiguous_matches = ambiguous_matches;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn dismiss_unclassified_frames(state: State<Mutex<AppState>>, paths: Vec<PathBuf>) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+
+    let unclassified_frames = app_state.unclassified_frames.clone();
+    app_state.unclassified_frames.retain(|path| !paths.contains(path));
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if let Err(err) = UnclassifiedFrames::save(root_directory, &app_state.unclassified_frames) {
+        // Revert the change if save fails
+        app_state.unclassified_frames = unclassified_frames;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
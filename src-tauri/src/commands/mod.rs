@@ -1,8 +1,16 @@
 pub mod calibration;
+pub mod catalog;
 pub mod gallery;
 pub mod image;
+pub mod ingest;
 pub mod imaging_sessions;
+pub mod optical_trains;
+pub mod planner;
 pub mod preferences;
+pub mod projects;
+pub mod sites;
 pub mod state;
+pub mod targets;
 pub mod utils;
+pub mod weather;
 pub mod equipment;
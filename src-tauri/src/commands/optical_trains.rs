@@ -0,0 +1,142 @@
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::optical_train::OpticalTrain;
+use crate::models::projects::ProjectList;
+use crate::models::state::AppState;
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+#[tauri::command]
+pub fn save_optical_train(
+    train: OpticalTrain,
+    state: State<Mutex<AppState>>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    if train.name.trim().is_empty() {
+        return Err("Optical train needs a name.".to_string());
+    }
+    if !train.camera_id.is_nil() && !app_state.equipment_list.cameras.contains_key(&train.camera_id) {
+        return Err("Camera not found.".to_string());
+    }
+    if !train.telescope_id.is_nil() && !app_state.equipment_list.telescopes.contains_key(&train.telescope_id) {
+        return Err("Telescope not found.".to_string());
+    }
+
+    let id = train.id;
+    let old_train = app_state.equipment_list.optical_trains.insert(id, train);
+
+    if let Err(err) = app_state.equipment_list.save(&root_directory) {
+        // Revert the change if save fails
+        match old_train {
+            Some(old) => app_state.equipment_list.optical_trains.insert(id, old),
+            None => app_state.equipment_list.optical_trains.remove(&id),
+        };
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// light frames keep their equipment and projects their sessions, they only lose the link to the train
+#[tauri::command]
+pub fn delete_optical_train(id: Uuid, state: State<Mutex<AppState>>) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let train = app_state
+        .equipment_list
+        .optical_trains
+        .remove(&id)
+        .ok_or("Optical train not found")?;
+
+    if let Err(err) = app_state.equipment_list.save(&root_directory) {
+        // Revert the change if save fails
+        app_state.equipment_list.optical_trains.insert(id, train);
+        return Err(err.to_string());
+    }
+
+    let old_frame_list = app_state.imaging_frame_list.clone();
+    for light_frame in app_state.imaging_frame_list.light_frames.values_mut() {
+        if light_frame.optical_train_id == Some(id) {
+            light_frame.optical_train_id = None;
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+        // Revert both changes if save fails
+        app_state.imaging_frame_list = old_frame_list;
+        app_state.equipment_list.optical_trains.insert(id, train);
+        if let Err(err) = app_state.equipment_list.save(&root_directory) {
+            eprintln!("Error reverting equipment_list: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    let old_projects = app_state.projects.clone();
+    let mut projects_changed = false;
+    for project in app_state.projects.values_mut() {
+        if project.optical_train_id == Some(id) {
+            project.optical_train_id = None;
+            projects_changed = true;
+        }
+    }
+
+    if projects_changed {
+        if let Err(err) = ProjectList::save(root_directory.clone(), &app_state.projects) {
+            // Revert all changes if save fails
+            app_state.projects = old_projects;
+            app_state.imaging_frame_list = old_frame_list;
+            app_state.equipment_list.optical_trains.insert(id, train);
+            if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+                eprintln!("Error reverting imaging_frame_list: {}", err);
+            }
+            if let Err(err) = app_state.equipment_list.save(&root_directory) {
+                eprintln!("Error reverting equipment_list: {}", err);
+            }
+            return Err(err.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+// uses the train for every light frame set of the imaging session
+#[tauri::command]
+pub fn apply_optical_train(
+    session_id: Uuid,
+    train_id: Uuid,
+    state: State<Mutex<AppState>>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let light_frame_ids = app_state
+        .imaging_sessions
+        .get(&session_id)
+        .ok_or("Imaging session not found")?
+        .light_frame_ids
+        .clone();
+    let train = app_state
+        .equipment_list
+        .optical_trains
+        .get(&train_id)
+        .ok_or("Optical train not found")?
+        .clone();
+
+    let old_frame_list = app_state.imaging_frame_list.clone();
+    for id in &light_frame_ids {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(id) {
+            train.apply(light_frame);
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory, &app_state.imaging_frame_list) {
+        // Revert the change if save fails
+        app_state.imaging_frame_list = old_frame_list;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::models::state::AppState;
+use crate::planner::{plan_night, NightPlan, DEFAULT_MIN_ALTITUDE};
+use chrono::{Duration, NaiveDate};
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+// targets returned per night, the curves of the whole catalog are too much for the frontend
+const MAX_TARGETS: usize = 30;
+const MAX_NIGHTS: u32 = 31;
+
+// plans the nights from the start date at a site, ranking the catalog or only the wishlist
+// against the field of view of the optical train
+#[tauri::command]
+pub fn plan_nights(
+    state: State<Mutex<AppState>>,
+    site_id: Uuid,
+    start_date: NaiveDate,
+    nights: u32,
+    optical_train_id: Option<Uuid>,
+    wishlist_only: bool,
+    min_altitude: Option<f64>,
+) -> Result<Vec<NightPlan>, String> {
+    let state = state.lock().unwrap();
+
+    if nights == 0 || nights > MAX_NIGHTS {
+        return Err(format!("Plan between 1 and {} nights.", MAX_NIGHTS));
+    }
+    let site = state.sites.get(&site_id).ok_or("Observing site not found")?;
+    let field_of_view = state.train_field_of_view(optical_train_id);
+    let min_altitude = min_altitude.unwrap_or(DEFAULT_MIN_ALTITUDE);
+
+    let objects: Vec<_> = state
+        .dso_catalog
+        .objects
+        .iter()
+        .map(|object| (object, state.wishlist.object_ids.contains(&object.id)))
+        .filter(|(_, wishlist)| *wishlist || !wishlist_only)
+        .collect();
+
+    Ok((0..nights)
+        .map(|night| {
+            let date = start_date + Duration::days(night as i64);
+            let mut plan = plan_night(site, date, objects.iter().copied(), field_of_view, min_altitude);
+            plan.targets.truncate(MAX_TARGETS);
+            plan
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn add_to_wishlist(state: State<Mutex<AppState>>, object_id: String) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let root_directory = state.preferences.storage.root_directory.clone();
+
+    let id = state
+        .dso_catalog
+        .object(&object_id)
+        .ok_or("Catalog object not found.")?
+        .id
+        .clone();
+    if state.wishlist.object_ids.contains(&id) {
+        return Ok(());
+    }
+
+    state.wishlist.object_ids.push(id);
+    if let Err(err) = state.wishlist.save(root_directory) {
+        // Revert the change if save fails
+        state.wishlist.object_ids.pop();
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_from_wishlist(state: State<Mutex<AppState>>, object_id: String) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let root_directory = state.preferences.storage.root_directory.clone();
+
+    let index = state
+        .wishlist
+        .object_ids
+        .iter()
+        .position(|id| *id == object_id)
+        .ok_or("Target is not on the wishlist.")?;
+
+    let id = state.wishlist.object_ids.remove(index);
+    if let Err(err) = state.wishlist.save(root_directory) {
+        // Revert the change if save fails
+        state.wishlist.object_ids.insert(index, id);
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
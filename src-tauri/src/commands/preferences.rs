@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use std::sync::Mutex;
 use tauri::{AppHandle, Manager, State};
 use crate::models::state::AppState;
+use crate::library_watcher::watch_root_directory;
+use crate::ingest::watch_source_directory;
 
 #[tauri::command]
 pub fn setup_backup(_path: String) {}
@@ -12,7 +14,11 @@ pub fn setup_backup(_path: String) {}
 pub fn save_preferences(preferences: Preferences, state: State<Mutex<AppState>>, app_handle: AppHandle) -> Result<(), String> {
     let mut app_state = state.lock().unwrap();
     app_state.preferences = preferences;
-    Preferences::save(app_handle.path().app_data_dir().unwrap(), &app_state.preferences).map_err(|e| e.to_string())
+    Preferences::save(app_handle.path().app_data_dir().unwrap(), &app_state.preferences).map_err(|e| e.to_string())?;
+
+    watch_source_directory(&app_handle, &mut app_state);
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -31,5 +37,9 @@ pub fn set_root_directory(app_handle: AppHandle, root_directory: PathBuf, state:
     }
 
     app_state.preferences.storage.root_directory = root_directory;
-    Preferences::save(app_handle.path().app_data_dir().unwrap(), &app_state.preferences).map_err(|e| e.to_string())
+    Preferences::save(app_handle.path().app_data_dir().unwrap(), &app_state.preferences).map_err(|e| e.to_string())?;
+
+    watch_root_directory(&app_handle, &mut app_state);
+
+    Ok(())
 }
@@ -0,0 +1,134 @@
+use crate::models::imaging_frames::LightFrame;
+use crate::models::projects::{ImagingProject, ProjectList};
+use crate::models::state::AppState;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+// creates or updates a project, projects with automatic assignment collect the matching
+// sessions that are in no other project yet
+#[tauri::command]
+pub fn save_project(state: State<Mutex<AppState>>, mut project: ImagingProject) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    if project.name.trim().is_empty() || project.target.trim().is_empty() {
+        return Err("Project needs a name and a target.".to_string());
+    }
+    if project.goals.iter().any(|goal| goal.hours <= 0.0) {
+        return Err("Integration goals need a positive number of hours.".to_string());
+    }
+    let filter_types: HashSet<_> = project.goals.iter().map(|goal| goal.filter_type).collect();
+    if filter_types.len() < project.goals.len() {
+        return Err("Each filter can only have one integration goal.".to_string());
+    }
+    if project
+        .optical_train_id
+        .is_some_and(|id| !app_state.equipment_list.optical_trains.contains_key(&id))
+    {
+        return Err("Optical train not found.".to_string());
+    }
+    // a session belongs to one project at most
+    if project.session_ids.iter().any(|id| !app_state.imaging_sessions.contains_key(id)) {
+        return Err("Imaging session not found.".to_string());
+    }
+    if app_state
+        .projects
+        .values()
+        .filter(|other| other.id != project.id)
+        .any(|other| other.session_ids.iter().any(|id| project.session_ids.contains(id)))
+    {
+        return Err("An imaging session already belongs to another project.".to_string());
+    }
+
+    if project.auto_assign {
+        let assigned: HashSet<Uuid> = app_state
+            .projects
+            .values()
+            .filter(|other| other.id != project.id)
+            .flat_map(|other| other.session_ids.iter().copied())
+            .chain(project.session_ids.iter().copied())
+            .collect();
+        let mut matching: Vec<Uuid> = app_state
+            .imaging_sessions
+            .values()
+            .filter(|session| !assigned.contains(&session.id))
+            .filter(|session| {
+                let light_frames: Vec<&LightFrame> = session
+                    .light_frame_ids
+                    .iter()
+                    .filter_map(|id| app_state.imaging_frame_list.light_frames.get(id))
+                    .collect();
+                project.matches(light_frames)
+            })
+            .map(|session| session.id)
+            .collect();
+        project.session_ids.append(&mut matching);
+    }
+
+    let id = project.id;
+    let old_project = app_state.projects.insert(id, project);
+
+    if let Err(err) = ProjectList::save(root_directory, &app_state.projects) {
+        // Revert the change if save fails
+        match old_project {
+            Some(old) => app_state.projects.insert(id, old),
+            None => app_state.projects.remove(&id),
+        };
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// the sessions stay in the log, they only leave the project
+#[tauri::command]
+pub fn delete_project(state: State<Mutex<AppState>>, id: Uuid) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let project = app_state.projects.remove(&id).ok_or("Project not found")?;
+
+    if let Err(err) = ProjectList::save(root_directory, &app_state.projects) {
+        // Revert the change if save fails
+        app_state.projects.insert(id, project);
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// moves a session to a project by hand, none removes it from its project
+#[tauri::command]
+pub fn set_session_project(
+    state: State<Mutex<AppState>>,
+    session_id: Uuid,
+    project_id: Option<Uuid>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    if !app_state.imaging_sessions.contains_key(&session_id) {
+        return Err("Imaging session not found".to_string());
+    }
+    if project_id.is_some_and(|id| !app_state.projects.contains_key(&id)) {
+        return Err("Project not found".to_string());
+    }
+
+    let old_projects = app_state.projects.clone();
+    for project in app_state.projects.values_mut() {
+        project.session_ids.retain(|id| *id != session_id);
+        if Some(project.id) == project_id {
+            project.session_ids.push(session_id);
+        }
+    }
+
+    if let Err(err) = ProjectList::save(root_directory, &app_state.projects) {
+        // Revert the change if save fails
+        app_state.projects = old_projects;
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::imaging_session_list::ImagingSessionList;
+use crate::models::observing_sites::{ObservingSite, SiteList};
+use crate::models::state::AppState;
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+#[tauri::command]
+pub fn save_site(site: ObservingSite, state: State<Mutex<AppState>>) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    if site.name.trim().is_empty() {
+        return Err("Observing site needs a name.".to_string());
+    }
+    if !(-90.0..=90.0).contains(&site.latitude) || !(-180.0..=180.0).contains(&site.longitude) {
+        return Err("Latitude or longitude out of range.".to_string());
+    }
+
+    let id = site.id;
+    let moved = app_state
+        .sites
+        .get(&id)
+        .is_some_and(|old| old.latitude != site.latitude || old.longitude != site.longitude);
+    let old_site = app_state.sites.insert(id, site.clone());
+
+    if let Err(err) = SiteList::save(root_directory.clone(), &app_state.sites) {
+        // Revert the change if save fails
+        match old_site {
+            Some(old) => app_state.sites.insert(id, old),
+            None => app_state.sites.remove(&id),
+        };
+        return Err(err.to_string());
+    }
+
+    if !moved {
+        return Ok(());
+    }
+
+    // the moon and target positions of the linked sessions move with the site
+    let old_frames = app_state.imaging_frame_list.light_frames.clone();
+    for light_frame_id in site_light_frame_ids(&app_state, &id) {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(&light_frame_id) {
+            light_frame.update_sky(&site);
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+        // Revert both changes if save fails
+        app_state.imaging_frame_list.light_frames = old_frames;
+        if let Some(old) = old_site {
+            app_state.sites.insert(id, old);
+        }
+        if let Err(err) = SiteList::save(root_directory, &app_state.sites) {
+            eprintln!("Error reverting site_list: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// light frames of the sessions linked to the site
+fn site_light_frame_ids(app_state: &AppState, site_id: &Uuid) -> Vec<Uuid> {
+    app_state
+        .imaging_sessions
+        .values()
+        .filter(|session| session.site_id.as_ref() == Some(site_id))
+        .flat_map(|session| session.light_frame_ids.iter().copied())
+        .collect()
+}
+
+// imaging sessions keep their data, they only lose the link to the site
+#[tauri::command]
+pub fn delete_site(id: Uuid, state: State<Mutex<AppState>>) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    let site = app_state.sites.remove(&id).ok_or("Observing site not found")?;
+
+    if let Err(err) = SiteList::save(root_directory.clone(), &app_state.sites) {
+        // Revert the change if save fails
+        app_state.sites.insert(id, site);
+        return Err(err.to_string());
+    }
+
+    let light_frame_ids = site_light_frame_ids(&app_state, &id);
+    let old_sessions = app_state.imaging_sessions.clone();
+    for session in app_state.imaging_sessions.values_mut() {
+        if session.site_id == Some(id) {
+            session.site_id = None;
+        }
+    }
+
+    if let Err(err) = ImagingSessionList::save(root_directory.clone(), &app_state.imaging_sessions) {
+        // Revert both changes if save fails
+        app_state.imaging_sessions = old_sessions;
+        app_state.sites.insert(id, site);
+        if let Err(err) = SiteList::save(root_directory, &app_state.sites) {
+            eprintln!("Error reverting site_list: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    // without a site the moon and target positions are unknown
+    let old_frames = app_state.imaging_frame_list.light_frames.clone();
+    for light_frame_id in &light_frame_ids {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(light_frame_id) {
+            light_frame.clear_sky();
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+        // Revert all changes if save fails
+        app_state.imaging_frame_list.light_frames = old_frames;
+        app_state.imaging_sessions = old_sessions;
+        app_state.sites.insert(id, site);
+        if let Err(err) = ImagingSessionList::save(root_directory.clone(), &app_state.imaging_sessions) {
+            eprintln!("Error reverting imaging_session_list: {}", err);
+        }
+        if let Err(err) = SiteList::save(root_directory, &app_state.sites) {
+            eprintln!("Error reverting site_list: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_session_site(
+    session_id: Uuid,
+    site_id: Option<Uuid>,
+    state: State<Mutex<AppState>>,
+) -> Result<(), String> {
+    let mut app_state = state.lock().unwrap();
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    if site_id.is_some_and(|id| !app_state.sites.contains_key(&id)) {
+        return Err("Observing site not found".to_string());
+    }
+    let session = app_state
+        .imaging_sessions
+        .get_mut(&session_id)
+        .ok_or("Imaging session not found")?;
+    let old_site_id = std::mem::replace(&mut session.site_id, site_id);
+
+    if let Err(err) = ImagingSessionList::save(root_directory.clone(), &app_state.imaging_sessions) {
+        // Revert the change if save fails
+        if let Some(session) = app_state.imaging_sessions.get_mut(&session_id) {
+            session.site_id = old_site_id;
+        }
+        return Err(err.to_string());
+    }
+
+    // the moon and target positions depend on where the subs were taken
+    let site = site_id.and_then(|id| app_state.sites.get(&id)).cloned();
+    let old_frames = app_state.imaging_frame_list.light_frames.clone();
+    let light_frame_ids = app_state.imaging_sessions[&session_id].light_frame_ids.clone();
+    for id in &light_frame_ids {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(id) {
+            match &site {
+                Some(site) => light_frame.update_sky(site),
+                None => light_frame.clear_sky(),
+            }
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+        // Revert both changes if save fails
+        app_state.imaging_frame_list.light_frames = old_frames;
+        if let Some(session) = app_state.imaging_sessions.get_mut(&session_id) {
+            session.site_id = old_site_id;
+        }
+        if let Err(err) = ImagingSessionList::save(root_directory, &app_state.imaging_sessions) {
+            eprintln!("Error reverting imaging_session_list: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
+use std::path::PathBuf;
 use std::sync::Mutex;
 use tauri::State;
 use crate::models::frontend::analytics::Analytics;
+use crate::models::frontend::projects::ProjectProgress;
 use crate::models::frontend::state::{
     CalibrationTableRow, EquipmentList, FrontendAppState, LogTableRow, TableData,
 };
 use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::observing_sites::ObservingSite;
+use crate::models::projects::ImagingProject;
 use crate::models::state::AppState;
 
 #[tauri::command]
@@ -23,7 +27,7 @@ pub fn load_frontend_app_state(state: State<Mutex<AppState>>) -> Result<String,
     let sessions_data: Vec<LogTableRow> = app_state
         .imaging_sessions
         .iter()
-        .filter_map(|i| LogTableRow::new(i.1, &app_state))
+        .flat_map(|i| LogTableRow::new(i.1, &app_state))
         .collect();
 
     let table_data = TableData {
@@ -46,6 +50,20 @@ pub fn load_frontend_app_state(state: State<Mutex<AppState>>) -> Result<String,
         .cloned()
         .collect();
     let mount_list = app_state.equipment_list.mounts.values().cloned().collect();
+    let filter_wheel_list = app_state.equipment_list.filter_wheels.values().cloned().collect();
+    let focuser_list = app_state.equipment_list.focusers.values().cloned().collect();
+    let guide_scope_list = app_state.equipment_list.guide_scopes.values().cloned().collect();
+    let guide_camera_list = app_state.equipment_list.guide_cameras.values().cloned().collect();
+    let off_axis_guider_list = app_state.equipment_list.off_axis_guiders.values().cloned().collect();
+    let rotator_list = app_state.equipment_list.rotators.values().cloned().collect();
+    let dew_heater_list = app_state.equipment_list.dew_heaters.values().cloned().collect();
+    let field_rotator_list = app_state.equipment_list.field_rotators.values().cloned().collect();
+    let optical_train_list = app_state
+        .equipment_list
+        .optical_trains
+        .values()
+        .cloned()
+        .collect();
 
     let equipment_list = EquipmentList {
         telescope_list,
@@ -53,9 +71,38 @@ pub fn load_frontend_app_state(state: State<Mutex<AppState>>) -> Result<String,
         mount_list,
         filter_list,
         flattener_list,
+        filter_wheel_list,
+        focuser_list,
+        guide_scope_list,
+        guide_camera_list,
+        off_axis_guider_list,
+        rotator_list,
+        dew_heater_list,
+        field_rotator_list,
+        optical_train_list,
+        filter_aliases: app_state.equipment_list.filter_aliases.clone(),
     };
 
-    let analytics = Analytics::new();
+    let analytics = Analytics::new(&app_state);
+
+    // files imported since they were picked up by the watcher are no longer unclassified
+    let unclassified_frames: Vec<PathBuf> = app_state
+        .unclassified_frames
+        .iter()
+        .filter(|path| !app_state.imaging_frame_list.contains_frame(&path.to_string_lossy()))
+        .cloned()
+        .collect();
+    let ingest_proposals = app_state.ingest_proposals.clone();
+    let ambiguous_matches = app_state.ambiguous_matches.clone();
+    let mut sites: Vec<ObservingSite> = app_state.sites.values().cloned().collect();
+    sites.sort_by(|a, b| a.name.cmp(&b.name));
+    let wishlist = app_state.wishlist.object_ids.clone();
+    let mut projects: Vec<&ImagingProject> = app_state.projects.values().collect();
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+    let projects: Vec<ProjectProgress> = projects
+        .into_iter()
+        .map(|project| ProjectProgress::new(project, &app_state))
+        .collect();
 
     let data = FrontendAppState {
         preferences,
@@ -63,6 +110,12 @@ pub fn load_frontend_app_state(state: State<Mutex<AppState>>) -> Result<String,
         equipment_list,
         image_list,
         analytics,
+        unclassified_frames,
+        ingest_proposals,
+        ambiguous_matches,
+        sites,
+        wishlist,
+        projects,
     };
 
     serde_json::to_string(&data).map_err(|e| e.to_string())
@@ -0,0 +1,134 @@
+use crate::file_store;
+use crate::models::dso_catalog::{designation_key, DeepSkyObject, DsoCatalog};
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::state::AppState;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+#[tauri::command]
+pub fn search_dso_catalog(state: State<Mutex<AppState>>, query: String) -> Result<Vec<DeepSkyObject>, String> {
+    let state = state.lock().unwrap();
+    Ok(state
+        .dso_catalog
+        .search(&query)
+        .into_iter()
+        .take(50)
+        .cloned()
+        .collect())
+}
+
+// links a target name to a catalog object, for the logged light frames and future imports
+#[tauri::command]
+pub fn set_target_object(state: State<Mutex<AppState>>, target: String, object_id: String) -> Result<(), String> {
+    let mut state = state.lock().unwrap();
+    let root_directory = state.preferences.storage.root_directory.clone();
+
+    let object = state
+        .dso_catalog
+        .object(&object_id)
+        .ok_or("Catalog object not found.")?
+        .clone();
+    let key = designation_key(&target);
+
+    let old_alias = state.target_aliases.aliases.insert(key.clone(), object.id.clone());
+    if let Err(err) = state.target_aliases.save(root_directory.clone()) {
+        // Revert the change if save fails
+        match old_alias {
+            Some(old) => state.target_aliases.aliases.insert(key, old),
+            None => state.target_aliases.aliases.remove(&key),
+        };
+        return Err(err.to_string());
+    }
+
+    let old_frames = state.imaging_frame_list.light_frames.clone();
+    let mut light_frames = old_frames.clone();
+    for light_frame in light_frames.values_mut() {
+        if designation_key(&light_frame.target) == key {
+            light_frame.target = object.id.clone();
+            light_frame.ra = light_frame.ra.or(Some(object.ra));
+            light_frame.dec = light_frame.dec.or(Some(object.dec));
+            if let Some(site) = state.site_of(&light_frame.id) {
+                light_frame.update_sky(site);
+            }
+        }
+    }
+    state.imaging_frame_list.light_frames = light_frames;
+
+    if let Err(err) = ImagingFrameList::save(root_directory.clone(), &state.imaging_frame_list) {
+        // Revert both changes if save fails
+        state.imaging_frame_list.light_frames = old_frames;
+        match old_alias {
+            Some(old) => state.target_aliases.aliases.insert(key, old),
+            None => state.target_aliases.aliases.remove(&key),
+        };
+        if let Err(err) = state.target_aliases.save(root_directory) {
+            eprintln!("Error reverting target_aliases: {}", err);
+        }
+        return Err(err.to_string());
+    }
+
+    Ok(())
+}
+
+// renames the targets of all logged light frames to their catalog designation,
+// returns the number of changed light frames
+#[tauri::command]
+pub fn normalize_targets(state: State<Mutex<AppState>>) -> Result<u32, String> {
+    let mut state = state.lock().unwrap();
+    let root_directory = state.preferences.storage.root_directory.clone();
+
+    let old_frames = state.imaging_frame_list.light_frames.clone();
+    let mut light_frames = old_frames.clone();
+    let mut changed = 0;
+    for light_frame in light_frames.values_mut() {
+        if state.normalize_target(light_frame, None) {
+            // the coordinates may be new
+            if let Some(site) = state.site_of(&light_frame.id) {
+                light_frame.update_sky(site);
+            }
+            changed += 1;
+        }
+    }
+    if changed == 0 {
+        return Ok(0);
+    }
+    state.imaging_frame_list.light_frames = light_frames;
+
+    if let Err(err) = ImagingFrameList::save(root_directory, &state.imaging_frame_list) {
+        // Revert the change if save fails
+        state.imaging_frame_list.light_frames = old_frames;
+        return Err(err.to_string());
+    }
+
+    Ok(changed as u32)
+}
+
+// replaces the bundled catalog with a newer one from a local file. the bundled catalog has every Messier and
+// Caldwell object and the popular NGC, IC and Sh2 targets, full NGC/IC/Sh2 coverage comes from importing an
+// export of those catalogs in the same format. the import replaces the bundled objects, so it should list the
+// Messier and Caldwell numbers as aliases and carry a higher version
+#[tauri::command]
+pub fn import_dso_catalog(
+    app_handle: AppHandle,
+    state: State<Mutex<AppState>>,
+    path: PathBuf,
+) -> Result<u32, String> {
+    let mut state = state.lock().unwrap();
+
+    let catalog: DsoCatalog = file_store::load(&path).map_err(|e| e.to_string())?;
+    if catalog.version <= state.dso_catalog.version {
+        return Err(format!(
+            "Catalog version {} is not newer than the current version {}.",
+            catalog.version, state.dso_catalog.version
+        ));
+    }
+
+    catalog
+        .save(app_handle.path().app_data_dir().unwrap())
+        .map_err(|e| e.to_string())?;
+    let version = catalog.version;
+    state.dso_catalog = catalog.indexed();
+
+    Ok(version)
+}
@@ -0,0 +1,16 @@
+use crate::models::state::AppState;
+use crate::weather::update_session_weather;
+use std::sync::Mutex;
+use tauri::State;
+use uuid::Uuid;
+
+// fetches the weather of an imaging session again, e.g. after setting its site or the api key
+#[tauri::command]
+pub async fn fetch_session_weather(state: State<'_, Mutex<AppState>>, session_id: Uuid) -> Result<(), String> {
+    let updated = update_session_weather(&state, &session_id).map_err(|e| e.to_string())?;
+    if !updated {
+        return Err("No weather found, add a weather API key in the preferences.".to_string());
+    }
+
+    Ok(())
+}
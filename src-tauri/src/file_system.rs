@@ -1,6 +1,6 @@
 #[cfg(target_os = "windows")]
 use std::os::windows::ffi::OsStrExt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{fs, io};
 
 #[cfg(target_os = "windows")]
@@ -8,6 +8,11 @@ use winapi::um::fileapi::SetFileAttributesW;
 #[cfg(target_os = "windows")]
 use winapi::um::winnt::FILE_ATTRIBUTE_HIDDEN;
 
+const FRAME_EXTENSIONS: [&str; 14] = [
+    "fit", "fits", "fts", "xisf", "cr2", "cr3", "nef", "arw", "dng", "raf", "orf", "tif", "tiff",
+    "png",
+];
+
 pub fn set_folder_invisible(path: &PathBuf) {
     for component in path.ancestors() {
         let dir_name = component.file_name().and_then(|os_str| os_str.to_str());
@@ -54,3 +59,11 @@ pub fn rename_folder_with_overwrite(old_path: &PathBuf, new_path: &PathBuf) -> i
     }
     fs::rename(old_path, new_path)
 }
+
+// returns true if the path has the extension of a raw, fits or image file
+pub fn is_frame_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| FRAME_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::PathBuf;
+
+const BLOCK_SIZE: usize = 2880;
+const CARD_SIZE: usize = 80;
+const MAX_HEADER_BLOCKS: usize = 100;
+
+// reads the keywords of the primary header of a fits file
+pub fn read_fits_header(path: &PathBuf) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut keywords = HashMap::new();
+    let mut block = [0u8; BLOCK_SIZE];
+
+    for _ in 0..MAX_HEADER_BLOCKS {
+        reader.read_exact(&mut block)?;
+
+        for card in block.chunks(CARD_SIZE) {
+            let card: String = card
+                .iter()
+                .map(|&b| if b.is_ascii() { b as char } else { '?' })
+                .collect();
+            let key = card[..8].trim().to_string();
+
+            if key == "END" {
+                return Ok(keywords);
+            }
+            if &card[8..10] != "= " {
+                continue;
+            }
+
+            keywords.insert(key, parse_value(&card[10..]));
+        }
+    }
+
+    Err("END keyword not found in fits header.".into())
+}
+
+// reads the fits keywords embedded in the xml header of a xisf file
+pub fn read_xisf_header(path: &PathBuf) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut signature = [0u8; 16];
+    reader.read_exact(&mut signature)?;
+    if &signature[..8] != b"XISF0100" {
+        return Err("Not a xisf file.".into());
+    }
+    let length = u32::from_le_bytes([signature[8], signature[9], signature[10], signature[11]]) as usize;
+
+    let mut header = vec![0u8; length];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8_lossy(&header);
+
+    let mut keywords = HashMap::new();
+    for element in header.split("<FITSKeyword").skip(1) {
+        let name = xml_attribute(element, "name");
+        let value = xml_attribute(element, "value");
+
+        if let (Some(name), Some(value)) = (name, value) {
+            keywords.insert(name.trim().to_string(), parse_value(&value));
+        }
+    }
+
+    Ok(keywords)
+}
+
+fn parse_value(value: &str) -> String {
+    let value = value.trim_start();
+
+    if let Some(quoted) = value.strip_prefix('\'') {
+        // strings end at the next single quote, two single quotes are an escaped quote
+        let mut result = String::new();
+        let mut chars = quoted.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            result.push(c);
+        }
+        result.trim_end().to_string()
+    } else {
+        value.split('/').next().unwrap_or_default().trim().to_string()
+    }
+}
+
+fn xml_attribute(element: &str, name: &str) -> Option<String> {
+    let start = element.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = element[start..].find('"')? + start;
+
+    Some(
+        element[start..end]
+            .replace("&apos;", "'")
+            .replace("&quot;", "\"")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&"),
+    )
+}
@@ -1,9 +1,50 @@
-use exif::{Exif, In, Reader, Tag};
+use crate::astronomy::Equatorial;
+use crate::fits::{read_fits_header, read_xisf_header};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use exif::{Exif, In, Reader, Tag, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::BufReader;
 use std::path::PathBuf;
 
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FrameMetadata {
+    pub timestamp: Option<DateTime<Utc>>,
+    // the night itself if the header has a date without a time
+    pub night: Option<NaiveDate>,
+    // when the file was written, the night is taken from it if the headers have no date
+    pub modified: Option<DateTime<Utc>>,
+    pub target: Option<String>,
+    pub sub_length: Option<f64>,
+    pub gain: Option<i32>,
+    pub offset: Option<i32>,
+    pub camera_temp: Option<f64>,
+    pub filter: Option<String>,
+    pub camera: Option<String>,
+    pub telescope: Option<String>,
+    pub image_type: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    // pointing in degrees
+    pub ra: Option<f64>,
+    pub dec: Option<f64>,
+    // arcmin (width, height)
+    pub field_of_view: Option<(f64, f64)>,
+    pub hfr: Option<f64>,
+    pub star_count: Option<i32>,
+}
+
+impl FrameMetadata {
+    pub fn pointing(&self) -> Option<Equatorial> {
+        Some(Equatorial {
+            ra: self.ra?,
+            dec: self.dec?,
+        })
+    }
+}
+
 fn get_exif_data(image: &PathBuf) -> Result<Exif, Box<dyn Error>> {
     let file = File::open(image)?;
     let exif_reader = Reader::new();
@@ -59,3 +100,158 @@ pub fn get_date(image: &PathBuf) -> Result<String, Box<dyn Error>> {
 
     Ok(date_str)
 }
+
+// reads the metadata of a frame from its fits/xisf header or its exif data
+pub fn get_metadata(image: &PathBuf) -> Result<FrameMetadata, Box<dyn Error>> {
+    let extension = image
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let mut metadata = match extension.as_str() {
+        "fit" | "fits" | "fts" => metadata_from_header(&read_fits_header(image)?),
+        "xisf" => metadata_from_header(&read_xisf_header(image)?),
+        _ => {
+            let exif = get_exif_data(image)?;
+            let camera = exif
+                .get_field(Tag::Model, In::PRIMARY)
+                .map(|field| field.display_value().to_string().trim_matches('"').to_string());
+
+            FrameMetadata {
+                timestamp: get_date(image).ok().and_then(|date| parse_local_timestamp(&date)),
+                sub_length: get_exposure_time(image).ok(),
+                gain: get_gain(image).ok(),
+                camera,
+                latitude: gps_coordinate(&exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, 'S'),
+                longitude: gps_coordinate(&exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, 'W'),
+                ..Default::default()
+            }
+        }
+    };
+    metadata.modified = fs::metadata(image)
+        .and_then(|data| data.modified())
+        .ok()
+        .map(DateTime::<Utc>::from);
+
+    Ok(metadata)
+}
+
+// degrees, minutes and seconds, negative in the southern or western hemisphere
+fn gps_coordinate(exif: &Exif, tag: Tag, ref_tag: Tag, negative_ref: char) -> Option<f64> {
+    let parts = match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Rational(parts) if !parts.is_empty() => parts.clone(),
+        _ => return None,
+    };
+    let angle = parts
+        .iter()
+        .take(3)
+        .enumerate()
+        .map(|(i, part)| part.to_f64() / 60f64.powi(i as i32))
+        .sum::<f64>();
+
+    let negative = exif
+        .get_field(ref_tag, In::PRIMARY)
+        .is_some_and(|field| field.display_value().to_string().contains(negative_ref));
+    Some(if negative { -angle } else { angle })
+}
+
+fn metadata_from_header(header: &HashMap<String, String>) -> FrameMetadata {
+    let text = |keys: &[&str]| {
+        keys.iter()
+            .filter_map(|key| header.get(*key))
+            .find(|value| !value.is_empty())
+            .cloned()
+    };
+    let number = |keys: &[&str]| text(keys).and_then(|value| value.parse::<f64>().ok());
+    let angle = |keys: &[&str]| text(keys).and_then(|value| parse_angle(&value));
+
+    FrameMetadata {
+        timestamp: text(&["DATE-OBS"])
+            .and_then(|date| parse_timestamp(&date).map(|t| t.and_utc()))
+            .or_else(|| text(&["DATE-LOC"]).and_then(|date| parse_local_timestamp(&date))),
+        night: text(&["DATE-OBS", "DATE-LOC"])
+            .and_then(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()),
+        modified: None,
+        target: text(&["OBJECT"]),
+        sub_length: number(&["EXPTIME", "EXPOSURE"]),
+        gain: number(&["GAIN"]).map(|gain| gain.round() as i32),
+        offset: number(&["OFFSET", "BLKLEVEL"]).map(|offset| offset.round() as i32),
+        camera_temp: number(&["CCD-TEMP", "SET-TEMP"]),
+        filter: text(&["FILTER"]),
+        camera: text(&["INSTRUME"]),
+        telescope: text(&["TELESCOP"]),
+        image_type: text(&["IMAGETYP", "FRAME"]),
+        latitude: angle(&["SITELAT", "OBSGEO-B"]),
+        longitude: angle(&["SITELONG", "OBSGEO-L"]),
+        // RA is written in degrees, OBJCTRA in hours, CRVAL is the plate solved center
+        ra: number(&["RA"])
+            .or_else(|| angle(&["OBJCTRA"]).map(|hours| hours * 15.0))
+            .or_else(|| number(&["CRVAL1"])),
+        dec: angle(&["DEC", "OBJCTDEC"]).or_else(|| number(&["CRVAL2"])),
+        field_of_view: field_of_view(header),
+        hfr: number(&["HFR", "HFD"]),
+        star_count: number(&["STARS", "NSTARS", "STARCOUNT"]).map(|stars| stars as i32),
+    }
+}
+
+// image size times the pixel scale of the wcs solution, or of the focal length and pixel size
+fn field_of_view(header: &HashMap<String, String>) -> Option<(f64, f64)> {
+    let number = |key: &str| header.get(key).and_then(|value| value.trim().parse::<f64>().ok());
+
+    // arcsec per pixel
+    let scale = number("CDELT2")
+        .or_else(|| number("CDELT1"))
+        .map(|degrees| degrees.abs() * 3600.0)
+        .or_else(|| {
+            let focal_length = number("FOCALLEN").filter(|f| *f > 0.0)?;
+            Some(206.265 * number("XPIXSZ")? / focal_length)
+        })?;
+
+    Some((number("NAXIS1")? * scale / 60.0, number("NAXIS2")? * scale / 60.0))
+}
+
+// parses decimal (8.5) and sexagesimal (+008 30 00, 8:30:00) angles
+pub fn parse_angle(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if let Ok(angle) = value.parse::<f64>() {
+        return Some(angle);
+    }
+
+    let parts: Vec<f64> = value
+        .split(|c: char| c == ' ' || c == ':')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<f64>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+
+    let sign = if value.starts_with('-') { -1.0 } else { 1.0 };
+    let angle = parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| part.abs() / 60f64.powi(i as i32))
+        .sum::<f64>();
+
+    Some(sign * angle)
+}
+
+// parses fits (2024-01-01T22:33:44.123) and exif (2024-01-01 22:33:44) timestamps
+pub fn parse_timestamp(date: &str) -> Option<NaiveDateTime> {
+    let date = date.trim();
+
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y:%m:%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(date, format).ok())
+}
+
+// cameras write their local time, which is assumed to be the timezone of this computer
+fn parse_local_timestamp(date: &str) -> Option<DateTime<Utc>> {
+    let timestamp = parse_timestamp(date)?;
+    Local
+        .from_local_datetime(&timestamp)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+}
@@ -0,0 +1,329 @@
+use crate::file_system::is_frame_file;
+use crate::image::{get_metadata, FrameMetadata};
+use crate::models::equipment::EquipmentList;
+use crate::models::imaging_frames::LightFrame;
+use crate::models::ingest::{
+    AmbiguousMatch, AmbiguousMatches, IngestFrameType, IngestGroup, IngestMode, IngestProposals, IngestRules,
+};
+use crate::models::observing_sites::ObservingSite;
+use crate::models::state::AppState;
+use crate::weather::update_session_weather;
+use crate::night::{group_lights, night_of};
+use chrono::NaiveDate;
+use notify::event::ModifyKind;
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+struct PendingFile {
+    size: u64,
+    changed: Instant,
+}
+
+// treats the source directory as an inbox for new captures
+pub struct SourceWatcher {
+    _watcher: RecommendedWatcher,
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for SourceWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl SourceWatcher {
+    pub fn new(
+        app_handle: AppHandle,
+        source_directory: &PathBuf,
+        rules: IngestRules,
+    ) -> Result<SourceWatcher, Box<dyn Error>> {
+        let pending: Arc<Mutex<HashMap<PathBuf, PendingFile>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        let pending_events = pending.clone();
+        let mut watcher = RecommendedWatcher::new(
+            move |result: notify::Result<Event>| match result {
+                Ok(event) => {
+                    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(ModifyKind::Data(_)))
+                    {
+                        return;
+                    }
+
+                    let mut pending = pending_events.lock().unwrap();
+                    for path in event.paths.into_iter().filter(|p| is_frame_file(p)) {
+                        pending.entry(path).or_insert(PendingFile {
+                            size: 0,
+                            changed: Instant::now(),
+                        });
+                    }
+                }
+                Err(err) => eprintln!("Error watching source directory: {}", err),
+            },
+            Config::default(),
+        )?;
+        watcher.watch(source_directory, RecursiveMode::Recursive)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = stop.clone();
+        thread::spawn(move || poll_pending(app_handle, pending, rules, stop_thread));
+
+        Ok(SourceWatcher {
+            _watcher: watcher,
+            stop,
+        })
+    }
+}
+
+// (re)starts the watcher on the current source directory, dropping the previous one
+pub fn watch_source_directory(app_handle: &AppHandle, app_state: &mut AppState) {
+    app_state.source_watcher = None;
+
+    let source_directory = app_state.preferences.storage.source_directory.clone();
+    let rules = app_state.preferences.ingest.clone();
+    if source_directory.as_os_str().is_empty() || rules.mode == IngestMode::OFF {
+        return;
+    }
+
+    match SourceWatcher::new(app_handle.clone(), &source_directory, rules) {
+        Ok(watcher) => {
+            app_state.source_watcher = Some(watcher);
+        }
+        Err(err) => {
+            eprintln!("Error watching {}: {}", source_directory.display(), err);
+        }
+    }
+}
+
+// waits until every pending file stopped growing and ingests them as one batch
+fn poll_pending(
+    app_handle: AppHandle,
+    pending: Arc<Mutex<HashMap<PathBuf, PendingFile>>>,
+    rules: IngestRules,
+    stop: Arc<AtomicBool>,
+) {
+    let settle = Duration::from_secs(rules.settle_seconds);
+
+    while !stop.load(Ordering::Relaxed) {
+        thread::sleep(POLL_INTERVAL);
+
+        let finished: Vec<PathBuf> = {
+            let mut pending = pending.lock().unwrap();
+
+            pending.retain(|path, file| match fs::metadata(path) {
+                Ok(metadata) => {
+                    if metadata.len() != file.size {
+                        file.size = metadata.len();
+                        file.changed = Instant::now();
+                    }
+                    true
+                }
+                Err(_) => false,
+            });
+
+            if pending.is_empty() || pending.values().any(|file| file.changed.elapsed() < settle) {
+                continue;
+            }
+
+            pending.drain().map(|(path, _)| path).collect()
+        };
+
+        ingest(&app_handle, finished, &rules);
+    }
+}
+
+fn ingest(app_handle: &AppHandle, frames: Vec<PathBuf>, rules: &IngestRules) {
+    let state = match app_handle.try_state::<Mutex<AppState>>() {
+        Some(state) => state,
+        None => return,
+    };
+
+    // skip frames that are already known
+    let frames: Vec<PathBuf> = {
+        let app_state = state.lock().unwrap();
+        frames
+            .into_iter()
+            .filter(|path| {
+                !app_state.imaging_frame_list.contains_frame(&path.to_string_lossy())
+                    && !app_state.ingest_proposals.iter().any(|g| g.frames.contains(path))
+            })
+            .collect()
+    };
+    if frames.is_empty() {
+        return;
+    }
+
+    // reading metadata is slow, so do it without holding the lock
+    let groups = group_frames(frames);
+
+    let (auto, proposals): (Vec<IngestGroup>, Vec<IngestGroup>) = groups.into_iter().partition(|group| {
+        rules.mode == IngestMode::AUTO
+            && group.frame_type == IngestFrameType::LIGHT
+            && group.frames.len() >= rules.min_light_subs
+    });
+
+    // light groups of the same folder, night, target and camera become one session
+    let mut sessions: Vec<Vec<IngestGroup>> = vec![];
+    for group in auto {
+        match sessions.iter_mut().find(|s| session_key(&s[0]) == session_key(&group)) {
+            Some(session) => session.push(group),
+            None => sessions.push(vec![group]),
+        }
+    }
+
+    let mut app_state = state.lock().unwrap();
+    let proposal_count = app_state.ingest_proposals.len();
+    let match_count = app_state.ambiguous_matches.len();
+    let mut sited_ids = vec![];
+    app_state.ingest_proposals.extend(proposals);
+    for session in sessions {
+        let folder_dir = session[0].folder.to_string_lossy().to_string();
+        let light_frames: Vec<LightFrame> = match session
+            .iter()
+            .map(|group| light_frame_from_group(group, &app_state.equipment_list))
+            .collect()
+        {
+            Ok(light_frames) => light_frames,
+            Err(err) => {
+                // left as a proposal, so the user sees the frames that couldn't be filed
+                eprintln!("Error creating imaging session: {}", err);
+                app_state.ingest_proposals.extend(session);
+                continue;
+            }
+        };
+        let ambiguous_matches: Vec<AmbiguousMatch> = light_frames
+            .iter()
+            .zip(&session)
+            .flat_map(|(light_frame, group)| AmbiguousMatch::find(light_frame, &group.metadata, &app_state.equipment_list))
+            .collect();
+
+        let site_id = ObservingSite::detect(&app_state.sites, session.iter().flat_map(|group| &group.metadata));
+
+        match app_state.add_imaging_session(light_frames, folder_dir, site_id) {
+            Ok(session_id) => {
+                app_state.ambiguous_matches.extend(ambiguous_matches);
+                if site_id.is_some() {
+                    sited_ids.push(session_id);
+                }
+            }
+            Err(err) => {
+                eprintln!("Error creating imaging session: {}", err);
+                app_state.ingest_proposals.extend(session);
+            }
+        }
+    }
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if app_state.ingest_proposals.len() != proposal_count {
+        if let Err(err) = IngestProposals::save(root_directory.clone(), &app_state.ingest_proposals) {
+            eprintln!("Error saving ingest_proposals: {}", err);
+        }
+    }
+    if app_state.ambiguous_matches.len() != match_count {
+        if let Err(err) = AmbiguousMatches::save(root_directory, &app_state.ambiguous_matches) {
+            eprintln!("Error saving ambiguous_matches: {}", err);
+        }
+    }
+    drop(app_state);
+
+    if let Err(err) = app_handle.emit("ingest", ()) {
+        eprintln!("Error emitting ingest: {}", err);
+    }
+    if sited_ids.is_empty() {
+        return;
+    }
+
+    // the sessions are shown right away, their weather follows. a failed weather request doesn't stop
+    // the import, it can be fetched again later
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let state = app_handle.state::<Mutex<AppState>>();
+        for session_id in &sited_ids {
+            if let Err(err) = update_session_weather(&state, session_id) {
+                eprintln!("Error fetching weather: {}", err);
+            }
+        }
+
+        if let Err(err) = app_handle.emit("ingest", ()) {
+            eprintln!("Error emitting ingest: {}", err);
+        }
+    });
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct GroupKey {
+    frame_type: IngestFrameType,
+    folder: PathBuf,
+    gain: Option<i32>,
+    sub_length: Option<i64>,
+    filter: Option<String>,
+}
+
+// groups frames by type, folder, gain, sub length and filter, lights are split further into nights
+pub fn group_frames(frames: Vec<PathBuf>) -> Vec<IngestGroup> {
+    let mut groups: HashMap<GroupKey, (Vec<PathBuf>, Vec<FrameMetadata>)> = HashMap::new();
+
+    for path in frames {
+        let metadata = get_metadata(&path).unwrap_or_default();
+        let key = GroupKey {
+            frame_type: IngestFrameType::detect(&path, &metadata),
+            folder: path.parent().map(|p| p.to_path_buf()).unwrap_or_default(),
+            gain: metadata.gain,
+            sub_length: metadata.sub_length.map(|s| (s * 1000.0).round() as i64),
+            filter: metadata.filter.as_ref().map(|f| f.trim().to_lowercase()),
+        };
+
+        let group = groups.entry(key).or_default();
+        group.0.push(path);
+        group.1.push(metadata);
+    }
+
+    let mut result = vec![];
+    for (key, (frames, metadata)) in groups {
+        let sets = if key.frame_type == IngestFrameType::LIGHT {
+            group_lights(frames, metadata)
+        } else {
+            vec![(frames, metadata)]
+        };
+
+        for (frames, metadata) in sets {
+            result.push(IngestGroup {
+                id: Uuid::new_v4(),
+                frame_type: key.frame_type.clone(),
+                folder: key.folder.clone(),
+                date: metadata.iter().filter_map(night_of).min(),
+                gain: metadata.iter().find_map(|m| m.gain),
+                sub_length: metadata.iter().find_map(|m| m.sub_length),
+                frames,
+                metadata,
+            });
+        }
+    }
+    result
+}
+
+fn session_key(group: &IngestGroup) -> (PathBuf, Option<NaiveDate>, Option<String>, Option<String>) {
+    let first = group.metadata.first();
+    (
+        group.folder.clone(),
+        group.date,
+        first.and_then(|m| m.target.as_ref()).map(|t| t.trim().to_lowercase()),
+        first.and_then(|m| m.camera.as_ref()).map(|c| c.trim().to_lowercase()),
+    )
+}
+
+pub fn light_frame_from_group(
+    group: &IngestGroup,
+    equipment_list: &EquipmentList,
+) -> Result<LightFrame, Box<dyn Error>> {
+    LightFrame::from_metadata(&group.frames, &group.metadata, equipment_list)
+}
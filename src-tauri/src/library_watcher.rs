@@ -0,0 +1,250 @@
+use crate::file_system::is_frame_file;
+use crate::models::frontend::library_change::{LibraryChange, LibraryChangeKind};
+use crate::models::image_list::ImageList;
+use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::imaging_session_list::ImagingSessionList;
+use crate::models::ingest::UnclassifiedFrames;
+use crate::models::state::AppState;
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+// keeps the records in sync with files that are added, removed or renamed outside of AstroLog
+pub struct LibraryWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl LibraryWatcher {
+    pub fn new(app_handle: AppHandle, root_directory: &PathBuf) -> Result<LibraryWatcher, Box<dyn Error>> {
+        let root = root_directory.clone();
+        let mut renames = RenameTracker::default();
+        let mut watcher = RecommendedWatcher::new(
+            move |result: notify::Result<Event>| match result {
+                Ok(event) => {
+                    for (kind, path, new_path) in renames.changes(event) {
+                        handle_change(&app_handle, &root, kind, path, new_path);
+                    }
+                }
+                Err(err) => eprintln!("Error watching library: {}", err),
+            },
+            Config::default(),
+        )?;
+        watcher.watch(root_directory, RecursiveMode::Recursive)?;
+
+        Ok(LibraryWatcher { _watcher: watcher })
+    }
+}
+
+// (re)starts the watcher on the current root directory, dropping the previous one
+pub fn watch_root_directory(app_handle: &AppHandle, app_state: &mut AppState) {
+    app_state.library_watcher = None;
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if root_directory.as_os_str().is_empty() {
+        return;
+    }
+
+    match LibraryWatcher::new(app_handle.clone(), &root_directory) {
+        Ok(watcher) => {
+            app_state.library_watcher = Some(watcher);
+        }
+        Err(err) => {
+            eprintln!("Error watching {}: {}", root_directory.display(), err);
+        }
+    }
+}
+
+type Change = (LibraryChangeKind, PathBuf, Option<PathBuf>);
+
+// pairs the two halves of a rename, every platform reports them differently. inotify sends From and To
+// with a shared tracker followed by Both, Windows sends From and To without a tracker and FSEvents
+// sends Any for the old and the new path
+#[derive(Default)]
+struct RenameTracker {
+    // old path and tracker of a rename waiting for its new path
+    pending: Option<(PathBuf, Option<usize>)>,
+    // tracker of the last rename paired from its halves, the Both that follows it is a duplicate
+    paired: Option<usize>,
+}
+
+impl RenameTracker {
+    fn changes(&mut self, event: Event) -> Vec<Change> {
+        let rename = match event.kind {
+            EventKind::Create(_) | EventKind::Remove(_) => None,
+            EventKind::Modify(ModifyKind::Name(mode)) => Some(mode),
+            _ => return vec![],
+        };
+        let Some(path) = event.paths.first().cloned() else {
+            return vec![];
+        };
+        let tracker = event.attrs.tracker();
+
+        // FSEvents doesn't say which half it is, the old path is gone by the time the event arrives
+        let old_half = match rename {
+            Some(RenameMode::From) => true,
+            Some(RenameMode::Any) | Some(RenameMode::Other) => !path.exists(),
+            _ => false,
+        };
+        let new_half = matches!(rename, Some(RenameMode::To | RenameMode::Any | RenameMode::Other)) && !old_half;
+
+        let mut changes = vec![];
+        if let Some((old_path, pending_tracker)) = self.pending.take() {
+            if new_half && pending_tracker == tracker {
+                self.paired = tracker;
+                return vec![(LibraryChangeKind::RENAMED, old_path, Some(path))];
+            }
+            // nothing took its place, the file was moved out of the library
+            changes.push((LibraryChangeKind::REMOVED, old_path, None));
+        }
+
+        match (event.kind, rename) {
+            (EventKind::Create(_), _) => changes.push((LibraryChangeKind::ADDED, path, None)),
+            (EventKind::Remove(_), _) => changes.push((LibraryChangeKind::REMOVED, path, None)),
+            (_, Some(RenameMode::Both)) if event.paths.len() == 2 => {
+                if tracker.is_none() || tracker != self.paired {
+                    changes.push((LibraryChangeKind::RENAMED, path, Some(event.paths[1].clone())));
+                }
+            }
+            _ if old_half => self.pending = Some((path, tracker)),
+            // a new path without an old one was moved into the library
+            _ if new_half => changes.push((LibraryChangeKind::ADDED, path, None)),
+            _ => {}
+        }
+        changes
+    }
+}
+
+// the path under the new folder if it was under the old one
+fn moved(path: &Path, from: &Path, to: &Path) -> Option<PathBuf> {
+    Some(to.join(path.strip_prefix(from).ok()?))
+}
+
+fn handle_change(app_handle: &AppHandle, root: &Path, kind: LibraryChangeKind, path: PathBuf, new_path: Option<PathBuf>) {
+    // a renamed or moved folder takes every record under it along
+    let folder = matches!(kind, LibraryChangeKind::RENAMED) && new_path.as_deref().is_some_and(Path::is_dir);
+
+    // ignore our own metadata and files that aren't frames
+    if path.starts_with(root.join(".astrolog"))
+        || !(folder || is_frame_file(&path) || new_path.as_deref().is_some_and(is_frame_file))
+    {
+        return;
+    }
+
+    let state = match app_handle.try_state::<Mutex<AppState>>() {
+        Some(state) => state,
+        None => return,
+    };
+    let mut app_state = state.lock().unwrap();
+
+    let path_str = path.to_string_lossy().to_string();
+    let mut frames_changed = false;
+    let mut images_changed = false;
+    let mut sessions_changed = false;
+    let unclassified = app_state.unclassified_frames.len();
+    let mut unclassified_changed = false;
+
+    let classified = match kind {
+        LibraryChangeKind::ADDED => {
+            let known = app_state.imaging_frame_list.contains_frame(&path_str)
+                || app_state.image_list.values().any(|image| image.path == path);
+
+            if !known && !app_state.unclassified_frames.contains(&path) {
+                app_state.unclassified_frames.push(path.clone());
+            }
+            known
+        }
+        LibraryChangeKind::REMOVED => {
+            frames_changed = app_state.imaging_frame_list.remove_frame(&path_str);
+
+            let images = app_state.image_list.len();
+            app_state.image_list.retain(|_, image| image.path != path);
+            images_changed = images != app_state.image_list.len();
+
+            app_state.unclassified_frames.retain(|p| p != &path);
+            frames_changed || images_changed
+        }
+        LibraryChangeKind::RENAMED if folder => {
+            let new_path = new_path.clone().unwrap_or_default();
+            frames_changed = app_state.imaging_frame_list.rename_folder(&path, &new_path);
+
+            for image in app_state.image_list.values_mut() {
+                if let Some(moved_path) = moved(&image.path, &path, &new_path) {
+                    image.path = moved_path;
+                    images_changed = true;
+                }
+            }
+
+            for p in app_state.unclassified_frames.iter_mut() {
+                if let Some(moved_path) = moved(p, &path, &new_path) {
+                    *p = moved_path;
+                    unclassified_changed = true;
+                }
+            }
+
+            for session in app_state.imaging_sessions.values_mut() {
+                if let Some(moved_path) = moved(Path::new(&session.folder_dir), &path, &new_path) {
+                    session.folder_dir = moved_path.to_string_lossy().to_string();
+                    sessions_changed = true;
+                }
+            }
+            frames_changed || images_changed || sessions_changed
+        }
+        LibraryChangeKind::RENAMED => {
+            let new_path = new_path.clone().unwrap_or_default();
+            frames_changed = app_state
+                .imaging_frame_list
+                .rename_frame(&path_str, &new_path.to_string_lossy());
+
+            for image in app_state.image_list.values_mut() {
+                if image.path == path {
+                    image.path = new_path.clone();
+                    images_changed = true;
+                }
+            }
+
+            for p in app_state.unclassified_frames.iter_mut() {
+                if p == &path {
+                    *p = new_path.clone();
+                    unclassified_changed = true;
+                }
+            }
+            frames_changed || images_changed
+        }
+    };
+
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+    if frames_changed {
+        if let Err(err) = ImagingFrameList::save(root_directory.clone(), &app_state.imaging_frame_list) {
+            eprintln!("Error saving imaging_frame_list: {}", err);
+        }
+    }
+    if images_changed {
+        if let Err(err) = ImageList::save(root_directory.clone(), &app_state.image_list) {
+            eprintln!("Error saving image_list: {}", err);
+        }
+    }
+    if sessions_changed {
+        if let Err(err) = ImagingSessionList::save(root_directory.clone(), &app_state.imaging_sessions) {
+            eprintln!("Error saving imaging_session_list: {}", err);
+        }
+    }
+    if unclassified_changed || unclassified != app_state.unclassified_frames.len() {
+        if let Err(err) = UnclassifiedFrames::save(root_directory, &app_state.unclassified_frames) {
+            eprintln!("Error saving unclassified_frames: {}", err);
+        }
+    }
+    drop(app_state);
+
+    let change = LibraryChange {
+        kind,
+        path,
+        new_path,
+        classified,
+    };
+    if let Err(err) = app_handle.emit("library_changed", change) {
+        eprintln!("Error emitting library_changed: {}", err);
+    }
+}
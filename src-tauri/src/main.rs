@@ -2,24 +2,51 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use commands::calibration::{analyze_calibration_frames, classify_calibration_frames};
+use commands::catalog::{add_catalog_item, import_equipment_catalog, search_equipment_catalog};
 use commands::gallery::{add_new_image, open_image};
 use commands::image::get_date;
-use commands::imaging_sessions::{export_csv, open_imaging_session};
+use commands::ingest::{
+    accept_ingest_proposal, dismiss_ambiguous_match, dismiss_ingest_proposal, dismiss_unclassified_frames,
+    resolve_ambiguous_match,
+};
+use commands::imaging_sessions::{
+    confirm_integrated_subs, create_imaging_session, export_csv, get_session_track, open_imaging_session,
+    set_subs_rejected,
+};
+use commands::optical_trains::{apply_optical_train, delete_optical_train, save_optical_train};
+use commands::planner::{add_to_wishlist, plan_nights, remove_from_wishlist};
 use commands::preferences::{save_preferences, set_root_directory, setup_backup};
+use commands::projects::{delete_project, save_project, set_session_project};
+use commands::sites::{delete_site, save_site, set_session_site};
 use commands::state::{add_close_lock, load_frontend_app_state, remove_close_lock, update_app_state_from_json};
+use commands::targets::{import_dso_catalog, normalize_targets, search_dso_catalog, set_target_object};
 use commands::utils::{open_browser, rename_directory};
+use commands::weather::fetch_session_weather;
 use models::frontend::process::Process;
 use models::state::AppState;
 use std::env;
 use std::sync::Mutex;
 use tauri::{Emitter, Manager};
-use crate::commands::equipment::{save_telescope, check_equipment_duplicate};
+use crate::commands::equipment::{
+    add_equipment_item, calculate_optics, calculate_sensor_metrics, check_equipment_duplicate, delete_equipment_item,
+    delete_maintenance_entry, merge_equipment_items, save_filter_aliases, save_maintenance_entry, save_telescope,
+    set_equipment_status, update_equipment_item,
+};
 use crate::file_system::set_folder_invisible;
+use crate::library_watcher::watch_root_directory;
+use crate::ingest::watch_source_directory;
 
+mod astronomy;
 mod commands;
 mod file_store;
+mod fits;
 mod image;
+mod ingest;
+mod library_watcher;
 mod models;
+mod night;
+mod planner;
+mod weather;
 pub mod file_system;
 
 fn main() {
@@ -43,6 +70,15 @@ fn main() {
             // state management
             app.manage(app_state);
 
+            let state: tauri::State<Mutex<AppState>> = app.state();
+            let mut app_state = state.lock().unwrap();
+
+            // watch root directory for external changes
+            watch_root_directory(app.handle(), &mut app_state);
+
+            // watch source directory for new captures
+            watch_source_directory(app.handle(), &mut app_state);
+
             Ok(())
         })
         .on_window_event(|window, event| {
@@ -59,24 +95,63 @@ fn main() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_keygen::Builder::new(&account_id, &verify_key).build())
         .invoke_handler(tauri::generate_handler![
+            accept_ingest_proposal,
+            add_catalog_item,
             add_close_lock,
+            add_equipment_item,
             add_new_image,
+            add_to_wishlist,
             analyze_calibration_frames,
+            apply_optical_train,
+            calculate_optics,
+            calculate_sensor_metrics,
             check_equipment_duplicate,
             classify_calibration_frames,
+            confirm_integrated_subs,
+            create_imaging_session,
+            delete_equipment_item,
+            delete_maintenance_entry,
+            delete_optical_train,
+            delete_project,
+            delete_site,
+            dismiss_ambiguous_match,
+            dismiss_ingest_proposal,
+            dismiss_unclassified_frames,
             export_csv,
+            fetch_session_weather,
             get_date,
+            get_session_track,
+            import_dso_catalog,
+            import_equipment_catalog,
             load_frontend_app_state,
+            merge_equipment_items,
+            normalize_targets,
             open_browser,
             open_image,
             open_imaging_session,
+            plan_nights,
             remove_close_lock,
+            remove_from_wishlist,
             rename_directory,
+            resolve_ambiguous_match,
+            save_filter_aliases,
+            save_maintenance_entry,
+            save_optical_train,
             save_preferences,
+            save_project,
+            save_site,
             save_telescope,
+            search_dso_catalog,
+            search_equipment_catalog,
+            set_equipment_status,
             set_root_directory,
+            set_session_project,
+            set_session_site,
+            set_subs_rejected,
+            set_target_object,
             setup_backup,
             update_app_state_from_json,
+            update_equipment_item,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
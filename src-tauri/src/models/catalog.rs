@@ -0,0 +1,112 @@
+use crate::file_store;
+use crate::models::equipment::{Equipment, EquipmentType};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const BUNDLED_CATALOG: &str = include_str!("../../catalog/equipment_catalog.json");
+
+// specs of common cameras, telescopes, reducers and filters to create equipment items from
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EquipmentCatalog {
+    pub version: u32,
+    pub entries: Vec<CatalogEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    // stays the same across catalog versions
+    pub key: String,
+    pub equipment_type: EquipmentType,
+    pub brand: String,
+    pub name: String,
+    // the type specific fields of the equipment item
+    pub specs: Map<String, Value>,
+}
+
+impl EquipmentCatalog {
+    pub fn bundled() -> EquipmentCatalog {
+        serde_json::from_str(BUNDLED_CATALOG).expect("bundled equipment catalog is invalid")
+    }
+
+    // the bundled catalog, unless an imported one is newer
+    pub fn load(dir: PathBuf) -> EquipmentCatalog {
+        let bundled = EquipmentCatalog::bundled();
+        // nothing imported yet
+        let path = EquipmentCatalog::path(dir);
+        if !path.exists() {
+            return bundled;
+        }
+        match file_store::load::<EquipmentCatalog>(&path) {
+            Ok(imported) if imported.version > bundled.version => imported,
+            Ok(_) => bundled,
+            Err(err) => {
+                eprintln!("Error loading equipment_catalog: {}", err);
+                bundled
+            }
+        }
+    }
+
+    pub fn save(&self, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+        Ok(file_store::save(
+            &EquipmentCatalog::path(dir),
+            &serde_json::to_string_pretty(self)?,
+        )?)
+    }
+
+    fn path(dir: PathBuf) -> PathBuf {
+        let mut filename = dir;
+        filename.push("equipment_catalog.json");
+        filename
+    }
+
+    // entries containing every word of the query in their brand or name
+    pub fn search(&self, query: &str, equipment_type: Option<EquipmentType>) -> Vec<&CatalogEntry> {
+        let words: Vec<String> = query.split_whitespace().map(|w| w.to_lowercase()).collect();
+
+        let mut entries: Vec<&CatalogEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| equipment_type.is_none_or(|t| t == entry.equipment_type))
+            .filter(|entry| {
+                let text = format!("{} {}", entry.brand, entry.name).to_lowercase();
+                words.iter().all(|word| text.contains(word))
+            })
+            .collect();
+        entries.sort_by(|a, b| (&a.brand, &a.name).cmp(&(&b.brand, &b.name)));
+        entries
+    }
+
+    pub fn entry(&self, key: &str) -> Option<&CatalogEntry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+}
+
+impl CatalogEntry {
+    // a new equipment item with the catalog specs, fields in overrides replace them
+    pub fn to_equipment(&self, overrides: Map<String, Value>) -> Result<Equipment, serde_json::Error> {
+        let mut value = self.specs.clone();
+        value.insert("id".to_string(), Value::String(Uuid::new_v4().to_string()));
+        value.insert("brand".to_string(), Value::String(self.brand.clone()));
+        value.insert("name".to_string(), Value::String(self.name.clone()));
+        for (key, override_value) in overrides {
+            merge(value.entry(key).or_insert(Value::Null), override_value);
+        }
+
+        Equipment::from_value(self.equipment_type, Value::Object(value))
+    }
+}
+
+// nested objects like the camera sensor are overridden field by field
+fn merge(target: &mut Value, value: Value) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(value)) => {
+            for (key, value) in value {
+                merge(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
@@ -0,0 +1,281 @@
+use crate::astronomy::{separation, Equatorial};
+use crate::file_store;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+
+const BUNDLED_CATALOG: &str = include_str!("../../catalog/dso_catalog.json");
+
+// long catalog names and the short designations they are written as
+const PREFIXES: [(&str, &str); 5] = [
+    ("MESSIER", "M"),
+    ("CALDWELL", "C"),
+    ("SHARPLESS", "SH2"),
+    ("BARNARD", "B"),
+    ("VANDENBERGH", "VDB"),
+];
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ObjectType {
+    GALAXY,
+    GALAXYCLUSTER,
+    EMISSIONNEBULA,
+    REFLECTIONNEBULA,
+    PLANETARYNEBULA,
+    SUPERNOVAREMNANT,
+    DARKNEBULA,
+    OPENCLUSTER,
+    GLOBULARCLUSTER,
+    OTHER,
+}
+
+// objects without a known size count as this share of the field
+const UNKNOWN_SIZE_FILL: f64 = 0.05;
+// objects filling this share of the field or more are framed best
+const IDEAL_FILL: f64 = 0.33;
+
+// offline catalog of deep sky objects to resolve target names against, see import_dso_catalog for
+// catalogs that aren't bundled completely
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DsoCatalog {
+    pub version: u32,
+    pub objects: Vec<DeepSkyObject>,
+
+    // designation key to position in objects
+    #[serde(skip)]
+    index: HashMap<String, usize>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeepSkyObject {
+    // main designation like M31 or NGC 7000, used as the target name
+    pub id: String,
+    // common name, empty if there is none
+    pub name: String,
+    // other designations and common names
+    pub aliases: Vec<String>,
+    pub object_type: ObjectType,
+    // degrees, J2000
+    pub ra: f64,
+    pub dec: f64,
+    // major and minor axis in arcmin
+    pub size: Option<[f64; 2]>,
+    pub magnitude: Option<f64>,
+}
+
+// a catalog object within the field of view of a frame
+#[derive(Debug)]
+pub struct FieldObject<'a> {
+    pub object: &'a DeepSkyObject,
+    // arcmin from the center of the frame
+    pub separation: f64,
+    // how likely this is the main target, higher is better
+    pub score: f64,
+}
+
+impl DeepSkyObject {
+    pub fn position(&self) -> Equatorial {
+        Equatorial {
+            ra: self.ra,
+            dec: self.dec,
+        }
+    }
+
+    // how well the object fills a field of view, from 0 to 1. objects larger than the field need a mosaic
+    pub fn framing(&self, field_of_view: (f64, f64)) -> f64 {
+        let fill = self.size.map_or(UNKNOWN_SIZE_FILL, |size| size[0] / field_of_view.0.max(field_of_view.1));
+        if fill > 1.0 {
+            1.0 / fill
+        } else {
+            (fill / IDEAL_FILL).min(1.0)
+        }
+    }
+
+    pub fn designations(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.id)
+            .chain(std::iter::once(&self.name).filter(|name| !name.is_empty()))
+            .chain(self.aliases.iter())
+    }
+}
+
+// "NGC 224", "ngc224" and "NGC0224" all become NGC224, "Messier 31" becomes M31
+pub fn designation_key(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_uppercase();
+    let key = PREFIXES
+        .iter()
+        .find_map(|(long, short)| {
+            key.strip_prefix(long)
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+                .map(|rest| format!("{}{}", short, rest))
+        })
+        .unwrap_or(key);
+
+    let letters = key.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = key[letters.len()..].trim_start_matches('0');
+    if letters.is_empty() || number.is_empty() {
+        key
+    } else {
+        format!("{}{}", letters, number)
+    }
+}
+
+impl DsoCatalog {
+    pub fn bundled() -> DsoCatalog {
+        let catalog: DsoCatalog = serde_json::from_str(BUNDLED_CATALOG).expect("bundled dso catalog is invalid");
+        catalog.indexed()
+    }
+
+    // the bundled catalog, unless an imported one is newer
+    pub fn load(dir: PathBuf) -> DsoCatalog {
+        let bundled = DsoCatalog::bundled();
+        // nothing imported yet
+        let path = DsoCatalog::path(dir);
+        if !path.exists() {
+            return bundled;
+        }
+        match file_store::load::<DsoCatalog>(&path) {
+            Ok(imported) if imported.version > bundled.version => imported.indexed(),
+            Ok(_) => bundled,
+            Err(err) => {
+                eprintln!("Error loading dso_catalog: {}", err);
+                bundled
+            }
+        }
+    }
+
+    pub fn save(&self, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+        Ok(file_store::save(
+            &DsoCatalog::path(dir),
+            &serde_json::to_string_pretty(self)?,
+        )?)
+    }
+
+    fn path(dir: PathBuf) -> PathBuf {
+        let mut filename = dir;
+        filename.push("dso_catalog.json");
+        filename
+    }
+
+    pub fn indexed(mut self) -> DsoCatalog {
+        self.index = HashMap::new();
+        for (i, object) in self.objects.iter().enumerate() {
+            for designation in object.designations() {
+                // the first object keeps a designation used twice
+                self.index.entry(designation_key(designation)).or_insert(i);
+            }
+        }
+        self
+    }
+
+    pub fn object(&self, id: &str) -> Option<&DeepSkyObject> {
+        self.index.get(&designation_key(id)).map(|i| &self.objects[*i])
+    }
+
+    // matches the whole name first, then fewer and fewer leading words, so headers like
+    // "M31 Panel 2" or "NGC7000_Ha" still resolve
+    pub fn resolve(&self, name: &str) -> Option<&DeepSkyObject> {
+        let words: Vec<&str> = name
+            .split(|c: char| c.is_whitespace() || c == '_')
+            .filter(|word| !word.is_empty())
+            .collect();
+        (1..=words.len()).rev().find_map(|n| self.object(&words[..n].join(" ")))
+    }
+
+    // objects overlapping a field centered on the pointing, the likely main target first.
+    // large objects close to the center score best, so a galaxy wins over the star cluster next to it
+    pub fn in_field(&self, pointing: &Equatorial, field_of_view: (f64, f64)) -> Vec<FieldObject<'_>> {
+        let (width, height) = field_of_view;
+        let radius = width.hypot(height) / 2.0;
+
+        let mut objects: Vec<FieldObject> = self
+            .objects
+            .iter()
+            .filter_map(|object| {
+                let distance = separation(pointing, &object.position()) * 60.0;
+                let major = object.size.map_or(0.0, |size| size[0]);
+                if distance > radius + major / 2.0 {
+                    return None;
+                }
+
+                let fill = object.size.map_or(UNKNOWN_SIZE_FILL, |size| (size[0] / width.max(height)).min(1.0));
+                Some(FieldObject {
+                    object,
+                    separation: distance,
+                    score: fill - (distance / radius).min(1.0) / 2.0,
+                })
+            })
+            .collect();
+        objects.sort_by(|a, b| b.score.total_cmp(&a.score));
+        objects
+    }
+
+    // objects with a designation containing the query, exact matches first
+    pub fn search(&self, query: &str) -> Vec<&DeepSkyObject> {
+        let query = designation_key(query);
+        let mut objects: Vec<(bool, &DeepSkyObject)> = self
+            .objects
+            .iter()
+            .filter_map(|object| {
+                let keys: Vec<String> = object.designations().map(|d| designation_key(d)).collect();
+                if keys.iter().any(|key| key.contains(&query)) {
+                    Some((!keys.contains(&query), object))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        objects.sort_by(|a, b| a.0.cmp(&b.0));
+        objects.into_iter().map(|(_, object)| object).collect()
+    }
+}
+
+// target names the user linked to catalog objects by hand, by designation key
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TargetAliases {
+    pub aliases: HashMap<String, String>,
+}
+
+impl TargetAliases {
+    pub fn load(dir: PathBuf) -> Result<TargetAliases, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("target_aliases.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(&self, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("target_aliases.json");
+
+        Ok(file_store::save(&filename, &serde_json::to_string_pretty(self)?)?)
+    }
+}
+
+// catalog ids of objects the user wants to image, planned before the rest of the catalog
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Wishlist {
+    pub object_ids: Vec<String>,
+}
+
+impl Wishlist {
+    pub fn load(dir: PathBuf) -> Result<Wishlist, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("wishlist.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(&self, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("wishlist.json");
+
+        Ok(file_store::save(&filename, &serde_json::to_string_pretty(self)?)?)
+    }
+}
@@ -1,18 +1,34 @@
 use crate::file_store;
+use crate::models::filters::{parse_filter_type, FilterAlias, FilterType};
+use crate::models::imaging_frames::LightFrame;
+use crate::models::lifecycle::Lifecycle;
+use crate::models::optical_train::OpticalTrain;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::error::Error;
 use std::path::PathBuf;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct EquipmentList {
     pub telescopes: HashMap<Uuid, Telescope>,
     pub cameras: HashMap<Uuid, Camera>,
     pub mounts: HashMap<Uuid, Mount>,
     pub filters: HashMap<Uuid, Filter>,
     pub flatteners: HashMap<Uuid, Flattener>,
+    pub filter_wheels: HashMap<Uuid, FilterWheel>,
+    pub focusers: HashMap<Uuid, Focuser>,
+    pub guide_scopes: HashMap<Uuid, GuideScope>,
+    pub guide_cameras: HashMap<Uuid, GuideCamera>,
+    pub off_axis_guiders: HashMap<Uuid, OffAxisGuider>,
+    pub rotators: HashMap<Uuid, Rotator>,
+    pub dew_heaters: HashMap<Uuid, DewHeater>,
+    pub field_rotators: HashMap<Uuid, FieldRotator>,
+    pub optical_trains: HashMap<Uuid, OpticalTrain>,
+    // header values mapped to filter types, checked before the built-in aliases
+    pub filter_aliases: Vec<FilterAlias>,
 }
 
 impl<'de> Deserialize<'de> for EquipmentList {
@@ -27,6 +43,26 @@ impl<'de> Deserialize<'de> for EquipmentList {
             pub mounts: Vec<Mount>,
             pub filters: Vec<Filter>,
             pub flatteners: Vec<Flattener>,
+            #[serde(default)]
+            pub filter_wheels: Vec<FilterWheel>,
+            #[serde(default)]
+            pub focusers: Vec<Focuser>,
+            #[serde(default)]
+            pub guide_scopes: Vec<GuideScope>,
+            #[serde(default)]
+            pub guide_cameras: Vec<GuideCamera>,
+            #[serde(default)]
+            pub off_axis_guiders: Vec<OffAxisGuider>,
+            #[serde(default)]
+            pub rotators: Vec<Rotator>,
+            #[serde(default)]
+            pub dew_heaters: Vec<DewHeater>,
+            #[serde(default)]
+            pub field_rotators: Vec<FieldRotator>,
+            #[serde(default)]
+            pub optical_trains: Vec<OpticalTrain>,
+            #[serde(default)]
+            pub filter_aliases: Vec<FilterAlias>,
         }
 
         let TempEquipmentList {
@@ -35,6 +71,16 @@ impl<'de> Deserialize<'de> for EquipmentList {
             mounts,
             filters,
             flatteners,
+            filter_wheels,
+            focusers,
+            guide_scopes,
+            guide_cameras,
+            off_axis_guiders,
+            rotators,
+            dew_heaters,
+            field_rotators,
+            optical_trains,
+            filter_aliases,
         } = TempEquipmentList::deserialize(deserializer)?;
 
         let telescopes_map: HashMap<Uuid, Telescope> = telescopes
@@ -42,8 +88,13 @@ impl<'de> Deserialize<'de> for EquipmentList {
             .map(|frame| (frame.id, frame))
             .collect();
 
-        let cameras_map: HashMap<Uuid, Camera> =
-            cameras.into_iter().map(|frame| (frame.id, frame)).collect();
+        let cameras_map: HashMap<Uuid, Camera> = cameras
+            .into_iter()
+            .map(|mut frame| {
+                frame.migrate_sensor();
+                (frame.id, frame)
+            })
+            .collect();
 
         let mounts_map: HashMap<Uuid, Mount> =
             mounts.into_iter().map(|frame| (frame.id, frame)).collect();
@@ -56,12 +107,27 @@ impl<'de> Deserialize<'de> for EquipmentList {
             .map(|frame| (frame.id, frame))
             .collect();
 
+        let optical_trains_map: HashMap<Uuid, OpticalTrain> = optical_trains
+            .into_iter()
+            .map(|train| (train.id, train))
+            .collect();
+
         Ok(EquipmentList {
             telescopes: telescopes_map,
             cameras: cameras_map,
             mounts: mounts_map,
             filters: filters_map,
             flatteners: flatteners_map,
+            filter_wheels: to_map(filter_wheels),
+            focusers: to_map(focusers),
+            guide_scopes: to_map(guide_scopes),
+            guide_cameras: to_map(guide_cameras),
+            off_axis_guiders: to_map(off_axis_guiders),
+            rotators: to_map(rotators),
+            dew_heaters: to_map(dew_heaters),
+            field_rotators: to_map(field_rotators),
+            optical_trains: optical_trains_map,
+            filter_aliases,
         })
     }
 }
@@ -76,13 +142,24 @@ impl Serialize for EquipmentList {
         let mounts: Vec<&Mount> = self.mounts.values().collect();
         let filters: Vec<&Filter> = self.filters.values().collect();
         let flatteners: Vec<&Flattener> = self.flatteners.values().collect();
+        let optical_trains: Vec<&OpticalTrain> = self.optical_trains.values().collect();
 
-        let mut state = serializer.serialize_struct("EquipmentList", 4)?;
+        let mut state = serializer.serialize_struct("EquipmentList", 15)?;
         state.serialize_field("telescopes", &telescopes)?;
         state.serialize_field("cameras", &cameras)?;
         state.serialize_field("mounts", &mounts)?;
         state.serialize_field("filters", &filters)?;
         state.serialize_field("flatteners", &flatteners)?;
+        state.serialize_field("filter_wheels", &self.filter_wheels.values().collect::<Vec<_>>())?;
+        state.serialize_field("focusers", &self.focusers.values().collect::<Vec<_>>())?;
+        state.serialize_field("guide_scopes", &self.guide_scopes.values().collect::<Vec<_>>())?;
+        state.serialize_field("guide_cameras", &self.guide_cameras.values().collect::<Vec<_>>())?;
+        state.serialize_field("off_axis_guiders", &self.off_axis_guiders.values().collect::<Vec<_>>())?;
+        state.serialize_field("rotators", &self.rotators.values().collect::<Vec<_>>())?;
+        state.serialize_field("dew_heaters", &self.dew_heaters.values().collect::<Vec<_>>())?;
+        state.serialize_field("field_rotators", &self.field_rotators.values().collect::<Vec<_>>())?;
+        state.serialize_field("optical_trains", &optical_trains)?;
+        state.serialize_field("filter_aliases", &self.filter_aliases)?;
         state.end()
     }
 }
@@ -95,6 +172,16 @@ impl EquipmentList {
             mounts: HashMap::new(),
             filters: HashMap::new(),
             flatteners: HashMap::new(),
+            filter_wheels: HashMap::new(),
+            focusers: HashMap::new(),
+            guide_scopes: HashMap::new(),
+            guide_cameras: HashMap::new(),
+            off_axis_guiders: HashMap::new(),
+            rotators: HashMap::new(),
+            dew_heaters: HashMap::new(),
+            field_rotators: HashMap::new(),
+            optical_trains: HashMap::new(),
+            filter_aliases: vec![],
         }
     }
 
@@ -114,36 +201,456 @@ impl EquipmentList {
             &serde_json::to_string_pretty(self)?,
         )?)
     }
+
+    pub fn get(&self, equipment_type: EquipmentType, id: &Uuid) -> Option<Equipment> {
+        match equipment_type {
+            EquipmentType::TELESCOPE => self.telescopes.get(id).cloned().map(Equipment::Telescope),
+            EquipmentType::CAMERA => self.cameras.get(id).cloned().map(Equipment::Camera),
+            EquipmentType::MOUNT => self.mounts.get(id).cloned().map(Equipment::Mount),
+            EquipmentType::FILTER => self.filters.get(id).cloned().map(Equipment::Filter),
+            EquipmentType::FLATTENER => self.flatteners.get(id).cloned().map(Equipment::Flattener),
+            EquipmentType::FILTERWHEEL => self.filter_wheels.get(id).cloned().map(Equipment::FilterWheel),
+            EquipmentType::FOCUSER => self.focusers.get(id).cloned().map(Equipment::Focuser),
+            EquipmentType::GUIDESCOPE => self.guide_scopes.get(id).cloned().map(Equipment::GuideScope),
+            EquipmentType::GUIDECAMERA => self.guide_cameras.get(id).cloned().map(Equipment::GuideCamera),
+            EquipmentType::OFFAXISGUIDER => self.off_axis_guiders.get(id).cloned().map(Equipment::OffAxisGuider),
+            EquipmentType::ROTATOR => self.rotators.get(id).cloned().map(Equipment::Rotator),
+            EquipmentType::DEWHEATER => self.dew_heaters.get(id).cloned().map(Equipment::DewHeater),
+            EquipmentType::FIELDROTATOR => self.field_rotators.get(id).cloned().map(Equipment::FieldRotator),
+        }
+    }
+
+    // inserts the item into the list of its type, returns the previous item with the same id
+    pub fn insert(&mut self, item: Equipment) -> Option<Equipment> {
+        match item {
+            Equipment::Telescope(t) => self.telescopes.insert(t.id, t).map(Equipment::Telescope),
+            Equipment::Camera(c) => self.cameras.insert(c.id, c).map(Equipment::Camera),
+            Equipment::Mount(m) => self.mounts.insert(m.id, m).map(Equipment::Mount),
+            Equipment::Filter(f) => self.filters.insert(f.id, f).map(Equipment::Filter),
+            Equipment::Flattener(fl) => self.flatteners.insert(fl.id, fl).map(Equipment::Flattener),
+            Equipment::FilterWheel(fw) => self.filter_wheels.insert(fw.id, fw).map(Equipment::FilterWheel),
+            Equipment::Focuser(fo) => self.focusers.insert(fo.id, fo).map(Equipment::Focuser),
+            Equipment::GuideScope(gs) => self.guide_scopes.insert(gs.id, gs).map(Equipment::GuideScope),
+            Equipment::GuideCamera(gc) => self.guide_cameras.insert(gc.id, gc).map(Equipment::GuideCamera),
+            Equipment::OffAxisGuider(oag) => self.off_axis_guiders.insert(oag.id, oag).map(Equipment::OffAxisGuider),
+            Equipment::Rotator(r) => self.rotators.insert(r.id, r).map(Equipment::Rotator),
+            Equipment::DewHeater(dh) => self.dew_heaters.insert(dh.id, dh).map(Equipment::DewHeater),
+            Equipment::FieldRotator(fr) => self.field_rotators.insert(fr.id, fr).map(Equipment::FieldRotator),
+        }
+    }
+
+    pub fn remove(&mut self, equipment_type: EquipmentType, id: &Uuid) -> Option<Equipment> {
+        match equipment_type {
+            EquipmentType::TELESCOPE => self.telescopes.remove(id).map(Equipment::Telescope),
+            EquipmentType::CAMERA => self.cameras.remove(id).map(Equipment::Camera),
+            EquipmentType::MOUNT => self.mounts.remove(id).map(Equipment::Mount),
+            EquipmentType::FILTER => self.filters.remove(id).map(Equipment::Filter),
+            EquipmentType::FLATTENER => self.flatteners.remove(id).map(Equipment::Flattener),
+            EquipmentType::FILTERWHEEL => self.filter_wheels.remove(id).map(Equipment::FilterWheel),
+            EquipmentType::FOCUSER => self.focusers.remove(id).map(Equipment::Focuser),
+            EquipmentType::GUIDESCOPE => self.guide_scopes.remove(id).map(Equipment::GuideScope),
+            EquipmentType::GUIDECAMERA => self.guide_cameras.remove(id).map(Equipment::GuideCamera),
+            EquipmentType::OFFAXISGUIDER => self.off_axis_guiders.remove(id).map(Equipment::OffAxisGuider),
+            EquipmentType::ROTATOR => self.rotators.remove(id).map(Equipment::Rotator),
+            EquipmentType::DEWHEATER => self.dew_heaters.remove(id).map(Equipment::DewHeater),
+            EquipmentType::FIELDROTATOR => self.field_rotators.remove(id).map(Equipment::FieldRotator),
+        }
+    }
+
+    // every item of every type
+    pub fn items(&self) -> Vec<&dyn EquipmentItem> {
+        EquipmentType::ALL
+            .iter()
+            .flat_map(|equipment_type| self.items_of(*equipment_type))
+            .collect()
+    }
+
+    pub fn items_of(&self, equipment_type: EquipmentType) -> Vec<&dyn EquipmentItem> {
+        fn dyn_items<T: EquipmentItem>(items: &HashMap<Uuid, T>) -> Vec<&dyn EquipmentItem> {
+            items.values().map(|item| item as &dyn EquipmentItem).collect()
+        }
+
+        match equipment_type {
+            EquipmentType::TELESCOPE => dyn_items(&self.telescopes),
+            EquipmentType::CAMERA => dyn_items(&self.cameras),
+            EquipmentType::MOUNT => dyn_items(&self.mounts),
+            EquipmentType::FILTER => dyn_items(&self.filters),
+            EquipmentType::FLATTENER => dyn_items(&self.flatteners),
+            EquipmentType::FILTERWHEEL => dyn_items(&self.filter_wheels),
+            EquipmentType::FOCUSER => dyn_items(&self.focusers),
+            EquipmentType::GUIDESCOPE => dyn_items(&self.guide_scopes),
+            EquipmentType::GUIDECAMERA => dyn_items(&self.guide_cameras),
+            EquipmentType::OFFAXISGUIDER => dyn_items(&self.off_axis_guiders),
+            EquipmentType::ROTATOR => dyn_items(&self.rotators),
+            EquipmentType::DEWHEATER => dyn_items(&self.dew_heaters),
+            EquipmentType::FIELDROTATOR => dyn_items(&self.field_rotators),
+        }
+    }
+
+    // items of the same type whose names are close to the given one and have the same model numbers,
+    // most similar first
+    pub fn duplicates(&self, equipment_type: EquipmentType, view_name: &str) -> Vec<DuplicateCandidate> {
+        let key = name_key(view_name);
+        let numbers = model_numbers(&key);
+        let mut candidates: Vec<DuplicateCandidate> = self
+            .items_of(equipment_type)
+            .into_iter()
+            .filter_map(|item| {
+                let item_key = name_key(&item.view_name());
+                (model_numbers(&item_key) == numbers).then(|| DuplicateCandidate {
+                    id: *item.id(),
+                    name: item.view_name(),
+                    similarity: similarity(&key, &item_key),
+                })
+            })
+            .filter(|candidate| candidate.similarity >= DUPLICATE_SIMILARITY)
+            .collect();
+
+        candidates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        candidates.truncate(5);
+        candidates
+    }
+
+    // number of optical trains and filter wheel slots that contain the equipment item
+    pub fn references(&self, equipment_type: EquipmentType, id: &Uuid) -> usize {
+        let trains = self
+            .optical_trains
+            .values()
+            .filter(|train| train.equipment_id(equipment_type) == Some(id))
+            .count();
+        let slots = match equipment_type {
+            EquipmentType::FILTER => self
+                .filter_wheels
+                .values()
+                .flat_map(|wheel| wheel.slots.iter())
+                .filter(|slot| slot.filter_id == *id)
+                .count(),
+            _ => 0,
+        };
+        trains + slots
+    }
+
+    pub fn reassign(&mut self, equipment_type: EquipmentType, from: &Uuid, to: &Uuid) {
+        for train in self.optical_trains.values_mut() {
+            if let Some(id) = train.equipment_id_mut(equipment_type).filter(|id| *id == from) {
+                *id = *to;
+            }
+        }
+        if equipment_type == EquipmentType::FILTER {
+            for slot in self.filter_wheels.values_mut().flat_map(|wheel| wheel.slots.iter_mut()) {
+                if slot.filter_id == *from {
+                    slot.filter_id = *to;
+                }
+            }
+        }
+    }
+
+    // the train an imported light frame was most likely taken with, by name if several fit
+    pub fn matching_train(&self, light_frame: &LightFrame) -> Option<&OpticalTrain> {
+        self.optical_trains
+            .values()
+            .filter(|train| train.matches(light_frame))
+            .min_by(|a, b| a.name.cmp(&b.name))
+    }
+
+    // the filter of the type the header names, preferring the ones in the given filter wheel
+    pub fn resolve_filter_type(&self, header: &str, filter_wheel_id: &Uuid) -> Option<Uuid> {
+        let filter_type = FilterType::from_header(header, &self.filter_aliases)?;
+        let candidates: Vec<&Filter> = self
+            .filters
+            .values()
+            .filter(|filter| filter.filter_type == filter_type && filter.lifecycle().is_active())
+            .collect();
+
+        if let Some(wheel) = self.filter_wheels.get(filter_wheel_id) {
+            if let Some(filter) = candidates.iter().find(|f| wheel.slots.iter().any(|s| s.filter_id == f.id)) {
+                return Some(filter.id);
+            }
+        }
+
+        match candidates[..] {
+            [filter] => Some(filter.id),
+            _ => None,
+        }
+    }
+
+    // the item whose aliases or name best match a header value like INSTRUME, TELESCOP or FILTER,
+    // None if nothing or several items match equally well
+    pub fn resolve<T: EquipmentItem>(items: &HashMap<Uuid, T>, header: &str) -> Option<Uuid> {
+        match EquipmentList::candidates(items, header)[..] {
+            [id] => Some(id),
+            _ => None,
+        }
+    }
+
+    // all items sharing the best match score for the header value, retired items are only kept
+    // for the sessions they were already used in
+    pub fn candidates<T: EquipmentItem>(items: &HashMap<Uuid, T>, header: &str) -> Vec<Uuid> {
+        let header = header.trim().to_lowercase();
+        if header.is_empty() {
+            return vec![];
+        }
+
+        let scored: Vec<(u8, Uuid)> = items
+            .values()
+            .filter(|item| item.lifecycle().is_active())
+            .filter_map(|item| match_score(item, &header).map(|score| (score, *item.id())))
+            .collect();
+
+        let best = scored.iter().map(|(score, _)| *score).max();
+        let mut ids: Vec<Uuid> = scored
+            .into_iter()
+            .filter(|(score, _)| Some(*score) == best)
+            .map(|(_, id)| id)
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub enum EquipmentType {
+    #[serde(rename = "Telescope")]
+    TELESCOPE,
+    #[serde(rename = "Camera")]
+    CAMERA,
+    #[serde(rename = "Mount")]
+    MOUNT,
+    #[serde(rename = "Filter")]
+    FILTER,
+    #[serde(rename = "Flattener")]
+    FLATTENER,
+    #[serde(rename = "Filter Wheel")]
+    FILTERWHEEL,
+    #[serde(rename = "Focuser")]
+    FOCUSER,
+    #[serde(rename = "Guide Scope")]
+    GUIDESCOPE,
+    #[serde(rename = "Guide Camera")]
+    GUIDECAMERA,
+    #[serde(rename = "Off-Axis Guider")]
+    OFFAXISGUIDER,
+    #[serde(rename = "Rotator")]
+    ROTATOR,
+    #[serde(rename = "Dew Heater")]
+    DEWHEATER,
+    #[serde(rename = "Field Rotator")]
+    FIELDROTATOR,
+}
+
+impl EquipmentType {
+    pub const ALL: [EquipmentType; 13] = [
+        EquipmentType::TELESCOPE,
+        EquipmentType::CAMERA,
+        EquipmentType::MOUNT,
+        EquipmentType::FILTER,
+        EquipmentType::FLATTENER,
+        EquipmentType::FILTERWHEEL,
+        EquipmentType::FOCUSER,
+        EquipmentType::GUIDESCOPE,
+        EquipmentType::GUIDECAMERA,
+        EquipmentType::OFFAXISGUIDER,
+        EquipmentType::ROTATOR,
+        EquipmentType::DEWHEATER,
+        EquipmentType::FIELDROTATOR,
+    ];
+
+    // everything besides the main imaging equipment
+    pub const ACCESSORIES: [EquipmentType; 8] = [
+        EquipmentType::FILTERWHEEL,
+        EquipmentType::FOCUSER,
+        EquipmentType::GUIDESCOPE,
+        EquipmentType::GUIDECAMERA,
+        EquipmentType::OFFAXISGUIDER,
+        EquipmentType::ROTATOR,
+        EquipmentType::DEWHEATER,
+        EquipmentType::FIELDROTATOR,
+    ];
+}
+
+#[derive(Clone, Debug)]
+pub enum Equipment {
+    Telescope(Telescope),
+    Camera(Camera),
+    Mount(Mount),
+    Filter(Filter),
+    Flattener(Flattener),
+    FilterWheel(FilterWheel),
+    Focuser(Focuser),
+    GuideScope(GuideScope),
+    GuideCamera(GuideCamera),
+    OffAxisGuider(OffAxisGuider),
+    Rotator(Rotator),
+    DewHeater(DewHeater),
+    FieldRotator(FieldRotator),
+}
+
+impl Equipment {
+    pub fn from_value(equipment_type: EquipmentType, value: Value) -> Result<Equipment, serde_json::Error> {
+        Ok(match equipment_type {
+            EquipmentType::TELESCOPE => Equipment::Telescope(serde_json::from_value(value)?),
+            EquipmentType::CAMERA => {
+                let mut camera: Camera = serde_json::from_value(value)?;
+                camera.migrate_sensor();
+                Equipment::Camera(camera)
+            }
+            EquipmentType::MOUNT => Equipment::Mount(serde_json::from_value(value)?),
+            EquipmentType::FILTER => Equipment::Filter(serde_json::from_value(value)?),
+            EquipmentType::FLATTENER => Equipment::Flattener(serde_json::from_value(value)?),
+            EquipmentType::FILTERWHEEL => Equipment::FilterWheel(serde_json::from_value(value)?),
+            EquipmentType::FOCUSER => Equipment::Focuser(serde_json::from_value(value)?),
+            EquipmentType::GUIDESCOPE => Equipment::GuideScope(serde_json::from_value(value)?),
+            EquipmentType::GUIDECAMERA => Equipment::GuideCamera(serde_json::from_value(value)?),
+            EquipmentType::OFFAXISGUIDER => Equipment::OffAxisGuider(serde_json::from_value(value)?),
+            EquipmentType::ROTATOR => Equipment::Rotator(serde_json::from_value(value)?),
+            EquipmentType::DEWHEATER => Equipment::DewHeater(serde_json::from_value(value)?),
+            EquipmentType::FIELDROTATOR => Equipment::FieldRotator(serde_json::from_value(value)?),
+        })
+    }
+
+    pub fn equipment_type(&self) -> EquipmentType {
+        match self {
+            Equipment::Telescope(_) => EquipmentType::TELESCOPE,
+            Equipment::Camera(_) => EquipmentType::CAMERA,
+            Equipment::Mount(_) => EquipmentType::MOUNT,
+            Equipment::Filter(_) => EquipmentType::FILTER,
+            Equipment::Flattener(_) => EquipmentType::FLATTENER,
+            Equipment::FilterWheel(_) => EquipmentType::FILTERWHEEL,
+            Equipment::Focuser(_) => EquipmentType::FOCUSER,
+            Equipment::GuideScope(_) => EquipmentType::GUIDESCOPE,
+            Equipment::GuideCamera(_) => EquipmentType::GUIDECAMERA,
+            Equipment::OffAxisGuider(_) => EquipmentType::OFFAXISGUIDER,
+            Equipment::Rotator(_) => EquipmentType::ROTATOR,
+            Equipment::DewHeater(_) => EquipmentType::DEWHEATER,
+            Equipment::FieldRotator(_) => EquipmentType::FIELDROTATOR,
+        }
+    }
+
+    pub fn item(&self) -> &dyn EquipmentItem {
+        match self {
+            Equipment::Telescope(t) => t,
+            Equipment::Camera(c) => c,
+            Equipment::Mount(m) => m,
+            Equipment::Filter(f) => f,
+            Equipment::Flattener(fl) => fl,
+            Equipment::FilterWheel(fw) => fw,
+            Equipment::Focuser(fo) => fo,
+            Equipment::GuideScope(gs) => gs,
+            Equipment::GuideCamera(gc) => gc,
+            Equipment::OffAxisGuider(oag) => oag,
+            Equipment::Rotator(r) => r,
+            Equipment::DewHeater(dh) => dh,
+            Equipment::FieldRotator(fr) => fr,
+        }
+    }
+
+    pub fn item_mut(&mut self) -> &mut dyn EquipmentItem {
+        match self {
+            Equipment::Telescope(t) => t,
+            Equipment::Camera(c) => c,
+            Equipment::Mount(m) => m,
+            Equipment::Filter(f) => f,
+            Equipment::Flattener(fl) => fl,
+            Equipment::FilterWheel(fw) => fw,
+            Equipment::Focuser(fo) => fo,
+            Equipment::GuideScope(gs) => gs,
+            Equipment::GuideCamera(gc) => gc,
+            Equipment::OffAxisGuider(oag) => oag,
+            Equipment::Rotator(r) => r,
+            Equipment::DewHeater(dh) => dh,
+            Equipment::FieldRotator(fr) => fr,
+        }
+    }
 }
 
 pub trait EquipmentItem {
-    // fn id(&self) -> &Uuid;
+    fn id(&self) -> &Uuid;
     fn brand(&self) -> &str;
     fn name(&self) -> &str;
+    fn aliases(&self) -> &[HeaderAlias];
+    fn aliases_mut(&mut self) -> &mut Vec<HeaderAlias>;
+    fn lifecycle(&self) -> &Lifecycle;
+    fn lifecycle_mut(&mut self) -> &mut Lifecycle;
     fn view_name(&self) -> String {
         format!("{} {}", self.brand(), self.name())
     }
 }
 
+// header aliases and lifecycle every kind of equipment has, flattened so the saved items keep their layout
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ItemCommon {
+    // header values this item is recognized by
+    #[serde(default)]
+    aliases: Vec<HeaderAlias>,
+    #[serde(default)]
+    lifecycle: Lifecycle,
+}
+
+macro_rules! impl_equipment_item {
+    ($($item:ty),*) => {
+        $(
+            impl EquipmentItem for $item {
+                fn id(&self) -> &Uuid {
+                    &self.id
+                }
+                fn brand(&self) -> &str {
+                    &self.brand
+                }
+                fn name(&self) -> &str {
+                    &self.name
+                }
+                fn aliases(&self) -> &[HeaderAlias] {
+                    &self.common.aliases
+                }
+                fn aliases_mut(&mut self) -> &mut Vec<HeaderAlias> {
+                    &mut self.common.aliases
+                }
+                fn lifecycle(&self) -> &Lifecycle {
+                    &self.common.lifecycle
+                }
+                fn lifecycle_mut(&mut self) -> &mut Lifecycle {
+                    &mut self.common.lifecycle
+                }
+            }
+        )*
+    };
+}
+
+impl_equipment_item!(
+    Telescope,
+    Camera,
+    Mount,
+    Filter,
+    Flattener,
+    FilterWheel,
+    Focuser,
+    GuideScope,
+    GuideCamera,
+    OffAxisGuider,
+    Rotator,
+    DewHeater,
+    FieldRotator
+);
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Telescope {
     pub id: Uuid,
     brand: String,
     name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
 
     focal_length: i32,
     aperture: i32,
 }
 
-impl EquipmentItem for Telescope {
-    // fn id(&self) -> &Uuid {
-    //     &self.id
-    // }
-    fn brand(&self) -> &str {
-        &self.brand
+impl Telescope {
+    pub fn focal_length(&self) -> i32 {
+        self.focal_length
     }
-    fn name(&self) -> &str {
-        &self.name
+
+    pub fn aperture(&self) -> i32 {
+        self.aperture
     }
 }
 
@@ -152,21 +659,161 @@ pub struct Camera {
     id: Uuid,
     brand: String,
     name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
 
+    // free-form description kept from before the sensor was structured
+    #[serde(default)]
     chip_size: String,
     mega_pixel: f64,
     rgb: bool,
+    #[serde(default)]
+    sensor: Sensor,
 }
 
-impl EquipmentItem for Camera {
-    // fn id(&self) -> &Uuid {
-    //     &self.id
-    // }
-    fn brand(&self) -> &str {
-        &self.brand
+impl Camera {
+    // fills in the sensor from the chip size and megapixels of cameras created before it existed
+    fn migrate_sensor(&mut self) {
+        if self.sensor.size.is_none() {
+            self.sensor.size = parse_chip_size(&self.chip_size);
+        }
+
+        if let Some((width, height)) = self.sensor.size {
+            if self.sensor.resolution.is_none() && self.mega_pixel > 0.0 {
+                let horizontal_pixels = (self.mega_pixel * 1_000_000.0 * width / height).sqrt();
+                let vertical_pixels = horizontal_pixels * height / width;
+                self.sensor.resolution = Some((horizontal_pixels.round() as u32, vertical_pixels.round() as u32));
+            }
+        }
+
+        if self.sensor.pixel_size.is_none() {
+            self.sensor.pixel_size = match (self.sensor.size, self.sensor.resolution) {
+                (Some((width, _)), Some((horizontal_pixels, _))) if horizontal_pixels > 0 => {
+                    Some(width * 1000.0 / horizontal_pixels as f64)
+                }
+                _ => None,
+            };
+        }
     }
-    fn name(&self) -> &str {
-        &self.name
+
+    // sensor width and height in mm
+    pub fn sensor_size(&self) -> Option<(f64, f64)> {
+        self.sensor.size.or_else(|| {
+            let (pixel_size, (horizontal_pixels, vertical_pixels)) = (self.sensor.pixel_size?, self.sensor.resolution?);
+            Some((
+                pixel_size * horizontal_pixels as f64 / 1000.0,
+                pixel_size * vertical_pixels as f64 / 1000.0,
+            ))
+        })
+    }
+
+    // pixel size in µm
+    pub fn pixel_size(&self) -> Option<f64> {
+        self.sensor.pixel_size
+    }
+
+    // gain at which one ADU equals one electron, estimated from the full well curve and the bit depth
+    pub fn unity_gain(&self) -> Option<f64> {
+        let levels = 2f64.powi(self.sensor.bit_depth? as i32);
+        let mut curve = self.sensor.full_well.clone();
+        curve.sort_by_key(|point| point.gain);
+
+        curve.windows(2).find_map(|pair| {
+            let (low, high) = (&pair[0], &pair[1]);
+            let (low_e, high_e) = (low.value / levels, high.value / levels);
+            if (low_e - 1.0) * (high_e - 1.0) > 0.0 || low_e == high_e {
+                return None;
+            }
+            Some(low.gain as f64 + (low_e - 1.0) / (low_e - high_e) * (high.gain - low.gain) as f64)
+        })
+    }
+
+    // read noise in e- at the given gain
+    pub fn read_noise(&self, gain: i32) -> Option<f64> {
+        interpolate(&self.sensor.read_noise, gain)
+    }
+
+    // full well capacity in e- at the given gain
+    pub fn full_well(&self, gain: i32) -> Option<f64> {
+        interpolate(&self.sensor.full_well, gain)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sensor {
+    // µm
+    pixel_size: Option<f64>,
+    // pixels (width, height)
+    resolution: Option<(u32, u32)>,
+    // mm (width, height)
+    size: Option<(f64, f64)>,
+    // None for mono sensors
+    bayer_pattern: Option<BayerPattern>,
+    bit_depth: Option<u8>,
+    cooled: bool,
+    // e- by gain
+    full_well: Vec<GainValue>,
+    // e- by gain
+    read_noise: Vec<GainValue>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum BayerPattern {
+    RGGB,
+    BGGR,
+    GRBG,
+    GBRG,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GainValue {
+    gain: i32,
+    value: f64,
+}
+
+// linear interpolation between the measured gains, clamped to the measured range
+fn interpolate(curve: &[GainValue], gain: i32) -> Option<f64> {
+    let below = curve.iter().filter(|point| point.gain <= gain).max_by_key(|point| point.gain);
+    let above = curve.iter().filter(|point| point.gain >= gain).min_by_key(|point| point.gain);
+
+    match (below, above) {
+        (Some(low), Some(high)) if high.gain > low.gain => {
+            let t = (gain - low.gain) as f64 / (high.gain - low.gain) as f64;
+            Some(low.value + t * (high.value - low.value))
+        }
+        (Some(point), _) | (None, Some(point)) => Some(point.value),
+        (None, None) => None,
+    }
+}
+
+// parses chip sizes like "23.5 x 15.7 mm" or "APS-C" into width and height in mm
+fn parse_chip_size(chip_size: &str) -> Option<(f64, f64)> {
+    let chip_size = chip_size.to_lowercase();
+
+    let named = [
+        ("full frame", (36.0, 24.0)),
+        ("aps-h", (28.7, 19.0)),
+        ("aps-c", (23.5, 15.6)),
+        ("four thirds", (17.3, 13.0)),
+        ("4/3", (17.3, 13.0)),
+    ];
+    if let Some((_, size)) = named.iter().find(|(name, _)| chip_size.contains(name)) {
+        return Some(*size);
+    }
+
+    let values: Vec<f64> = chip_size
+        .replace("mm", "")
+        .split(|c: char| c == 'x' || c == '*' || c == '×')
+        .filter_map(|value| value.trim().replace(',', ".").parse().ok())
+        .collect();
+
+    match values[..] {
+        // larger values are pixel resolutions, not millimeters
+        [width, height] if width > 0.0 && height > 0.0 && width < 100.0 && height < 100.0 => {
+            Some((width.max(height), width.min(height)))
+        }
+        _ => None,
     }
 }
 
@@ -175,58 +822,338 @@ pub struct Mount {
     id: Uuid,
     brand: String,
     name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
 }
 
-impl EquipmentItem for Mount {
-    // fn id(&self) -> &Uuid {
-    //     &self.id
-    // }
-    fn brand(&self) -> &str {
-        &self.brand
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "FilterRecord")]
+pub struct Filter {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    filter_type: FilterType,
+    // the free-form type of older logs that names no known type, only kept while the type is OTHER
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filter_type_label: Option<String>,
+    // nm
+    #[serde(default)]
+    central_wavelength: Option<f64>,
+    // nm
+    #[serde(default)]
+    bandwidth: Option<f64>,
+    // size and mount, like 1.25", 2" or 36 mm unmounted
+    #[serde(default)]
+    size: Option<String>,
+}
+
+impl Filter {
+    pub fn filter_type(&self) -> FilterType {
+        self.filter_type
     }
-    fn name(&self) -> &str {
-        &self.name
+}
+
+// filter types used to be free-form strings
+#[derive(Deserialize)]
+struct FilterRecord {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+    filter_type: String,
+    #[serde(default)]
+    filter_type_label: Option<String>,
+    #[serde(default)]
+    central_wavelength: Option<f64>,
+    #[serde(default)]
+    bandwidth: Option<f64>,
+    #[serde(default)]
+    size: Option<String>,
+}
+
+impl From<FilterRecord> for Filter {
+    fn from(record: FilterRecord) -> Self {
+        let (filter_type, label) = parse_filter_type(&record.filter_type);
+
+        Filter {
+            id: record.id,
+            brand: record.brand,
+            name: record.name,
+            common: record.common,
+            filter_type,
+            filter_type_label: match filter_type {
+                FilterType::OTHER => record.filter_type_label.or(label),
+                _ => None,
+            },
+            central_wavelength: record.central_wavelength,
+            bandwidth: record.bandwidth,
+            size: record.size,
+        }
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Filter {
+pub struct Flattener {
     id: Uuid,
     brand: String,
     name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
 
-    filter_type: String,
+    factor: f64,
 }
 
-impl EquipmentItem for Filter {
-    // fn id(&self) -> &Uuid {
-    //     &self.id
-    // }
-    fn brand(&self) -> &str {
-        &self.brand
+impl Flattener {
+    pub fn factor(&self) -> f64 {
+        self.factor
     }
-    fn name(&self) -> &str {
-        &self.name
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FilterWheel {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    // filters by wheel position
+    slots: Vec<FilterSlot>,
+}
+
+impl FilterWheel {
+    pub fn filter_in_slot(&self, position: u32) -> Option<&Uuid> {
+        self.slots
+            .iter()
+            .find(|slot| slot.position == position)
+            .map(|slot| &slot.filter_id)
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Flattener {
+pub struct FilterSlot {
+    position: u32,
+    filter_id: Uuid,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Focuser {
     id: Uuid,
     brand: String,
     name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
 
-    factor: f64,
+    // steps
+    max_position: Option<i32>,
+    // µm per step
+    step_size: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GuideScope {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    focal_length: i32,
+    aperture: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GuideCamera {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    // µm
+    pixel_size: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OffAxisGuider {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    // mm
+    prism_size: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Rotator {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    // degrees per step
+    step_size: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DewHeater {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    channels: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FieldRotator {
+    id: Uuid,
+    brand: String,
+    name: String,
+    #[serde(flatten)]
+    common: ItemCommon,
+
+    // degrees
+    max_rotation: Option<f64>,
+}
+
+fn to_map<T: EquipmentItem>(items: Vec<T>) -> HashMap<Uuid, T> {
+    items.into_iter().map(|item| (*item.id(), item)).collect()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum MatchRule {
+    EXACT,
+    PREFIX,
+    CONTAINS,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HeaderAlias {
+    pub pattern: String,
+    pub rule: MatchRule,
 }
 
-impl EquipmentItem for Flattener {
-    // fn id(&self) -> &Uuid {
-    //     &self.id
-    // }
-    fn brand(&self) -> &str {
-        &self.brand
+impl HeaderAlias {
+    fn matches(&self, header: &str) -> bool {
+        let pattern = self.pattern.trim().to_lowercase();
+        !pattern.is_empty()
+            && match self.rule {
+                MatchRule::EXACT => header == pattern,
+                MatchRule::PREFIX => header.starts_with(&pattern),
+                MatchRule::CONTAINS => header.contains(&pattern),
+            }
     }
-    fn name(&self) -> &str {
-        &self.name
+}
+
+// exact aliases win over exact names, which win over partial aliases and partial names
+fn match_score<T: EquipmentItem>(item: &T, header: &str) -> Option<u8> {
+    let alias_score = item
+        .aliases()
+        .iter()
+        .filter(|alias| alias.matches(header))
+        .map(|alias| match alias.rule {
+            MatchRule::EXACT => 5,
+            MatchRule::PREFIX => 3,
+            MatchRule::CONTAINS => 2,
+        })
+        .max();
+
+    let view_name = item.view_name().to_lowercase();
+    let name = item.name().to_lowercase();
+    let header_tokens = tokens(header);
+    let name_score = if view_name == header || name == header {
+        Some(4)
+    } else if contains_tokens(&header_tokens, &tokens(&name))
+        || contains_tokens(&tokens(&view_name), &header_tokens)
+    {
+        Some(1)
+    } else {
+        None
+    };
+
+    alias_score.max(name_score)
+}
+
+// partial matches shorter than this, like a slot number or a single letter, fit far too many items
+const MIN_PARTIAL_MATCH: usize = 3;
+
+// "Antlia Ha 3nm" has the tokens "antlia", "ha" and "3nm"
+fn tokens(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+// whether every token of the part is a whole token of the text, and the part is long enough to mean something
+fn contains_tokens(text: &[&str], part: &[&str]) -> bool {
+    part.iter().map(|token| token.len()).sum::<usize>() >= MIN_PARTIAL_MATCH
+        && part.iter().any(|token| token.chars().any(char::is_alphabetic))
+        && part.iter().all(|token| text.contains(token))
+}
+
+// names at least this similar are offered for a merge
+const DUPLICATE_SIMILARITY: f64 = 0.8;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DuplicateCandidate {
+    pub id: Uuid,
+    pub name: String,
+    // 1.0 if the names only differ in case, whitespace or punctuation
+    pub similarity: f64,
+}
+
+// "ZWO ASI-294MC" and "zwo  asi294mc" share the key "zwoasi294mc"
+fn name_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+// "zwoasi294mc" has the model number "294mc", a number runs from its first digit to the next one
+// after letters. names with other model numbers are other items, like the ASI294MC and ASI294MM
+fn model_numbers(key: &str) -> Vec<String> {
+    let mut numbers: Vec<String> = vec![];
+    let mut previous: Option<char> = None;
+    for c in key.chars() {
+        if c.is_ascii_digit() && !previous.is_some_and(|p| p.is_ascii_digit()) {
+            numbers.push(String::new());
+        }
+        if let Some(number) = numbers.last_mut() {
+            number.push(c);
+        }
+        previous = Some(c);
     }
+    numbers
+}
+
+// 1 minus the edit distance relative to the longer key
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    1.0 - previous[b.len()] as f64 / longest as f64
 }
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum FilterType {
+    L,
+    R,
+    G,
+    B,
+    HA,
+    OIII,
+    SII,
+    DUALBAND,
+    UVIRCUT,
+    LIGHTPOLLUTION,
+    OTHER,
+}
+
+// header value or name written by capture software, mapped to a channel
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FilterAlias {
+    pub alias: String,
+    pub filter_type: FilterType,
+}
+
+const DEFAULT_ALIASES: [(&str, FilterType); 40] = [
+    ("l", FilterType::L),
+    ("lum", FilterType::L),
+    ("luminance", FilterType::L),
+    ("clear", FilterType::L),
+    ("r", FilterType::R),
+    ("red", FilterType::R),
+    ("g", FilterType::G),
+    ("green", FilterType::G),
+    ("b", FilterType::B),
+    ("blue", FilterType::B),
+    ("ha", FilterType::HA),
+    ("h-a", FilterType::HA),
+    ("h-alpha", FilterType::HA),
+    ("halpha", FilterType::HA),
+    ("h alpha", FilterType::HA),
+    ("hα", FilterType::HA),
+    ("oiii", FilterType::OIII),
+    ("o3", FilterType::OIII),
+    ("o-iii", FilterType::OIII),
+    ("o iii", FilterType::OIII),
+    ("sii", FilterType::SII),
+    ("s2", FilterType::SII),
+    ("s-ii", FilterType::SII),
+    ("s ii", FilterType::SII),
+    ("dual", FilterType::DUALBAND),
+    ("dualband", FilterType::DUALBAND),
+    ("dual-band", FilterType::DUALBAND),
+    ("duo", FilterType::DUALBAND),
+    ("l-extreme", FilterType::DUALBAND),
+    ("l-enhance", FilterType::DUALBAND),
+    ("l-ultimate", FilterType::DUALBAND),
+    ("uv/ir", FilterType::UVIRCUT),
+    ("uvir", FilterType::UVIRCUT),
+    ("uv-ir", FilterType::UVIRCUT),
+    ("ir-cut", FilterType::UVIRCUT),
+    ("lp", FilterType::LIGHTPOLLUTION),
+    ("cls", FilterType::LIGHTPOLLUTION),
+    ("lpr", FilterType::LIGHTPOLLUTION),
+    ("l-pro", FilterType::LIGHTPOLLUTION),
+    ("light pollution", FilterType::LIGHTPOLLUTION),
+];
+
+impl FilterType {
+    // maps a header value like "Ha", "H-alpha" or "HA 7nm" to a channel, user aliases first
+    pub fn from_header(header: &str, aliases: &[FilterAlias]) -> Option<FilterType> {
+        let header = normalize(header);
+        if header.is_empty() {
+            return None;
+        }
+
+        let user = aliases.iter().map(|a| (normalize(&a.alias), a.filter_type));
+        let defaults = DEFAULT_ALIASES.iter().map(|(alias, t)| (alias.to_string(), *t));
+        let table: Vec<(String, FilterType)> = user.chain(defaults).collect();
+
+        // the whole value first, then its words, so "Antlia Ha 3nm" still maps to HA
+        table
+            .iter()
+            .find(|(alias, _)| *alias == header)
+            .or_else(|| {
+                header
+                    .split(|c: char| c.is_whitespace() || c == '_' || c == ',')
+                    .find_map(|word| table.iter().find(|(alias, _)| alias == word))
+            })
+            .map(|(_, filter_type)| *filter_type)
+    }
+}
+
+// drops bandwidths like "7nm" or "3 nm"
+fn normalize(value: &str) -> String {
+    let value = value.trim().to_lowercase();
+    let words: Vec<&str> = value.split_whitespace().collect();
+
+    let mut result: Vec<&str> = vec![];
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+        let is_number = |w: &str| w.replace(',', ".").parse::<f64>().is_ok();
+        if word.ends_with("nm") && is_number(&word[..word.len() - 2]) {
+            i += 1;
+        } else if is_number(word) && words.get(i + 1) == Some(&"nm") {
+            i += 2;
+        } else {
+            result.push(word);
+            i += 1;
+        }
+    }
+    result.join(" ")
+}
+
+// filter types used to be free-form strings, text that names no known type is returned as the label
+pub fn parse_filter_type(value: &str) -> (FilterType, Option<String>) {
+    let typed: Result<FilterType, _> = serde_json::from_value(serde_json::Value::String(value.to_string()));
+
+    match typed.ok().or_else(|| FilterType::from_header(value, &[])) {
+        Some(filter_type) => (filter_type, None),
+        None => (FilterType::OTHER, Some(value.trim().to_string()).filter(|label| !label.is_empty())),
+    }
+}
@@ -1,4 +1,12 @@
+use crate::models::equipment::{EquipmentItem, EquipmentType};
+use crate::models::filters::FilterType;
+use crate::models::imaging_frames::LightFrame;
+use crate::models::lifecycle::MaintenanceEvent;
+use crate::models::state::AppState;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Analytics {
@@ -6,26 +14,66 @@ pub struct Analytics {
     info_cards: Vec<InfoCard>,
     equipment_chart: EquipmentChart,
     integration_chart: IntegrationChart,
+    optical_trains: Vec<OpticalTrainUsage>,
+    equipment_usage: Vec<EquipmentUsage>,
+    sites: Vec<SiteUsage>,
+    maintenance_timeline: Vec<MaintenanceEvent>,
+    altitude_quality: AltitudeQuality,
 }
 
 impl Analytics {
-    pub fn new() -> Analytics {
+    pub fn new(app_state: &AppState) -> Analytics {
         let sessions_chart = SessionsChart {};
 
         let info_cards: Vec<InfoCard> = vec![];
 
-        let pie_chart_data = PieChartData {};
+        let equipment_usage: Vec<EquipmentUsage> = EquipmentType::ALL
+            .iter()
+            .flat_map(|equipment_type| {
+                app_state
+                    .equipment_list
+                    .items_of(*equipment_type)
+                    .into_iter()
+                    .map(|item| EquipmentUsage::new(*equipment_type, item, app_state))
+            })
+            .collect();
+
         let equipment_chart = EquipmentChart {
-            mounts: pie_chart_data,
+            telescopes: PieChartData::new(EquipmentType::TELESCOPE, &equipment_usage),
+            cameras: PieChartData::new(EquipmentType::CAMERA, &equipment_usage),
+            mounts: PieChartData::new(EquipmentType::MOUNT, &equipment_usage),
+        };
+
+        let integration_chart = IntegrationChart {
+            integrated_subs: 0,
+            channels: ChannelIntegration::by_channel(app_state),
         };
 
-        let integration_chart = IntegrationChart { integrated_subs: 0 };
+        let mut optical_trains: Vec<OpticalTrainUsage> = app_state
+            .equipment_list
+            .optical_trains
+            .values()
+            .map(|train| OpticalTrainUsage::new(train.id, &train.name, app_state))
+            .collect();
+        optical_trains.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut sites: Vec<SiteUsage> = app_state
+            .sites
+            .values()
+            .map(|site| SiteUsage::new(site.id, &site.name, app_state))
+            .collect();
+        sites.sort_by(|a, b| b.integration_time.total_cmp(&a.integration_time));
 
         Analytics {
             sessions_chart,
             info_cards,
             equipment_chart,
             integration_chart,
+            optical_trains,
+            equipment_usage,
+            sites,
+            maintenance_timeline: MaintenanceEvent::timeline(&app_state.equipment_list),
+            altitude_quality: AltitudeQuality::new(app_state),
         }
     }
 }
@@ -41,13 +89,337 @@ struct InfoCard {
 
 #[derive(Serialize, Deserialize, Debug)]
 struct EquipmentChart {
+    telescopes: PieChartData,
+    cameras: PieChartData,
     mounts: PieChartData,
 }
 
+// integration time per item of one equipment type
 #[derive(Serialize, Deserialize, Debug)]
-struct PieChartData {}
+struct PieChartData {
+    slices: Vec<PieSlice>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct PieSlice {
+    id: Uuid,
+    label: String,
+    // seconds
+    value: f64,
+}
+
+impl PieChartData {
+    fn new(equipment_type: EquipmentType, usage: &[EquipmentUsage]) -> PieChartData {
+        let mut slices: Vec<PieSlice> = usage
+            .iter()
+            .filter(|u| u.equipment_type == equipment_type && u.integration_time > 0.0)
+            .map(|u| PieSlice {
+                id: u.id,
+                label: u.name.clone(),
+                value: u.integration_time,
+            })
+            .collect();
+        slices.sort_by(|a, b| b.value.total_cmp(&a.value));
+        PieChartData { slices }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct EquipmentUsage {
+    id: Uuid,
+    equipment_type: EquipmentType,
+    name: String,
+    nights: usize,
+    light_frames: usize,
+    // seconds
+    integration_time: f64,
+    targets: Vec<String>,
+    first_use: Option<NaiveDate>,
+    last_use: Option<NaiveDate>,
+    // averages over the accepted subs that have the value
+    average_hfr: Option<f64>,
+    average_star_count: Option<f64>,
+    // share of the subs that were not rejected
+    acceptance_rate: Option<f64>,
+}
+
+impl EquipmentUsage {
+    fn new(equipment_type: EquipmentType, item: &dyn EquipmentItem, app_state: &AppState) -> EquipmentUsage {
+        let light_frames: Vec<&LightFrame> = app_state
+            .imaging_frame_list
+            .light_frames
+            .values()
+            .filter(|light_frame| light_frame.equipment_id(equipment_type) == item.id())
+            .collect();
+
+        let nights: HashSet<_> = light_frames.iter().map(|light_frame| light_frame.date).collect();
+        let mut targets: Vec<String> = light_frames
+            .iter()
+            .map(|light_frame| light_frame.target.clone())
+            .filter(|target| !target.is_empty())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        targets.sort();
+
+        let accepted: Vec<_> = light_frames
+            .iter()
+            .flat_map(|light_frame| light_frame.frames.iter())
+            .filter(|sub| !sub.rejected)
+            .collect();
+        let average = |values: Vec<f64>| match values.len() {
+            0 => None,
+            len => Some(values.iter().sum::<f64>() / len as f64),
+        };
+
+        let total_subs: i32 = light_frames.iter().map(|light_frame| light_frame.total_subs).sum();
+        let integrated_subs: i32 = light_frames.iter().map(|light_frame| light_frame.integrated_subs()).sum();
+
+        EquipmentUsage {
+            id: *item.id(),
+            equipment_type,
+            name: item.view_name(),
+            nights: nights.len(),
+            light_frames: light_frames.len(),
+            integration_time: light_frames
+                .iter()
+                .map(|light_frame| light_frame.integrated_subs() as f64 * light_frame.sub_length)
+                .sum(),
+            targets,
+            first_use: nights.iter().min().copied(),
+            last_use: nights.iter().max().copied(),
+            average_hfr: average(accepted.iter().filter_map(|sub| sub.hfr).collect()),
+            average_star_count: average(accepted.iter().filter_map(|sub| sub.star_count.map(f64::from)).collect()),
+            acceptance_rate: match total_subs {
+                0 => None,
+                _ => Some(integrated_subs as f64 / total_subs as f64),
+            },
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 struct IntegrationChart {
     integrated_subs: i32,
+    channels: Vec<ChannelIntegration>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ChannelIntegration {
+    // None for light frames without a known filter
+    channel: Option<FilterType>,
+    // seconds
+    integration_time: f64,
+    integrated_subs: i32,
+}
+
+impl ChannelIntegration {
+    fn by_channel(app_state: &AppState) -> Vec<ChannelIntegration> {
+        let mut channels: HashMap<Option<FilterType>, ChannelIntegration> = HashMap::new();
+
+        for light_frame in app_state.imaging_frame_list.light_frames.values() {
+            let channel = app_state
+                .equipment_list
+                .filters
+                .get(&light_frame.filter_id)
+                .map(|filter| filter.filter_type());
+
+            let entry = channels.entry(channel).or_insert(ChannelIntegration {
+                channel,
+                integration_time: 0.0,
+                integrated_subs: 0,
+            });
+            entry.integration_time += light_frame.integrated_subs() as f64 * light_frame.sub_length;
+            entry.integrated_subs += light_frame.integrated_subs();
+        }
+
+        let mut channels: Vec<ChannelIntegration> = channels.into_values().collect();
+        channels.sort_by(|a, b| b.integration_time.total_cmp(&a.integration_time));
+        channels
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct OpticalTrainUsage {
+    id: Uuid,
+    name: String,
+    sessions: usize,
+    nights: usize,
+    light_frames: usize,
+    // seconds
+    integration_time: f64,
+}
+
+impl OpticalTrainUsage {
+    fn new(id: Uuid, name: &str, app_state: &AppState) -> OpticalTrainUsage {
+        let light_frames: Vec<_> = app_state
+            .imaging_frame_list
+            .light_frames
+            .values()
+            .filter(|light_frame| light_frame.optical_train_id == Some(id))
+            .collect();
+
+        let nights: HashSet<_> = light_frames.iter().map(|light_frame| light_frame.date).collect();
+        let sessions = app_state
+            .imaging_sessions
+            .values()
+            .filter(|session| {
+                light_frames
+                    .iter()
+                    .any(|light_frame| session.light_frame_ids.contains(&light_frame.id))
+            })
+            .count();
+
+        OpticalTrainUsage {
+            id,
+            name: name.to_string(),
+            sessions,
+            nights: nights.len(),
+            light_frames: light_frames.len(),
+            integration_time: light_frames
+                .iter()
+                .map(|light_frame| light_frame.integrated_subs() as f64 * light_frame.sub_length)
+                .sum(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct SiteUsage {
+    id: Uuid,
+    name: String,
+    sessions: usize,
+    nights: usize,
+    // seconds
+    integration_time: f64,
+}
+
+impl SiteUsage {
+    fn new(id: Uuid, name: &str, app_state: &AppState) -> SiteUsage {
+        let light_frames: Vec<&LightFrame> = app_state
+            .imaging_sessions
+            .values()
+            .filter(|session| session.site_id == Some(id))
+            .flat_map(|session| session.light_frame_ids.iter())
+            .filter_map(|id| app_state.imaging_frame_list.light_frames.get(id))
+            .collect();
+        let nights: HashSet<_> = light_frames.iter().map(|light_frame| light_frame.date).collect();
+
+        SiteUsage {
+            id,
+            name: name.to_string(),
+            sessions: app_state
+                .imaging_sessions
+                .values()
+                .filter(|session| session.site_id == Some(id))
+                .count(),
+            nights: nights.len(),
+            integration_time: light_frames
+                .iter()
+                .map(|light_frame| light_frame.integrated_subs() as f64 * light_frame.sub_length)
+                .sum(),
+        }
+    }
+}
+
+// degrees
+const ALTITUDE_BIN: f64 = 10.0;
+// bins with fewer subs are too noisy to judge
+const MIN_BIN_SUBS: usize = 10;
+// a bin is degraded once its stars are this much larger than above it
+const DEGRADATION: f64 = 1.1;
+
+// sub quality by target altitude over all sessions. hfr and star count are relative to the median
+// of their light frame set, so different optics and nights can be compared
+#[derive(Serialize, Deserialize, Debug)]
+struct AltitudeQuality {
+    bins: Vec<AltitudeBin>,
+    // degrees, below this the stars start to bloat
+    degradation_altitude: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct AltitudeBin {
+    // lower edge in degrees
+    altitude: f64,
+    subs: usize,
+    relative_hfr: Option<f64>,
+    relative_star_count: Option<f64>,
+    rejection_rate: f64,
+}
+
+impl AltitudeQuality {
+    fn new(app_state: &AppState) -> AltitudeQuality {
+        // altitude, relative hfr, relative star count, rejected
+        let mut samples: Vec<(f64, Option<f64>, Option<f64>, bool)> = vec![];
+
+        for light_frame in app_state.imaging_frame_list.light_frames.values() {
+            let median_hfr = median(light_frame.frames.iter().filter_map(|sub| sub.hfr).collect());
+            let median_stars = median(light_frame.frames.iter().filter_map(|sub| sub.star_count.map(f64::from)).collect());
+
+            for sub in &light_frame.frames {
+                if let Some(position) = &sub.position {
+                    samples.push((
+                        position.altitude,
+                        sub.hfr.zip(median_hfr).map(|(hfr, median)| hfr / median),
+                        sub.star_count.zip(median_stars).map(|(stars, median)| stars as f64 / median),
+                        sub.rejected,
+                    ));
+                }
+            }
+        }
+
+        let average = |values: Vec<f64>| match values.len() {
+            0 => None,
+            len => Some(values.iter().sum::<f64>() / len as f64),
+        };
+        let bins: Vec<AltitudeBin> = (0..(90.0 / ALTITUDE_BIN) as usize)
+            .map(|i| i as f64 * ALTITUDE_BIN)
+            .filter_map(|altitude| {
+                let bin: Vec<_> = samples
+                    .iter()
+                    .filter(|(a, ..)| *a >= altitude && *a < altitude + ALTITUDE_BIN)
+                    .collect();
+                if bin.is_empty() {
+                    return None;
+                }
+                Some(AltitudeBin {
+                    altitude,
+                    subs: bin.len(),
+                    relative_hfr: average(bin.iter().filter_map(|s| s.1).collect()),
+                    relative_star_count: average(bin.iter().filter_map(|s| s.2).collect()),
+                    rejection_rate: bin.iter().filter(|s| s.3).count() as f64 / bin.len() as f64,
+                })
+            })
+            .collect();
+
+        // walk down from the zenith until a bin is clearly worse than everything above it
+        let mut degradation_altitude = None;
+        let mut above: Vec<f64> = vec![];
+        for bin in bins.iter().rev().filter(|bin| bin.subs >= MIN_BIN_SUBS) {
+            let Some(hfr) = bin.relative_hfr else {
+                continue;
+            };
+            if let Some(reference) = average(above.clone()) {
+                if hfr > reference * DEGRADATION {
+                    degradation_altitude = Some(bin.altitude + ALTITUDE_BIN);
+                    break;
+                }
+            }
+            above.push(hfr);
+        }
+
+        AltitudeQuality {
+            bins,
+            degradation_altitude,
+        }
+    }
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    Some(values[values.len() / 2]).filter(|median| *median > 0.0)
 }
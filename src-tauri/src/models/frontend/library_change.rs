@@ -0,0 +1,17 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Serialize)]
+pub enum LibraryChangeKind {
+    ADDED,
+    REMOVED,
+    RENAMED,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LibraryChange {
+    pub kind: LibraryChangeKind,
+    pub path: PathBuf,
+    pub new_path: Option<PathBuf>,
+    pub classified: bool,
+}
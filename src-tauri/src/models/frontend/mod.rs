@@ -1,3 +1,6 @@
 pub mod analytics;
+pub mod library_change;
 pub mod state;
 pub mod process;
+pub mod projects;
+pub mod track;
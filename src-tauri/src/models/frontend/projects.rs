@@ -0,0 +1,101 @@
+use crate::models::filters::FilterType;
+use crate::models::projects::ImagingProject;
+use crate::models::state::AppState;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProjectProgress {
+    project: ImagingProject,
+    goals: Vec<GoalProgress>,
+    // seconds
+    integration_time: f64,
+    nights: usize,
+    // at the average integration per night so far, none before the first night
+    nights_remaining: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct GoalProgress {
+    filter_type: FilterType,
+    // seconds
+    goal: f64,
+    integration_time: f64,
+    integrated_subs: i32,
+    // 0 to 100, capped when the goal is exceeded
+    percent: f64,
+}
+
+impl ProjectProgress {
+    // integration of the project sessions per filter, from the accepted subs times their length.
+    // sessions can hold other targets or trains too, only their matching light frames count
+    pub fn new(project: &ImagingProject, app_state: &AppState) -> ProjectProgress {
+        let light_frames: Vec<_> = project
+            .session_ids
+            .iter()
+            .filter_map(|id| app_state.imaging_sessions.get(id))
+            .flat_map(|session| session.light_frame_ids.iter())
+            .filter_map(|id| app_state.imaging_frame_list.light_frames.get(id))
+            .filter(|light_frame| project.matches_frame(light_frame))
+            .collect();
+
+        let goals: Vec<GoalProgress> = project
+            .goals
+            .iter()
+            .map(|goal| {
+                let mut integration_time = 0.0;
+                let mut integrated_subs = 0;
+                for light_frame in &light_frames {
+                    let filter_type = app_state
+                        .equipment_list
+                        .filters
+                        .get(&light_frame.filter_id)
+                        .map(|filter| filter.filter_type());
+                    if filter_type == Some(goal.filter_type) {
+                        integration_time += light_frame.integrated_subs() as f64 * light_frame.sub_length;
+                        integrated_subs += light_frame.integrated_subs();
+                    }
+                }
+                let goal_time = goal.hours * 3600.0;
+
+                GoalProgress {
+                    filter_type: goal.filter_type,
+                    goal: goal_time,
+                    integration_time,
+                    integrated_subs,
+                    percent: (integration_time / goal_time * 100.0).min(100.0),
+                }
+            })
+            .collect();
+
+        let integration_time: f64 = light_frames
+            .iter()
+            .map(|light_frame| light_frame.integrated_subs() as f64 * light_frame.sub_length)
+            .sum();
+        let nights = light_frames
+            .iter()
+            .map(|light_frame| light_frame.date)
+            .collect::<HashSet<NaiveDate>>()
+            .len();
+
+        // only integration toward a goal brings the project closer to completion
+        let toward_goals: f64 = goals.iter().map(|goal| goal.integration_time.min(goal.goal)).sum();
+        let remaining: f64 = goals.iter().map(|goal| (goal.goal - goal.integration_time).max(0.0)).sum();
+        let nights_remaining = if remaining == 0.0 {
+            Some(0.0)
+        } else if nights > 0 && toward_goals > 0.0 {
+            Some((remaining / (toward_goals / nights as f64)).ceil())
+        } else {
+            None
+        };
+
+        ProjectProgress {
+            project: project.clone(),
+            goals,
+            integration_time,
+            nights,
+            nights_remaining,
+        }
+    }
+}
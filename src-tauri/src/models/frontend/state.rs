@@ -1,11 +1,22 @@
-use crate::models::equipment::{Camera, EquipmentItem, Filter, Flattener, Mount, Telescope};
+use crate::models::equipment::{
+    Camera, DewHeater, EquipmentItem, EquipmentType, FieldRotator, Filter, FilterWheel, Flattener, Focuser, GuideCamera,
+    GuideScope, Mount, OffAxisGuider, Rotator, Telescope,
+};
 use crate::models::frontend::analytics::Analytics;
 use crate::models::image_list::Image;
 use crate::models::imaging_frames;
 use crate::models::imaging_frames::CalibrationType;
+use crate::models::filters::FilterAlias;
+use crate::models::ingest::{AmbiguousMatch, IngestGroup};
+use crate::models::observing_sites::ObservingSite;
+use crate::models::frontend::projects::ProjectProgress;
+use crate::models::optical_train::OpticalTrain;
+use crate::models::optics::OpticsMetrics;
 use crate::models::imaging_session_list::ImagingSession;
 use crate::models::preferences::Preferences;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use uuid::Uuid;
 use crate::models::state::AppState;
 
@@ -16,6 +27,12 @@ pub struct FrontendAppState {
     pub equipment_list: EquipmentList,
     pub image_list: Vec<Image>,
     pub analytics: Analytics,
+    pub unclassified_frames: Vec<PathBuf>,
+    pub ingest_proposals: Vec<IngestGroup>,
+    pub ambiguous_matches: Vec<AmbiguousMatch>,
+    pub sites: Vec<ObservingSite>,
+    pub wishlist: Vec<String>,
+    pub projects: Vec<ProjectProgress>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -24,14 +41,20 @@ pub struct TableData {
     pub calibration: Vec<CalibrationTableRow>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LogTableRow {
     id: Uuid,
-    date: String,
+    session_id: Uuid,
+    date: NaiveDate,
+    // shown instead of the date if the date of an older log couldn't be read
+    legacy_date: Option<String>,
     target: String,
     sub_length: f64,
     total_subs: i32,
     integrated_subs: i32,
+    // integrated_subs is the count of an older log, not derived from the subs yet
+    legacy_integrated_subs: bool,
+    integration_time: f64,
     filter: String,
     gain: i32,
     offset: i32,
@@ -40,22 +63,45 @@ pub struct LogTableRow {
     average_seeing: f64,
     average_cloud_cover: f64,
     average_moon: f64,
+    // highest moon altitude and closest distance to the target while imaging
+    moon_altitude: Option<f64>,
+    moon_separation: Option<f64>,
+    // target altitude in degrees while imaging
+    min_altitude: Option<f64>,
+    max_altitude: Option<f64>,
+    mean_airmass: Option<f64>,
+    meridian_flip: Option<DateTime<Utc>>,
+    // from the weather provider, % and °C and m/s
+    humidity: Option<f64>,
+    dew_point: Option<f64>,
+    wind_speed: Option<f64>,
     telescope: String,
     flattener: String,
     mount: String,
     camera: String,
+    accessories: String,
     notes: String,
+    optics: Option<OpticsMetrics>,
+    optical_train: String,
+    site: String,
+    // equipment of this set that differs from its optical train
+    train_overrides: Vec<EquipmentType>,
 }
 
 impl LogTableRow {
-    pub fn new(imaging_session: &ImagingSession, app_state: &AppState) -> Option<Self> {
-        let light_frame = app_state
-            .imaging_frame_list
-            .light_frames
-            .get(&imaging_session.light_frame_id);
-
-        match light_frame {
-            Some(light_frame) => {
+    // one row per light frame set of the imaging session
+    pub fn new(imaging_session: &ImagingSession, app_state: &AppState) -> Vec<Self> {
+        imaging_session
+            .light_frame_ids
+            .iter()
+            .filter_map(|id| {
+                let light_frame = app_state.imaging_frame_list.light_frames.get(id);
+                if light_frame.is_none() {
+                    eprintln!("LightFrame with id {:?} not found", id);
+                }
+                light_frame
+            })
+            .map(|light_frame| {
                 let filter_name = app_state
                     .equipment_list
                     .filters
@@ -86,13 +132,40 @@ impl LogTableRow {
                     .get(&light_frame.camera_id)
                     .map_or("N/A".to_string(), |camera| camera.view_name().clone());
 
-                Some(LogTableRow {
+                let equipment_list = &app_state.equipment_list;
+                let optics = equipment_list.telescopes.get(&light_frame.telescope_id).map(|telescope| {
+                    OpticsMetrics::new(
+                        telescope,
+                        equipment_list.flatteners.get(&light_frame.flattener_id),
+                        equipment_list.cameras.get(&light_frame.camera_id),
+                        Some(light_frame.average_seeing),
+                    )
+                });
+
+                let accessories = EquipmentType::ACCESSORIES
+                    .iter()
+                    .filter_map(|equipment_type| {
+                        equipment_list.get(*equipment_type, light_frame.equipment_id(*equipment_type))
+                    })
+                    .map(|item| item.item().view_name())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let train = light_frame
+                    .optical_train_id
+                    .and_then(|id| equipment_list.optical_trains.get(&id));
+
+                LogTableRow {
                     id: light_frame.id,
-                    date: light_frame.date.clone(),
+                    session_id: imaging_session.id,
+                    date: light_frame.date,
+                    legacy_date: light_frame.legacy_date.clone(),
                     target: light_frame.target.clone(),
                     sub_length: light_frame.sub_length,
                     total_subs: light_frame.total_subs,
-                    integrated_subs: light_frame.integrated_subs,
+                    integrated_subs: light_frame.integrated_subs(),
+                    legacy_integrated_subs: light_frame.has_legacy_integrated_subs(),
+                    integration_time: light_frame.integrated_subs() as f64 * light_frame.sub_length,
                     filter: filter_name,
                     gain: light_frame.gain,
                     offset: light_frame.offset,
@@ -101,18 +174,179 @@ impl LogTableRow {
                     average_seeing: light_frame.average_seeing,
                     average_cloud_cover: light_frame.average_cloud_cover,
                     average_moon: light_frame.average_moon,
+                    moon_altitude: light_frame.moon.as_ref().map(|moon| moon.max_altitude),
+                    moon_separation: light_frame.moon.as_ref().and_then(|moon| moon.min_separation),
+                    min_altitude: light_frame.track.as_ref().map(|track| track.min_altitude),
+                    max_altitude: light_frame.track.as_ref().map(|track| track.max_altitude),
+                    mean_airmass: light_frame.track.as_ref().and_then(|track| track.mean_airmass),
+                    meridian_flip: light_frame.track.as_ref().and_then(|track| track.meridian_flip),
+                    humidity: light_frame.weather.as_ref().and_then(|weather| weather.humidity),
+                    dew_point: light_frame.weather.as_ref().and_then(|weather| weather.dew_point),
+                    wind_speed: light_frame.weather.as_ref().and_then(|weather| weather.wind_speed),
                     telescope: telescope_name,
                     flattener: flattener_name,
                     mount: mount_name,
                     camera: camera_name,
+                    accessories,
                     notes: light_frame.notes.clone(),
-                })
-            }
-            None => {
-                eprintln!("LightFrame with id {:?} not found", imaging_session.id);
-                None
+                    optics,
+                    optical_train: train.map_or("N/A".to_string(), |train| train.name.clone()),
+                    site: imaging_session
+                        .site_id
+                        .and_then(|id| app_state.sites.get(&id))
+                        .map_or("N/A".to_string(), |site| site.name.clone()),
+                    train_overrides: train.map_or(vec![], |train| train.overrides(light_frame)),
+                }
+            })
+            .collect()
+    }
+
+    // combines the rows of one imaging session into a single row
+    pub fn summarize(rows: &[LogTableRow]) -> Option<Self> {
+        fn join(rows: &[LogTableRow], value: fn(&LogTableRow) -> &String, separator: &str) -> String {
+            let mut values: Vec<&String> = vec![];
+            for row in rows {
+                let v = value(row);
+                if !v.is_empty() && !values.contains(&v) {
+                    values.push(v);
+                }
             }
+            values.iter().map(|v| v.as_str()).collect::<Vec<_>>().join(separator)
+        }
+        fn average(rows: &[LogTableRow], value: fn(&LogTableRow) -> f64) -> f64 {
+            rows.iter().map(value).sum::<f64>() / rows.len() as f64
+        }
+
+        // average of the rows that have a value
+        fn average_some(rows: &[LogTableRow], value: fn(&LogTableRow) -> Option<f64>) -> Option<f64> {
+            let values: Vec<f64> = rows.iter().filter_map(value).collect();
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
         }
+
+        let first = rows.first()?;
+        let integrated_subs: i32 = rows.iter().map(|r| r.integrated_subs).sum();
+        let integration_time: f64 = rows.iter().map(|r| r.integration_time).sum();
+
+        Some(LogTableRow {
+            id: first.session_id,
+            session_id: first.session_id,
+            date: rows.iter().map(|r| r.date).min()?,
+            legacy_date: rows.iter().find_map(|r| r.legacy_date.clone()),
+            target: join(rows, |r| &r.target, ", "),
+            // average sub length of the integrated subs
+            sub_length: if integrated_subs > 0 {
+                integration_time / integrated_subs as f64
+            } else {
+                first.sub_length
+            },
+            total_subs: rows.iter().map(|r| r.total_subs).sum(),
+            integrated_subs,
+            legacy_integrated_subs: rows.iter().any(|r| r.legacy_integrated_subs),
+            integration_time,
+            filter: join(rows, |r| &r.filter, ", "),
+            gain: first.gain,
+            offset: first.offset,
+            camera_temp: average(rows, |r| r.camera_temp),
+            outside_temp: average(rows, |r| r.outside_temp),
+            average_seeing: average(rows, |r| r.average_seeing),
+            average_cloud_cover: average(rows, |r| r.average_cloud_cover),
+            average_moon: average(rows, |r| r.average_moon),
+            moon_altitude: rows.iter().filter_map(|r| r.moon_altitude).reduce(f64::max),
+            moon_separation: rows.iter().filter_map(|r| r.moon_separation).reduce(f64::min),
+            min_altitude: rows.iter().filter_map(|r| r.min_altitude).reduce(f64::min),
+            max_altitude: rows.iter().filter_map(|r| r.max_altitude).reduce(f64::max),
+            mean_airmass: average_some(rows, |r| r.mean_airmass),
+            meridian_flip: rows.iter().filter_map(|r| r.meridian_flip).min(),
+            humidity: average_some(rows, |r| r.humidity),
+            dew_point: average_some(rows, |r| r.dew_point),
+            wind_speed: average_some(rows, |r| r.wind_speed),
+            telescope: join(rows, |r| &r.telescope, ", "),
+            flattener: join(rows, |r| &r.flattener, ", "),
+            mount: join(rows, |r| &r.mount, ", "),
+            camera: join(rows, |r| &r.camera, ", "),
+            accessories: join(rows, |r| &r.accessories, ", "),
+            notes: join(rows, |r| &r.notes, "; "),
+            optics: first.optics.clone(),
+            optical_train: join(rows, |r| &r.optical_train, ", "),
+            site: first.site.clone(),
+            train_overrides: vec![],
+        })
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn csv_header() -> Vec<&'static str> {
+        vec![
+            "date",
+            "target",
+            "sub_length",
+            "total_subs",
+            "integrated_subs",
+            "integration_time",
+            "filter",
+            "gain",
+            "offset",
+            "camera_temp",
+            "outside_temp",
+            "average_seeing",
+            "average_cloud_cover",
+            "average_moon",
+            "moon_altitude",
+            "moon_separation",
+            "min_altitude",
+            "max_altitude",
+            "mean_airmass",
+            "meridian_flip",
+            "humidity",
+            "dew_point",
+            "wind_speed",
+            "telescope",
+            "flattener",
+            "mount",
+            "camera",
+            "accessories",
+            "optical_train",
+            "site",
+            "notes",
+        ]
+    }
+
+    pub fn csv_record(&self) -> Vec<String> {
+        vec![
+            self.legacy_date.clone().unwrap_or_else(|| self.date.to_string()),
+            self.target.clone(),
+            self.sub_length.to_string(),
+            self.total_subs.to_string(),
+            self.integrated_subs.to_string(),
+            self.integration_time.to_string(),
+            self.filter.clone(),
+            self.gain.to_string(),
+            self.offset.to_string(),
+            self.camera_temp.to_string(),
+            self.outside_temp.to_string(),
+            self.average_seeing.to_string(),
+            self.average_cloud_cover.to_string(),
+            self.average_moon.to_string(),
+            self.moon_altitude.map_or(String::new(), |altitude| format!("{:.1}", altitude)),
+            self.moon_separation.map_or(String::new(), |separation| format!("{:.1}", separation)),
+            self.min_altitude.map_or(String::new(), |altitude| format!("{:.1}", altitude)),
+            self.max_altitude.map_or(String::new(), |altitude| format!("{:.1}", altitude)),
+            self.mean_airmass.map_or(String::new(), |airmass| format!("{:.2}", airmass)),
+            self.meridian_flip.map_or(String::new(), |time| time.to_rfc3339()),
+            self.humidity.map_or(String::new(), |humidity| format!("{:.0}", humidity)),
+            self.dew_point.map_or(String::new(), |dew_point| format!("{:.1}", dew_point)),
+            self.wind_speed.map_or(String::new(), |wind_speed| format!("{:.1}", wind_speed)),
+            self.telescope.clone(),
+            self.flattener.clone(),
+            self.mount.clone(),
+            self.camera.clone(),
+            self.accessories.clone(),
+            self.optical_train.clone(),
+            self.site.clone(),
+            self.notes.clone(),
+        ]
     }
 }
 
@@ -165,4 +399,14 @@ pub struct EquipmentList {
     pub mount_list: Vec<Mount>,
     pub filter_list: Vec<Filter>,
     pub flattener_list: Vec<Flattener>,
+    pub filter_wheel_list: Vec<FilterWheel>,
+    pub focuser_list: Vec<Focuser>,
+    pub guide_scope_list: Vec<GuideScope>,
+    pub guide_camera_list: Vec<GuideCamera>,
+    pub off_axis_guider_list: Vec<OffAxisGuider>,
+    pub rotator_list: Vec<Rotator>,
+    pub dew_heater_list: Vec<DewHeater>,
+    pub field_rotator_list: Vec<FieldRotator>,
+    pub optical_train_list: Vec<OpticalTrain>,
+    pub filter_aliases: Vec<FilterAlias>,
 }
@@ -0,0 +1,59 @@
+use crate::models::equipment::EquipmentItem;
+use crate::models::imaging_session_list::ImagingSession;
+use crate::models::state::AppState;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// one sub of an imaging session with the target position and its quality, for charting
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TrackPoint {
+    light_frame_id: Uuid,
+    filter: String,
+    timestamp: DateTime<Utc>,
+    // degrees
+    altitude: f64,
+    azimuth: f64,
+    airmass: Option<f64>,
+    // hours
+    hour_angle: f64,
+    hfr: Option<f64>,
+    star_count: Option<i32>,
+    rejected: bool,
+}
+
+impl TrackPoint {
+    // subs without a computed position are left out, sorted by time
+    pub fn for_session(session: &ImagingSession, app_state: &AppState) -> Vec<TrackPoint> {
+        let mut points: Vec<TrackPoint> = session
+            .light_frame_ids
+            .iter()
+            .filter_map(|id| app_state.imaging_frame_list.light_frames.get(id))
+            .flat_map(|light_frame| {
+                let filter = app_state
+                    .equipment_list
+                    .filters
+                    .get(&light_frame.filter_id)
+                    .map_or("N/A".to_string(), |filter| filter.view_name());
+
+                light_frame.frames.iter().filter_map(move |sub| {
+                    let position = sub.position.as_ref()?;
+                    Some(TrackPoint {
+                        light_frame_id: light_frame.id,
+                        filter: filter.clone(),
+                        timestamp: sub.midpoint()?,
+                        altitude: position.altitude,
+                        azimuth: position.azimuth,
+                        airmass: position.airmass,
+                        hour_angle: position.hour_angle,
+                        hfr: sub.hfr,
+                        star_count: sub.star_count,
+                        rejected: sub.rejected,
+                    })
+                })
+            })
+            .collect();
+        points.sort_by_key(|point| point.timestamp);
+        points
+    }
+}
@@ -1,15 +1,26 @@
+use crate::astronomy::{
+    airmass, hour_angle, mean_angle, moon_horizontal, moon_illumination, moon_position, separation, to_horizontal,
+    Equatorial,
+};
 use crate::file_store;
+use crate::image::{parse_timestamp, FrameMetadata};
+use crate::night::night_of;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::models::equipment::{EquipmentList, EquipmentType};
+use crate::models::observing_sites::ObservingSite;
+use crate::models::optics::OpticsMetrics;
 use crate::models::state::AppState;
+use crate::models::weather::{hour_of, HourlyWeather, WeatherConditions};
 use serde::ser::SerializeStruct;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::any::Any;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ImagingFrameList {
     pub light_frames: HashMap<Uuid, LightFrame>,
     pub dark_frames: HashMap<Uuid, DarkFrame>,
@@ -24,7 +35,7 @@ impl<'de> Deserialize<'de> for ImagingFrameList {
     {
         #[derive(Deserialize)]
         struct TempImagingFrameList {
-            light_frames: Vec<LightFrame>,
+            light_frames: Vec<serde_json::Value>,
             dark_frames: Vec<DarkFrame>,
             bias_frames: Vec<BiasFrame>,
             flat_frames: Vec<FlatFrame>,
@@ -37,10 +48,22 @@ impl<'de> Deserialize<'de> for ImagingFrameList {
             flat_frames,
         } = TempImagingFrameList::deserialize(deserializer)?;
 
+        // keep dates of older logs that can't be read, instead of losing them behind the placeholder date
         let light_frames_map: HashMap<Uuid, LightFrame> = light_frames
             .into_iter()
-            .map(|frame| (frame.id, frame))
-            .collect();
+            .map(|value| {
+                let unreadable_date = value
+                    .get("date")
+                    .and_then(|date| date.as_str())
+                    .filter(|date| parse_date(date).is_none())
+                    .map(|date| date.trim().to_string());
+                let mut frame = LightFrame::deserialize(value).map_err(de::Error::custom)?;
+                if frame.legacy_date.is_none() {
+                    frame.legacy_date = unreadable_date;
+                }
+                Ok((frame.id, frame))
+            })
+            .collect::<Result<_, D::Error>>()?;
 
         let dark_frames_map: HashMap<Uuid, DarkFrame> = dark_frames
             .into_iter()
@@ -112,6 +135,124 @@ impl ImagingFrameList {
         )?)
     }
 
+    pub fn contains_frame(&self, path: &str) -> bool {
+        self.light_frames.values().any(|f| f.frames.iter().any(|s| s.path == path))
+            || self.dark_frames.values().any(|f| f.frames.iter().any(|p| p == path))
+            || self.bias_frames.values().any(|f| f.frames.iter().any(|p| p == path))
+            || self.flat_frames.values().any(|f| f.frames.iter().any(|p| p == path))
+    }
+
+    // removes the path from every frame record, returns true if a record changed
+    pub fn remove_frame(&mut self, path: &str) -> bool {
+        fn remove<T>(frames: &mut Vec<T>, total_subs: &mut i32, is_path: impl Fn(&T) -> bool) -> bool {
+            let len = frames.len();
+            frames.retain(|frame| !is_path(frame));
+            let removed = (len - frames.len()) as i32;
+            *total_subs = (*total_subs - removed).max(0);
+            removed > 0
+        }
+
+        let mut changed = false;
+        for frame in self.light_frames.values_mut() {
+            changed |= remove(&mut frame.frames, &mut frame.total_subs, |s| s.path == path);
+        }
+        for frame in self.dark_frames.values_mut() {
+            changed |= remove(&mut frame.frames, &mut frame.total_subs, |p| p == path);
+        }
+        for frame in self.bias_frames.values_mut() {
+            changed |= remove(&mut frame.frames, &mut frame.total_subs, |p| p == path);
+        }
+        for frame in self.flat_frames.values_mut() {
+            changed |= remove(&mut frame.frames, &mut frame.total_subs, |p| p == path);
+        }
+        changed
+    }
+
+    // replaces the path in every frame record, returns true if a record changed
+    pub fn rename_frame(&mut self, from: &str, to: &str) -> bool {
+        self.rename_paths(|path| (path == from).then(|| to.to_string()))
+    }
+
+    // moves every frame record under the old folder to the new one, returns true if a record changed
+    pub fn rename_folder(&mut self, from: &Path, to: &Path) -> bool {
+        self.rename_paths(|path| {
+            let rest = Path::new(path).strip_prefix(from).ok()?;
+            Some(to.join(rest).to_string_lossy().to_string())
+        })
+    }
+
+    fn rename_paths(&mut self, rename: impl Fn(&str) -> Option<String>) -> bool {
+        let mut changed = false;
+        let frames = self
+            .light_frames
+            .values_mut()
+            .flat_map(|f| f.frames.iter_mut().map(|s| &mut s.path))
+            .chain(self.dark_frames.values_mut().flat_map(|f| f.frames.iter_mut()))
+            .chain(self.bias_frames.values_mut().flat_map(|f| f.frames.iter_mut()))
+            .chain(self.flat_frames.values_mut().flat_map(|f| f.frames.iter_mut()));
+
+        for frame in frames {
+            if let Some(new_path) = rename(frame) {
+                *frame = new_path;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    // every equipment id of the given type in the frame records
+    pub fn equipment_ids(&self, equipment_type: EquipmentType) -> Vec<&Uuid> {
+        match equipment_type {
+            EquipmentType::CAMERA => self
+                .light_frames
+                .values()
+                .map(|l| &l.camera_id)
+                .chain(self.dark_frames.values().map(|d| &d.camera_id))
+                .chain(self.bias_frames.values().map(|b| &b.camera_id))
+                .chain(self.flat_frames.values().map(|f| &f.camera_id))
+                .collect(),
+            _ => self.light_frames.values().map(|l| l.equipment_id(equipment_type)).collect(),
+        }
+    }
+
+    fn equipment_ids_mut(&mut self, equipment_type: EquipmentType) -> Vec<&mut Uuid> {
+        match equipment_type {
+            EquipmentType::CAMERA => self
+                .light_frames
+                .values_mut()
+                .map(|l| &mut l.camera_id)
+                .chain(self.dark_frames.values_mut().map(|d| &mut d.camera_id))
+                .chain(self.bias_frames.values_mut().map(|b| &mut b.camera_id))
+                .chain(self.flat_frames.values_mut().map(|f| &mut f.camera_id))
+                .collect(),
+            _ => self
+                .light_frames
+                .values_mut()
+                .map(|l| l.equipment_id_mut(equipment_type))
+                .collect(),
+        }
+    }
+
+    // number of frame records that reference the equipment item
+    pub fn equipment_references(&self, equipment_type: EquipmentType, id: &Uuid) -> usize {
+        self.equipment_ids(equipment_type)
+            .into_iter()
+            .filter(|equipment_id| *equipment_id == id)
+            .count()
+    }
+
+    // points every reference from one equipment item to another, returns the number of changed records
+    pub fn reassign_equipment(&mut self, equipment_type: EquipmentType, from: &Uuid, to: &Uuid) -> usize {
+        let mut changed = 0;
+        for equipment_id in self.equipment_ids_mut(equipment_type) {
+            if equipment_id == from {
+                *equipment_id = *to;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
     pub fn get_calibration_frames(app_state: &AppState) -> Vec<Box<dyn CalibrationFrame>> {
         // Clone the frames into vectors to own the data and avoid lifetime issues
         let dark_frames: Vec<_> = app_state
@@ -146,11 +287,18 @@ pub struct LightFrame {
     pub camera_id: Uuid,
     pub total_subs: i32,
     pub gain: i32,
-    pub frames: Vec<String>,
-
-    pub date: String,
+    #[serde(deserialize_with = "deserialize_sub_frames")]
+    pub frames: Vec<SubFrame>,
+
+    #[serde(deserialize_with = "deserialize_date")]
+    pub date: NaiveDate,
+    // the original text of a date older logs had in a format that couldn't be read, date is a placeholder then
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_date: Option<String>,
     pub target: String,
-    pub integrated_subs: i32,
+    // the count of older logs, it stands until the user confirms the accepted subs instead
+    #[serde(rename = "integrated_subs", default, skip_serializing_if = "Option::is_none")]
+    legacy_integrated_subs: Option<i32>,
     pub filter_id: Uuid,
     pub offset: i32,
     pub camera_temp: f64,
@@ -163,6 +311,519 @@ pub struct LightFrame {
     pub mount_id: Uuid,
     pub notes: String,
     pub sub_length: f64,
+
+    // accessories, nil if not used
+    #[serde(default)]
+    pub filter_wheel_id: Uuid,
+    #[serde(default)]
+    pub focuser_id: Uuid,
+    #[serde(default)]
+    pub guide_scope_id: Uuid,
+    #[serde(default)]
+    pub guide_camera_id: Uuid,
+    #[serde(default)]
+    pub off_axis_guider_id: Uuid,
+    #[serde(default)]
+    pub rotator_id: Uuid,
+    #[serde(default)]
+    pub dew_heater_id: Uuid,
+    #[serde(default)]
+    pub field_rotator_id: Uuid,
+
+    // calibration frames of this set, falling back to the ones of the imaging session
+    #[serde(default)]
+    pub dark_frame_id: Option<Uuid>,
+    #[serde(default)]
+    pub flat_frame_id: Option<Uuid>,
+    #[serde(default)]
+    pub bias_frame_id: Option<Uuid>,
+
+    // equipment ids above are taken from the train, any that differ override it for this set
+    #[serde(default)]
+    pub optical_train_id: Option<Uuid>,
+
+    // target coordinates in degrees from the frame headers
+    #[serde(default)]
+    pub ra: Option<f64>,
+    #[serde(default)]
+    pub dec: Option<f64>,
+    // arcmin (width, height) from the frame headers, else from the optics
+    #[serde(default)]
+    pub field_of_view: Option<(f64, f64)>,
+    // computed from the sub timestamps and the site of the imaging session
+    #[serde(default)]
+    pub moon: Option<MoonConditions>,
+    #[serde(default)]
+    pub track: Option<TargetTrack>,
+    // fetched from the weather provider for the site of the imaging session
+    #[serde(default)]
+    pub weather: Option<WeatherConditions>,
+    // conditions typed in by hand, fetched weather only fills the others
+    #[serde(default = "EnteredConditions::all")]
+    pub entered_conditions: EnteredConditions,
+}
+
+// which of the hand entered conditions of a light frame set hold a typed in value, older logs had all of them
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+pub struct EnteredConditions {
+    pub outside_temp: bool,
+    pub average_seeing: bool,
+    pub average_cloud_cover: bool,
+}
+
+impl EnteredConditions {
+    fn all() -> EnteredConditions {
+        EnteredConditions {
+            outside_temp: true,
+            average_seeing: true,
+            average_cloud_cover: true,
+        }
+    }
+}
+
+// where the target was in the sky while the set was taken
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TargetTrack {
+    // degrees
+    pub min_altitude: f64,
+    pub max_altitude: f64,
+    pub mean_airmass: Option<f64>,
+    // when the target crossed the meridian between the first and the last sub
+    pub meridian_flip: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubPosition {
+    // degrees, azimuth from north through east
+    pub altitude: f64,
+    pub azimuth: f64,
+    pub airmass: Option<f64>,
+    // hours, negative east of the meridian
+    pub hour_angle: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MoonConditions {
+    // illuminated fraction between 0 and 1
+    pub illumination: f64,
+    // degrees
+    pub min_altitude: f64,
+    pub max_altitude: f64,
+    // closest distance to the target, if its coordinates are known
+    pub min_separation: Option<f64>,
+    // share of the subs taken with the moon above the horizon
+    pub above_horizon: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubFrame {
+    pub path: String,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub exposure: Option<f64>,
+    pub camera_temp: Option<f64>,
+    pub hfr: Option<f64>,
+    pub star_count: Option<i32>,
+    pub rejected: bool,
+    // target position at the middle of the exposure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<SubPosition>,
+}
+
+impl SubFrame {
+    // the middle of the exposure, the timestamp is its start
+    pub fn midpoint(&self) -> Option<DateTime<Utc>> {
+        let exposure = self.exposure.unwrap_or_default();
+        Some(self.timestamp? + Duration::milliseconds((exposure * 500.0) as i64))
+    }
+
+    pub fn from_metadata(path: &PathBuf, metadata: &FrameMetadata) -> SubFrame {
+        SubFrame {
+            path: path.to_string_lossy().to_string(),
+            timestamp: metadata.timestamp,
+            exposure: metadata.sub_length,
+            camera_temp: metadata.camera_temp,
+            hfr: metadata.hfr,
+            star_count: metadata.star_count,
+            rejected: false,
+            position: None,
+        }
+    }
+}
+
+// frames used to be a list of paths
+fn deserialize_sub_frames<'de, D>(deserializer: D) -> Result<Vec<SubFrame>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum PathOrSubFrame {
+        Path(String),
+        SubFrame(SubFrame),
+    }
+
+    let frames: Vec<PathOrSubFrame> = Vec::deserialize(deserializer)?;
+
+    Ok(frames
+        .into_iter()
+        .map(|frame| match frame {
+            PathOrSubFrame::Path(path) => SubFrame {
+                path,
+                timestamp: None,
+                exposure: None,
+                camera_temp: None,
+                hfr: None,
+                star_count: None,
+                rejected: false,
+                position: None,
+            },
+            PathOrSubFrame::SubFrame(sub_frame) => sub_frame,
+        })
+        .collect())
+}
+
+impl LightFrame {
+    pub fn integrated_subs(&self) -> i32 {
+        match self.legacy_integrated_subs {
+            Some(integrated_subs) => integrated_subs,
+            None => self.frames.iter().filter(|s| !s.rejected).count() as i32,
+        }
+    }
+
+    pub fn has_legacy_integrated_subs(&self) -> bool {
+        self.legacy_integrated_subs.is_some()
+    }
+
+    // from now on the integrated subs are the subs that aren't rejected
+    pub fn confirm_integrated_subs(&mut self) {
+        self.legacy_integrated_subs = None;
+    }
+
+    // fills a light frame from the metadata of its subs, resolves the equipment by header names
+    // and completes it from the optical train of that camera and telescope. fails without a night
+    // to file the subs under
+    pub fn from_metadata(
+        frames: &[PathBuf],
+        metadata: &[FrameMetadata],
+        equipment_list: &EquipmentList,
+    ) -> Result<LightFrame, Box<dyn Error>> {
+        let date = metadata.iter().filter_map(night_of).min().ok_or_else(|| {
+            format!(
+                "No capture date found for {}.",
+                frames.first().map_or(String::new(), |frame| frame.display().to_string())
+            )
+        })?;
+        let target = most_common(metadata.iter().filter_map(|m| m.target.as_ref()).collect());
+        let filter = most_common(metadata.iter().filter_map(|m| m.filter.as_ref()).collect());
+        let camera = most_common(metadata.iter().filter_map(|m| m.camera.as_ref()).collect());
+        let telescope = most_common(metadata.iter().filter_map(|m| m.telescope.as_ref()).collect());
+
+        let temps: Vec<f64> = metadata.iter().filter_map(|m| m.camera_temp).collect();
+        let camera_temp = if temps.is_empty() {
+            0.0
+        } else {
+            temps.iter().sum::<f64>() / temps.len() as f64
+        };
+        let decs: Vec<f64> = metadata.iter().filter_map(|m| m.dec).collect();
+
+        let resolve = |header: Option<String>, id: fn(&EquipmentList, &str) -> Option<Uuid>| {
+            header.and_then(|h| id(equipment_list, &h)).unwrap_or(Uuid::nil())
+        };
+
+        let mut light_frame = LightFrame {
+            id: Uuid::new_v4(),
+            camera_id: resolve(camera, |e, h| EquipmentList::resolve(&e.cameras, h)),
+            total_subs: frames.len() as i32,
+            gain: metadata.iter().find_map(|m| m.gain).unwrap_or_default(),
+            frames: frames
+                .iter()
+                .zip(metadata)
+                .map(|(frame, data)| SubFrame::from_metadata(frame, data))
+                .collect(),
+            date,
+            legacy_date: None,
+            target: target.unwrap_or_default(),
+            legacy_integrated_subs: None,
+            filter_id: Uuid::nil(),
+            offset: metadata.iter().find_map(|m| m.offset).unwrap_or_default(),
+            camera_temp,
+            outside_temp: 0.0,
+            average_seeing: 0.0,
+            average_cloud_cover: 0.0,
+            average_moon: 0.0,
+            telescope_id: resolve(telescope, |e, h| EquipmentList::resolve(&e.telescopes, h)),
+            flattener_id: Uuid::nil(),
+            mount_id: Uuid::nil(),
+            notes: "".to_string(),
+            sub_length: metadata.iter().find_map(|m| m.sub_length).unwrap_or_default(),
+            filter_wheel_id: Uuid::nil(),
+            focuser_id: Uuid::nil(),
+            guide_scope_id: Uuid::nil(),
+            guide_camera_id: Uuid::nil(),
+            off_axis_guider_id: Uuid::nil(),
+            rotator_id: Uuid::nil(),
+            dew_heater_id: Uuid::nil(),
+            field_rotator_id: Uuid::nil(),
+            dark_frame_id: None,
+            flat_frame_id: None,
+            bias_frame_id: None,
+            optical_train_id: None,
+            ra: mean_angle(&metadata.iter().filter_map(|m| m.ra).collect::<Vec<_>>()),
+            dec: (!decs.is_empty()).then(|| decs.iter().sum::<f64>() / decs.len() as f64),
+            field_of_view: metadata.iter().find_map(|m| m.field_of_view),
+            moon: None,
+            track: None,
+            weather: None,
+            entered_conditions: EnteredConditions::default(),
+        };
+
+        if let Some(train) = equipment_list.matching_train(&light_frame) {
+            train.apply(&mut light_frame);
+        }
+
+        if light_frame.field_of_view.is_none() {
+            light_frame.field_of_view = equipment_list.telescopes.get(&light_frame.telescope_id).and_then(|telescope| {
+                OpticsMetrics::new(
+                    telescope,
+                    equipment_list.flatteners.get(&light_frame.flattener_id),
+                    equipment_list.cameras.get(&light_frame.camera_id),
+                    None,
+                )
+                .field_of_view
+            });
+        }
+
+        // capture software often only writes the wheel position, like "3" or "Filter 3"
+        if light_frame.filter_id.is_nil() {
+            let position = filter.as_ref().and_then(|f| f.rsplit(' ').next().and_then(|p| p.trim().parse::<u32>().ok()));
+            if let (Some(position), Some(wheel)) = (position, equipment_list.filter_wheels.get(&light_frame.filter_wheel_id)) {
+                if let Some(filter_id) = wheel.filter_in_slot(position) {
+                    light_frame.filter_id = *filter_id;
+                }
+            }
+        }
+
+        // or a channel name like "H-alpha" or "HA 7nm"
+        if light_frame.filter_id.is_nil() {
+            if let Some(filter_id) = filter.as_ref().and_then(|f| equipment_list.resolve_filter_type(f, &light_frame.filter_wheel_id)) {
+                light_frame.filter_id = filter_id;
+            }
+        }
+
+        // the names and aliases of the filters only once neither of them settled it
+        if light_frame.filter_id.is_nil() {
+            light_frame.filter_id = resolve(filter, |e, h| EquipmentList::resolve(&e.filters, h));
+        }
+        Ok(light_frame)
+    }
+
+    pub fn target_position(&self) -> Option<Equatorial> {
+        Some(Equatorial {
+            ra: self.ra?,
+            dec: self.dec?,
+        })
+    }
+
+    // first and last sub timestamp
+    pub fn time_span(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let times = self.frames.iter().filter_map(|sub| sub.midpoint());
+        Some((times.clone().min()?, times.max()?))
+    }
+
+    // keeps the hours the set was taken in and fills the conditions that were not typed in by hand.
+    // values the provider doesn't have stay as they were
+    pub fn update_weather(&mut self, hours: &[HourlyWeather]) {
+        let Some((start, end)) = self.time_span() else {
+            return;
+        };
+        let (first, last) = (hour_of(&start), hour_of(&end));
+        let Some(weather) = WeatherConditions::new(
+            hours
+                .iter()
+                .filter(|hour| hour.time >= first && hour.time <= last)
+                .cloned()
+                .collect(),
+        ) else {
+            return;
+        };
+
+        let entered = self.entered_conditions;
+        if let Some(temperature) = weather.temperature.filter(|_| !entered.outside_temp) {
+            self.outside_temp = temperature;
+        }
+        if let Some(cloud_cover) = weather.cloud_cover.filter(|_| !entered.average_cloud_cover) {
+            self.average_cloud_cover = cloud_cover;
+        }
+        if let Some(seeing) = weather.seeing.filter(|_| !entered.average_seeing) {
+            self.average_seeing = seeing;
+        }
+        self.weather = Some(match self.weather.take() {
+            Some(old) => weather.or(old),
+            None => weather,
+        });
+    }
+
+    // moon and target positions seen from the site
+    pub fn update_sky(&mut self, site: &ObservingSite) {
+        self.update_moon(site);
+        self.update_track(site);
+    }
+
+    // positions without a site are unknown, the moon illumination doesn't depend on it
+    pub fn clear_sky(&mut self) {
+        self.moon = None;
+        self.track = None;
+        for sub in self.frames.iter_mut() {
+            sub.position = None;
+        }
+    }
+
+    // position of the target for every sub with a timestamp, and their range over the set
+    fn update_track(&mut self, site: &ObservingSite) {
+        let Some(target) = self.target_position() else {
+            return;
+        };
+
+        let mut times: Vec<DateTime<Utc>> = vec![];
+        for sub in self.frames.iter_mut() {
+            sub.position = sub.midpoint().map(|time| {
+                times.push(time);
+                let horizontal = to_horizontal(&target, &time, site.latitude, site.longitude);
+                SubPosition {
+                    altitude: horizontal.altitude,
+                    azimuth: horizontal.azimuth,
+                    airmass: airmass(horizontal.altitude),
+                    hour_angle: hour_angle(&target, &time, site.longitude),
+                }
+            });
+        }
+
+        let positions: Vec<&SubPosition> = self.frames.iter().filter_map(|sub| sub.position.as_ref()).collect();
+        let (Some(first), Some(last)) = (times.iter().min(), times.iter().max()) else {
+            self.track = None;
+            return;
+        };
+        let airmasses: Vec<f64> = positions.iter().filter_map(|p| p.airmass).collect();
+
+        // the hour angle grows by a sidereal hour per hour
+        let first_hour_angle = hour_angle(&target, first, site.longitude);
+        let meridian_flip = if first_hour_angle < 0.0 && hour_angle(&target, last, site.longitude) >= 0.0 {
+            Some(*first + Duration::milliseconds((-first_hour_angle / 1.002_738 * 3_600_000.0) as i64))
+        } else {
+            None
+        };
+
+        self.track = Some(TargetTrack {
+            min_altitude: positions.iter().map(|p| p.altitude).fold(f64::INFINITY, f64::min),
+            max_altitude: positions.iter().map(|p| p.altitude).fold(f64::NEG_INFINITY, f64::max),
+            mean_airmass: (!airmasses.is_empty()).then(|| airmasses.iter().sum::<f64>() / airmasses.len() as f64),
+            meridian_flip,
+        });
+    }
+
+    // moon conditions of the accepted subs seen from the site, average_moon becomes the illumination
+    // in percent. keeps the hand entered value when no sub has a timestamp
+    fn update_moon(&mut self, site: &ObservingSite) {
+        let times: Vec<DateTime<Utc>> = self
+            .frames
+            .iter()
+            .filter(|sub| !sub.rejected)
+            .filter_map(|sub| sub.midpoint())
+            .collect();
+        if times.is_empty() {
+            return;
+        }
+
+        let target = self.target_position();
+        let altitudes: Vec<f64> = times
+            .iter()
+            .map(|time| moon_horizontal(time, site.latitude, site.longitude).altitude)
+            .collect();
+        let illumination = times.iter().map(moon_illumination).sum::<f64>() / times.len() as f64;
+
+        self.moon = Some(MoonConditions {
+            illumination,
+            min_altitude: altitudes.iter().copied().fold(f64::INFINITY, f64::min),
+            max_altitude: altitudes.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            min_separation: target.map(|target| {
+                times
+                    .iter()
+                    .map(|time| separation(&moon_position(time), &target))
+                    .fold(f64::INFINITY, f64::min)
+            }),
+            above_horizon: altitudes.iter().filter(|a| **a > 0.0).count() as f64 / times.len() as f64,
+        });
+        self.average_moon = (illumination * 1000.0).round() / 10.0;
+    }
+
+    pub fn equipment_id(&self, equipment_type: EquipmentType) -> &Uuid {
+        match equipment_type {
+            EquipmentType::TELESCOPE => &self.telescope_id,
+            EquipmentType::CAMERA => &self.camera_id,
+            EquipmentType::MOUNT => &self.mount_id,
+            EquipmentType::FILTER => &self.filter_id,
+            EquipmentType::FLATTENER => &self.flattener_id,
+            EquipmentType::FILTERWHEEL => &self.filter_wheel_id,
+            EquipmentType::FOCUSER => &self.focuser_id,
+            EquipmentType::GUIDESCOPE => &self.guide_scope_id,
+            EquipmentType::GUIDECAMERA => &self.guide_camera_id,
+            EquipmentType::OFFAXISGUIDER => &self.off_axis_guider_id,
+            EquipmentType::ROTATOR => &self.rotator_id,
+            EquipmentType::DEWHEATER => &self.dew_heater_id,
+            EquipmentType::FIELDROTATOR => &self.field_rotator_id,
+        }
+    }
+
+    pub fn equipment_id_mut(&mut self, equipment_type: EquipmentType) -> &mut Uuid {
+        match equipment_type {
+            EquipmentType::TELESCOPE => &mut self.telescope_id,
+            EquipmentType::CAMERA => &mut self.camera_id,
+            EquipmentType::MOUNT => &mut self.mount_id,
+            EquipmentType::FILTER => &mut self.filter_id,
+            EquipmentType::FLATTENER => &mut self.flattener_id,
+            EquipmentType::FILTERWHEEL => &mut self.filter_wheel_id,
+            EquipmentType::FOCUSER => &mut self.focuser_id,
+            EquipmentType::GUIDESCOPE => &mut self.guide_scope_id,
+            EquipmentType::GUIDECAMERA => &mut self.guide_camera_id,
+            EquipmentType::OFFAXISGUIDER => &mut self.off_axis_guider_id,
+            EquipmentType::ROTATOR => &mut self.rotator_id,
+            EquipmentType::DEWHEATER => &mut self.dew_heater_id,
+            EquipmentType::FIELDROTATOR => &mut self.field_rotator_id,
+        }
+    }
+}
+
+pub fn most_common(values: Vec<&String>) -> Option<String> {
+    let mut counts: HashMap<&String, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(a.0)))
+        .map(|(value, _)| value.clone())
+}
+
+// dates used to be free-form strings, so accept the common formats of older logs
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    let date = date.trim();
+    ["%Y-%m-%d", "%d.%m.%Y", "%m/%d/%Y", "%Y/%m/%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+        .or_else(|| parse_timestamp(date).map(|t| t.date()))
+}
+
+fn deserialize_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let date = String::deserialize(deserializer)?;
+
+    // don't fail the whole list because of one unreadable date, the list keeps the original as legacy_date
+    Ok(parse_date(&date).unwrap_or_else(|| {
+        eprintln!("Error parsing date {}", date);
+        NaiveDate::default()
+    }))
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -273,7 +934,7 @@ impl CalibrationFrame for BiasFrame {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct FlatFrame {
     id: Uuid,
     camera_id: Uuid,
@@ -66,8 +66,30 @@ impl ImagingSessionList {
 pub struct ImagingSession {
     pub id: Uuid,
     pub folder_dir: String,
-    pub light_frame_id: Uuid,
+    // one light frame set per filter
+    #[serde(alias = "light_frame_id", deserialize_with = "deserialize_light_frame_ids")]
+    pub light_frame_ids: Vec<Uuid>,
     pub flat_frame_id: Uuid,
     pub dark_frame_id: Uuid,
     pub bias_frame_id: Uuid,
+    #[serde(default)]
+    pub site_id: Option<Uuid>,
+}
+
+// older sessions had a single light_frame_id
+fn deserialize_light_frame_ids<'de, D>(deserializer: D) -> Result<Vec<Uuid>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Uuid),
+        Many(Vec<Uuid>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(id) => vec![id],
+        OneOrMany::Many(ids) => ids,
+    })
 }
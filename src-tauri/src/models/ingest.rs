@@ -0,0 +1,219 @@
+use crate::image::FrameMetadata;
+use crate::models::equipment::{EquipmentList, EquipmentType};
+use crate::models::imaging_frames::{most_common, LightFrame};
+use crate::file_store;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum IngestMode {
+    OFF,
+    PROPOSE,
+    AUTO,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestRules {
+    pub mode: IngestMode,
+    // seconds a file's size has to stay unchanged before it counts as finished
+    pub settle_seconds: u64,
+    // light groups with fewer subs are always proposed, never auto-created
+    pub min_light_subs: usize,
+}
+
+impl Default for IngestRules {
+    fn default() -> Self {
+        IngestRules {
+            mode: IngestMode::PROPOSE,
+            settle_seconds: 10,
+            min_light_subs: 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum IngestFrameType {
+    LIGHT,
+    DARK,
+    BIAS,
+    FLAT,
+}
+
+impl IngestFrameType {
+    // guesses the frame type from the image type header, folder and file names or the exposure time
+    pub fn detect(path: &PathBuf, metadata: &FrameMetadata) -> IngestFrameType {
+        let path = match &metadata.image_type {
+            Some(image_type) => image_type.to_lowercase(),
+            None => path.to_string_lossy().to_lowercase(),
+        };
+        let sub_length = metadata.sub_length;
+
+        if path.contains("bias") || path.contains("offset") {
+            IngestFrameType::BIAS
+        } else if path.contains("dark") {
+            IngestFrameType::DARK
+        } else if path.contains("flat") {
+            IngestFrameType::FLAT
+        } else if sub_length.is_some_and(|s| s < 0.001) {
+            IngestFrameType::BIAS
+        } else {
+            IngestFrameType::LIGHT
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestGroup {
+    pub id: Uuid,
+    pub frame_type: IngestFrameType,
+    pub folder: PathBuf,
+    pub frames: Vec<PathBuf>,
+    // observing night of the first frame
+    pub date: Option<NaiveDate>,
+    pub gain: Option<i32>,
+    pub sub_length: Option<f64>,
+
+    // not persisted, read again from the frames when a proposal is accepted after a restart
+    #[serde(skip)]
+    pub metadata: Vec<FrameMetadata>,
+}
+
+// groups of captures waiting for the user to accept or dismiss them
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IngestProposals {
+    pub groups: Vec<IngestGroup>,
+}
+
+impl IngestProposals {
+    pub fn load(dir: PathBuf) -> Result<IngestProposals, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("ingest_proposals.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(dir: PathBuf, groups: &[IngestGroup]) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("ingest_proposals.json");
+
+        let ingest_proposals = IngestProposals {
+            groups: groups.to_vec(),
+        };
+
+        Ok(file_store::save(
+            &filename,
+            &serde_json::to_string_pretty(&ingest_proposals)?,
+        )?)
+    }
+}
+
+// a header value that fits several equipment items equally well, left for the user to decide
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AmbiguousMatch {
+    pub id: Uuid,
+    pub light_frame_id: Uuid,
+    pub equipment_type: EquipmentType,
+    pub header: String,
+    pub candidates: Vec<Uuid>,
+}
+
+impl AmbiguousMatch {
+    pub fn find(light_frame: &LightFrame, metadata: &[FrameMetadata], equipment_list: &EquipmentList) -> Vec<AmbiguousMatch> {
+        let headers = [
+            (EquipmentType::CAMERA, most_common(metadata.iter().filter_map(|m| m.camera.as_ref()).collect())),
+            (EquipmentType::TELESCOPE, most_common(metadata.iter().filter_map(|m| m.telescope.as_ref()).collect())),
+            (EquipmentType::FILTER, most_common(metadata.iter().filter_map(|m| m.filter.as_ref()).collect())),
+        ];
+
+        headers
+            .into_iter()
+            .filter(|(equipment_type, _)| light_frame.equipment_id(*equipment_type).is_nil())
+            .filter_map(|(equipment_type, header)| {
+                let header = header?;
+                let candidates = match equipment_type {
+                    EquipmentType::CAMERA => EquipmentList::candidates(&equipment_list.cameras, &header),
+                    EquipmentType::TELESCOPE => EquipmentList::candidates(&equipment_list.telescopes, &header),
+                    _ => EquipmentList::candidates(&equipment_list.filters, &header),
+                };
+                if candidates.len() < 2 {
+                    return None;
+                }
+
+                Some(AmbiguousMatch {
+                    id: Uuid::new_v4(),
+                    light_frame_id: light_frame.id,
+                    equipment_type,
+                    header,
+                    candidates,
+                })
+            })
+            .collect()
+    }
+}
+
+// ambiguous matches waiting for the user, kept across restarts
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AmbiguousMatches {
+    pub matches: Vec<AmbiguousMatch>,
+}
+
+impl AmbiguousMatches {
+    pub fn load(dir: PathBuf) -> Result<AmbiguousMatches, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("ambiguous_matches.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(dir: PathBuf, matches: &[AmbiguousMatch]) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("ambiguous_matches.json");
+
+        let ambiguous_matches = AmbiguousMatches {
+            matches: matches.to_vec(),
+        };
+
+        Ok(file_store::save(
+            &filename,
+            &serde_json::to_string_pretty(&ambiguous_matches)?,
+        )?)
+    }
+}
+
+// files that appeared in the library without a frame or image record, kept until they are imported or dismissed
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UnclassifiedFrames {
+    pub frames: Vec<PathBuf>,
+}
+
+impl UnclassifiedFrames {
+    pub fn load(dir: PathBuf) -> Result<UnclassifiedFrames, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("unclassified_frames.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(dir: PathBuf, frames: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("unclassified_frames.json");
+
+        let unclassified_frames = UnclassifiedFrames {
+            frames: frames.to_vec(),
+        };
+
+        Ok(file_store::save(
+            &filename,
+            &serde_json::to_string_pretty(&unclassified_frames)?,
+        )?)
+    }
+}
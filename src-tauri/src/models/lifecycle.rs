@@ -0,0 +1,82 @@
+use crate::models::equipment::{EquipmentList, EquipmentType};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum EquipmentStatus {
+    #[default]
+    ACTIVE,
+    RETIRED,
+    SOLD,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Lifecycle {
+    pub purchase_date: Option<NaiveDate>,
+    pub price: Option<f64>,
+    pub serial_number: String,
+    pub status: EquipmentStatus,
+    // when the item was retired or sold
+    pub status_date: Option<NaiveDate>,
+    pub maintenance: Vec<MaintenanceEntry>,
+}
+
+impl Lifecycle {
+    pub fn is_active(&self) -> bool {
+        self.status == EquipmentStatus::ACTIVE
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub enum MaintenanceKind {
+    COLLIMATION,
+    CLEANING,
+    FIRMWARE,
+    TILT,
+    OTHER,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MaintenanceEntry {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    pub kind: MaintenanceKind,
+    #[serde(default)]
+    pub notes: String,
+}
+
+// maintenance entry together with the item it was done on, for the timeline
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MaintenanceEvent {
+    pub equipment_id: Uuid,
+    pub equipment_type: EquipmentType,
+    pub equipment_name: String,
+    pub entry: MaintenanceEntry,
+}
+
+impl MaintenanceEvent {
+    // every maintenance entry of every item, oldest first
+    pub fn timeline(equipment_list: &EquipmentList) -> Vec<MaintenanceEvent> {
+        let mut events: Vec<MaintenanceEvent> = EquipmentType::ALL
+            .iter()
+            .flat_map(|equipment_type| {
+                equipment_list
+                    .items_of(*equipment_type)
+                    .into_iter()
+                    .flat_map(move |item| {
+                        item.lifecycle().maintenance.iter().map(move |entry| MaintenanceEvent {
+                            equipment_id: *item.id(),
+                            equipment_type: *equipment_type,
+                            equipment_name: item.view_name(),
+                            entry: entry.clone(),
+                        })
+                    })
+            })
+            .collect();
+
+        events.sort_by_key(|event| event.entry.date);
+        events
+    }
+}
@@ -1,7 +1,17 @@
+pub mod catalog;
+pub mod dso_catalog;
 pub mod equipment;
+pub mod filters;
 pub mod frontend;
 pub mod image_list;
 pub mod imaging_frames;
+pub mod ingest;
+pub mod lifecycle;
+pub mod optical_train;
+pub mod optics;
 pub mod imaging_session_list;
+pub mod observing_sites;
 pub mod preferences;
+pub mod projects;
 pub mod state;
+pub mod weather;
@@ -0,0 +1,115 @@
+use crate::file_store;
+use crate::image::FrameMetadata;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+// sessions taken within this distance of a site are linked to it
+const DETECTION_RADIUS_KM: f64 = 10.0;
+
+#[derive(Debug)]
+pub struct SiteList {
+    pub sites: HashMap<Uuid, ObservingSite>,
+}
+
+impl<'de> Deserialize<'de> for SiteList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sites: Vec<ObservingSite> = Vec::deserialize(deserializer)?;
+
+        Ok(SiteList {
+            sites: sites.into_iter().map(|site| (site.id, site)).collect(),
+        })
+    }
+}
+
+impl Serialize for SiteList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sites: Vec<&ObservingSite> = self.sites.values().collect();
+        sites.serialize(serializer)
+    }
+}
+
+impl SiteList {
+    pub fn load(dir: PathBuf) -> Result<SiteList, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("site_list.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(dir: PathBuf, sites: &HashMap<Uuid, ObservingSite>) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize().unwrap();
+        filename.push(".astrolog");
+        filename.push("site_list.json");
+
+        let site_list = SiteList { sites: sites.clone() };
+
+        Ok(file_store::save(
+            &filename,
+            &serde_json::to_string_pretty(&site_list)?,
+        )?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObservingSite {
+    pub id: Uuid,
+    pub name: String,
+    // degrees, east and north positive
+    pub latitude: f64,
+    pub longitude: f64,
+    // meters
+    #[serde(default)]
+    pub elevation: Option<f64>,
+    // IANA name like Europe/Berlin
+    #[serde(default)]
+    pub timezone: String,
+    #[serde(default)]
+    pub bortle: Option<u8>,
+    // mag/arcsec²
+    #[serde(default)]
+    pub sqm: Option<f64>,
+}
+
+impl ObservingSite {
+    // great circle distance in km
+    pub fn distance(&self, latitude: f64, longitude: f64) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * 6371.0 * a.sqrt().asin()
+    }
+
+    // the closest site to the average position in the frame headers or exif gps data
+    pub fn detect<'a>(
+        sites: &HashMap<Uuid, ObservingSite>,
+        metadata: impl IntoIterator<Item = &'a FrameMetadata>,
+    ) -> Option<Uuid> {
+        let positions: Vec<(f64, f64)> = metadata
+            .into_iter()
+            .filter_map(|m| Some((m.latitude?, m.longitude?)))
+            .collect();
+        if positions.is_empty() {
+            return None;
+        }
+        let latitude = positions.iter().map(|(lat, _)| lat).sum::<f64>() / positions.len() as f64;
+        let longitude = positions.iter().map(|(_, lon)| lon).sum::<f64>() / positions.len() as f64;
+
+        sites
+            .values()
+            .map(|site| (site.distance(latitude, longitude), site.id))
+            .filter(|(distance, _)| *distance <= DETECTION_RADIUS_KM)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, id)| id)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::models::equipment::EquipmentType;
+use crate::models::imaging_frames::LightFrame;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// a named rig that is reused across sessions, nil ids mean the train has no item of that type
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpticalTrain {
+    pub id: Uuid,
+    pub name: String,
+    pub telescope_id: Uuid,
+    // flattener, reducer or coma corrector
+    pub flattener_id: Uuid,
+    pub camera_id: Uuid,
+    pub mount_id: Uuid,
+    #[serde(default)]
+    pub filter_wheel_id: Uuid,
+    #[serde(default)]
+    pub focuser_id: Uuid,
+    #[serde(default)]
+    pub guide_scope_id: Uuid,
+    #[serde(default)]
+    pub guide_camera_id: Uuid,
+    #[serde(default)]
+    pub off_axis_guider_id: Uuid,
+    #[serde(default)]
+    pub rotator_id: Uuid,
+    #[serde(default)]
+    pub dew_heater_id: Uuid,
+    #[serde(default)]
+    pub field_rotator_id: Uuid,
+    // mm between corrector and sensor
+    #[serde(default)]
+    pub back_focus: Option<f64>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl OpticalTrain {
+    // filters change within a night, so they stay with the light frames
+    pub fn equipment_id(&self, equipment_type: EquipmentType) -> Option<&Uuid> {
+        match equipment_type {
+            EquipmentType::TELESCOPE => Some(&self.telescope_id),
+            EquipmentType::CAMERA => Some(&self.camera_id),
+            EquipmentType::MOUNT => Some(&self.mount_id),
+            EquipmentType::FLATTENER => Some(&self.flattener_id),
+            EquipmentType::FILTERWHEEL => Some(&self.filter_wheel_id),
+            EquipmentType::FOCUSER => Some(&self.focuser_id),
+            EquipmentType::GUIDESCOPE => Some(&self.guide_scope_id),
+            EquipmentType::GUIDECAMERA => Some(&self.guide_camera_id),
+            EquipmentType::OFFAXISGUIDER => Some(&self.off_axis_guider_id),
+            EquipmentType::ROTATOR => Some(&self.rotator_id),
+            EquipmentType::DEWHEATER => Some(&self.dew_heater_id),
+            EquipmentType::FIELDROTATOR => Some(&self.field_rotator_id),
+            EquipmentType::FILTER => None,
+        }
+    }
+
+    pub fn equipment_id_mut(&mut self, equipment_type: EquipmentType) -> Option<&mut Uuid> {
+        match equipment_type {
+            EquipmentType::TELESCOPE => Some(&mut self.telescope_id),
+            EquipmentType::CAMERA => Some(&mut self.camera_id),
+            EquipmentType::MOUNT => Some(&mut self.mount_id),
+            EquipmentType::FLATTENER => Some(&mut self.flattener_id),
+            EquipmentType::FILTERWHEEL => Some(&mut self.filter_wheel_id),
+            EquipmentType::FOCUSER => Some(&mut self.focuser_id),
+            EquipmentType::GUIDESCOPE => Some(&mut self.guide_scope_id),
+            EquipmentType::GUIDECAMERA => Some(&mut self.guide_camera_id),
+            EquipmentType::OFFAXISGUIDER => Some(&mut self.off_axis_guider_id),
+            EquipmentType::ROTATOR => Some(&mut self.rotator_id),
+            EquipmentType::DEWHEATER => Some(&mut self.dew_heater_id),
+            EquipmentType::FIELDROTATOR => Some(&mut self.field_rotator_id),
+            EquipmentType::FILTER => None,
+        }
+    }
+
+    // links the light frame to this train and takes over its equipment
+    pub fn apply(&self, light_frame: &mut LightFrame) {
+        light_frame.optical_train_id = Some(self.id);
+        for equipment_type in EquipmentType::ALL {
+            if let Some(id) = self.equipment_id(equipment_type).filter(|id| !id.is_nil()) {
+                *light_frame.equipment_id_mut(equipment_type) = *id;
+            }
+        }
+    }
+
+    // equipment the light frame uses instead of the one of this train
+    pub fn overrides(&self, light_frame: &LightFrame) -> Vec<EquipmentType> {
+        EquipmentType::ALL
+            .into_iter()
+            .filter(|equipment_type| {
+                self.equipment_id(*equipment_type)
+                    .is_some_and(|id| !id.is_nil() && id != light_frame.equipment_id(*equipment_type))
+            })
+            .collect()
+    }
+
+    // imported frames only know camera and telescope, so those have to match
+    pub fn matches(&self, light_frame: &LightFrame) -> bool {
+        !light_frame.camera_id.is_nil()
+            && self.camera_id == light_frame.camera_id
+            && (self.telescope_id.is_nil() || self.telescope_id == light_frame.telescope_id)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::models::equipment::{Camera, Flattener, Telescope};
+use serde::{Deserialize, Serialize};
+
+// 1 radian in arcseconds
+const ARCSEC_PER_RADIAN: f64 = 206_264.806;
+// used when a session has no seeing recorded
+pub const TYPICAL_SEEING: f64 = 2.0;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum Sampling {
+    UNDERSAMPLED,
+    OPTIMAL,
+    OVERSAMPLED,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpticsMetrics {
+    // mm
+    pub effective_focal_length: f64,
+    pub focal_ratio: Option<f64>,
+    // µm
+    pub pixel_size: Option<f64>,
+    // arcsec/pixel
+    pub image_scale: Option<f64>,
+    // arcmin (width, height)
+    pub field_of_view: Option<(f64, f64)>,
+    pub sampling: Option<Sampling>,
+}
+
+impl OpticsMetrics {
+    pub fn new(
+        telescope: &Telescope,
+        flattener: Option<&Flattener>,
+        camera: Option<&Camera>,
+        seeing: Option<f64>,
+    ) -> OpticsMetrics {
+        let factor = flattener.map(|f| f.factor()).filter(|f| *f > 0.0).unwrap_or(1.0);
+        let effective_focal_length = telescope.focal_length() as f64 * factor;
+
+        let focal_ratio = if telescope.aperture() > 0 {
+            Some(effective_focal_length / telescope.aperture() as f64)
+        } else {
+            None
+        };
+
+        let pixel_size = camera.and_then(|c| c.pixel_size());
+        let image_scale = pixel_size
+            .filter(|_| effective_focal_length > 0.0)
+            .map(|p| ARCSEC_PER_RADIAN * p / 1000.0 / effective_focal_length);
+
+        let field_of_view = camera
+            .and_then(|c| c.sensor_size())
+            .filter(|_| effective_focal_length > 0.0)
+            .map(|(width, height)| (angle(width, effective_focal_length), angle(height, effective_focal_length)));
+
+        // a star should cover two to three pixels of the seeing disk
+        let seeing = seeing.filter(|s| *s > 0.0).unwrap_or(TYPICAL_SEEING);
+        let sampling = image_scale.map(|scale| {
+            let pixels_per_fwhm = seeing / scale;
+            if pixels_per_fwhm < 2.0 {
+                Sampling::UNDERSAMPLED
+            } else if pixels_per_fwhm > 3.0 {
+                Sampling::OVERSAMPLED
+            } else {
+                Sampling::OPTIMAL
+            }
+        });
+
+        OpticsMetrics {
+            effective_focal_length,
+            focal_ratio,
+            pixel_size,
+            image_scale,
+            field_of_view,
+            sampling,
+        }
+    }
+}
+
+// angle in arcmin covered by a sensor side of the given length at the given focal length (both mm)
+fn angle(size: f64, focal_length: f64) -> f64 {
+    2.0 * (size / (2.0 * focal_length)).atan() * ARCSEC_PER_RADIAN / 60.0
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SensorMetrics {
+    // µm
+    pub pixel_size: Option<f64>,
+    // mm (width, height)
+    pub sensor_size: Option<(f64, f64)>,
+    pub unity_gain: Option<f64>,
+    // e- at the requested gain
+    pub read_noise: Option<f64>,
+    // e- at the requested gain
+    pub full_well: Option<f64>,
+}
+
+impl SensorMetrics {
+    pub fn new(camera: &Camera, gain: Option<i32>) -> SensorMetrics {
+        SensorMetrics {
+            pixel_size: camera.pixel_size(),
+            sensor_size: camera.sensor_size(),
+            unity_gain: camera.unity_gain(),
+            read_noise: gain.and_then(|gain| camera.read_noise(gain)),
+            full_well: gain.and_then(|gain| camera.full_well(gain)),
+        }
+    }
+}
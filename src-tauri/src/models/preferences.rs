@@ -1,4 +1,5 @@
 use crate::file_store;
+use crate::models::ingest::IngestRules;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 use std::error::Error;
@@ -8,6 +9,8 @@ use std::path::PathBuf;
 pub struct Preferences {
     pub storage: Storage,
     user: User,
+    #[serde(default)]
+    pub ingest: IngestRules,
 }
 
 impl Preferences {
@@ -20,10 +23,20 @@ impl Preferences {
             },
             user: User {
                 weather_api_key: "".to_string(),
+                weather_file: "".to_string(),
             },
+            ingest: IngestRules::default(),
         }
     }
 
+    pub fn weather_api_key(&self) -> &str {
+        &self.user.weather_api_key
+    }
+
+    pub fn weather_file(&self) -> &str {
+        &self.user.weather_file
+    }
+
     pub fn load(dir: PathBuf) -> Result<Preferences, Box<dyn Error>> {
         let mut filename = dir;
         filename.push("preferences.json");
@@ -44,12 +57,15 @@ impl Preferences {
 pub struct Storage {
     pub root_directory: PathBuf,
     backup_directory: PathBuf,
-    source_directory: PathBuf,
+    pub source_directory: PathBuf,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct User {
     weather_api_key: String,
+    // json file of hourly weather records used instead of OpenWeather, for working offline and testing
+    #[serde(default)]
+    weather_file: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
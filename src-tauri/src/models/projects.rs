@@ -0,0 +1,100 @@
+use crate::file_store;
+use crate::models::dso_catalog::designation_key;
+use crate::models::filters::FilterType;
+use crate::models::imaging_frames::LightFrame;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct ProjectList {
+    pub projects: HashMap<Uuid, ImagingProject>,
+}
+
+impl<'de> Deserialize<'de> for ProjectList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let projects: Vec<ImagingProject> = Vec::deserialize(deserializer)?;
+
+        Ok(ProjectList {
+            projects: projects.into_iter().map(|project| (project.id, project)).collect(),
+        })
+    }
+}
+
+impl Serialize for ProjectList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let projects: Vec<&ImagingProject> = self.projects.values().collect();
+        projects.serialize(serializer)
+    }
+}
+
+impl ProjectList {
+    pub fn load(dir: PathBuf) -> Result<ProjectList, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("project_list.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(dir: PathBuf, projects: &HashMap<Uuid, ImagingProject>) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("project_list.json");
+
+        let project_list = ProjectList {
+            projects: projects.clone(),
+        };
+
+        Ok(file_store::save(
+            &filename,
+            &serde_json::to_string_pretty(&project_list)?,
+        )?)
+    }
+}
+
+// a target imaged over many nights until the integration goal of every filter is reached
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImagingProject {
+    pub id: Uuid,
+    pub name: String,
+    // catalog designation or the name in the frame headers
+    pub target: String,
+    // none accepts sessions of any optical train
+    pub optical_train_id: Option<Uuid>,
+    pub goals: Vec<IntegrationGoal>,
+    pub session_ids: Vec<Uuid>,
+    // new sessions of the target and optical train are added on import
+    pub auto_assign: bool,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IntegrationGoal {
+    pub filter_type: FilterType,
+    // hours
+    pub hours: f64,
+}
+
+impl ImagingProject {
+    // whether the light frames of a session were taken of the target with the optical train of this project
+    pub fn matches<'a>(&self, light_frames: impl IntoIterator<Item = &'a LightFrame>) -> bool {
+        light_frames.into_iter().any(|light_frame| self.matches_frame(light_frame))
+    }
+
+    // whether a single light frame set counts toward this project
+    pub fn matches_frame(&self, light_frame: &LightFrame) -> bool {
+        designation_key(&light_frame.target) == designation_key(&self.target)
+            && self
+                .optical_train_id
+                .is_none_or(|id| light_frame.optical_train_id == Some(id))
+    }
+}
@@ -1,20 +1,46 @@
 use std::collections::HashMap;
+use crate::models::catalog::EquipmentCatalog;
+use crate::models::dso_catalog::{designation_key, DeepSkyObject, DsoCatalog, TargetAliases, Wishlist};
 use crate::models::equipment::EquipmentList;
 use crate::models::image_list::{Image, ImageList};
-use crate::models::imaging_frames::ImagingFrameList;
+use crate::models::imaging_frames::{ImagingFrameList, LightFrame};
 use crate::models::imaging_session_list::{ImagingSession, ImagingSessionList};
+use crate::models::observing_sites::{ObservingSite, SiteList};
+use crate::models::optics::OpticsMetrics;
 use crate::models::preferences::Preferences;
-use std::path::PathBuf;
+use crate::models::projects::{ImagingProject, ProjectList};
+use crate::models::weather::{HourlyWeather, WeatherCache};
+use crate::weather::{self, WeatherRequest};
+use crate::library_watcher::LibraryWatcher;
+use crate::ingest::SourceWatcher;
+use crate::models::ingest::{AmbiguousMatch, AmbiguousMatches, IngestGroup, IngestProposals, UnclassifiedFrames};
+use std::error::Error;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
 use uuid::Uuid;
 
+// arcmin, a typical small refractor with an aps-c sensor
+const DEFAULT_FIELD_OF_VIEW: (f64, f64) = (120.0, 80.0);
+
 pub struct AppState {
     pub preferences: Preferences,
     pub equipment_list: EquipmentList,
+    pub equipment_catalog: EquipmentCatalog,
+    pub dso_catalog: DsoCatalog,
+    pub target_aliases: TargetAliases,
+    pub wishlist: Wishlist,
     pub imaging_frame_list: ImagingFrameList,
     pub imaging_sessions: HashMap<Uuid, ImagingSession>,
     pub image_list: HashMap<Uuid, Image>,
+    pub sites: HashMap<Uuid, ObservingSite>,
+    pub projects: HashMap<Uuid, ImagingProject>,
+    pub weather_cache: WeatherCache,
     pub close_lock: bool,
+    pub unclassified_frames: Vec<PathBuf>,
+    pub library_watcher: Option<LibraryWatcher>,
+    pub ingest_proposals: Vec<IngestGroup>,
+    pub ambiguous_matches: Vec<AmbiguousMatch>,
+    pub source_watcher: Option<SourceWatcher>,
 }
 
 impl AppState {
@@ -24,6 +50,14 @@ impl AppState {
         let mut imaging_frame_list = ImagingFrameList::new();
         let mut imaging_sessions: HashMap<Uuid, ImagingSession> = HashMap::new();
         let mut image_list: HashMap<Uuid, Image> = HashMap::new();
+        let mut sites: HashMap<Uuid, ObservingSite> = HashMap::new();
+        let mut projects: HashMap<Uuid, ImagingProject> = HashMap::new();
+        let mut target_aliases = TargetAliases::default();
+        let mut wishlist = Wishlist::default();
+        let mut weather_cache = WeatherCache::default();
+        let mut unclassified_frames: Vec<PathBuf> = vec![];
+        let mut ingest_proposals: Vec<IngestGroup> = vec![];
+        let mut ambiguous_matches: Vec<AmbiguousMatch> = vec![];
 
         match Preferences::load(app_handle.path().app_data_dir().unwrap()) {
             Ok(data) => {
@@ -70,13 +104,304 @@ impl AppState {
             }
         }
 
+        match SiteList::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                sites = data.sites;
+            }
+            Err(err) => {
+                eprintln!("Error loading site_list {}: {}", "", err);
+            }
+        }
+
+        match ProjectList::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                projects = data.projects;
+            }
+            Err(err) => {
+                eprintln!("Error loading project_list {}: {}", "", err);
+            }
+        }
+
+        match TargetAliases::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                target_aliases = data;
+            }
+            Err(err) => {
+                eprintln!("Error loading target_aliases {}: {}", "", err);
+            }
+        }
+
+        match Wishlist::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                wishlist = data;
+            }
+            Err(err) => {
+                eprintln!("Error loading wishlist {}: {}", "", err);
+            }
+        }
+
+        match UnclassifiedFrames::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                unclassified_frames = data.frames;
+            }
+            Err(err) => {
+                eprintln!("Error loading unclassified_frames {}: {}", "", err);
+            }
+        }
+
+        match IngestProposals::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                ingest_proposals = data.groups;
+            }
+            Err(err) => {
+                eprintln!("Error loading ingest_proposals {}: {}", "", err);
+            }
+        }
+
+        match AmbiguousMatches::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                ambiguous_matches = data.matches;
+            }
+            Err(err) => {
+                eprintln!("Error loading ambiguous_matches {}: {}", "", err);
+            }
+        }
+
+        match WeatherCache::load(PathBuf::from(&preferences.storage.root_directory)) {
+            Ok(data) => {
+                weather_cache = data;
+            }
+            Err(err) => {
+                eprintln!("Error loading weather_cache {}: {}", "", err);
+            }
+        }
+
+        let equipment_catalog = EquipmentCatalog::load(app_handle.path().app_data_dir().unwrap());
+        let dso_catalog = DsoCatalog::load(app_handle.path().app_data_dir().unwrap());
+
         AppState {
             preferences,
             equipment_list,
+            equipment_catalog,
+            dso_catalog,
+            target_aliases,
+            wishlist,
             imaging_frame_list,
             imaging_sessions,
             image_list,
+            sites,
+            projects,
+            weather_cache,
             close_lock: false,
+            unclassified_frames,
+            library_watcher: None,
+            ingest_proposals,
+            ambiguous_matches,
+            source_watcher: None,
+        }
+    }
+
+    // adds a new imaging session with one light frame set per filter and saves both lists
+    pub fn add_imaging_session(
+        &mut self,
+        light_frames: Vec<LightFrame>,
+        folder_dir: String,
+        site_id: Option<Uuid>,
+    ) -> Result<Uuid, Box<dyn Error>> {
+        let session_ids = self.add_imaging_sessions(vec![(light_frames, folder_dir, site_id)])?;
+        Ok(session_ids[0])
+    }
+
+    // adds the imaging sessions and saves them together, none of them is kept if saving fails
+    pub fn add_imaging_sessions(
+        &mut self,
+        sessions: Vec<(Vec<LightFrame>, String, Option<Uuid>)>,
+    ) -> Result<Vec<Uuid>, Box<dyn Error>> {
+        let root_directory = self.preferences.storage.root_directory.clone();
+
+        let mut added: Vec<(ImagingSession, Option<Uuid>)> = vec![];
+        for (light_frames, folder_dir, site_id) in sessions {
+            let session = ImagingSession {
+                id: Uuid::new_v4(),
+                folder_dir,
+                light_frame_ids: light_frames.iter().map(|l| l.id).collect(),
+                flat_frame_id: Uuid::nil(),
+                dark_frame_id: Uuid::nil(),
+                bias_frame_id: Uuid::nil(),
+                site_id,
+            };
+
+            // the weather is fetched after adding the session, without holding the state lock
+            let site = site_id.and_then(|id| self.sites.get(&id)).cloned();
+
+            for mut light_frame in light_frames {
+                self.normalize_target(&mut light_frame, Some(Path::new(&session.folder_dir)));
+                if let Some(site) = &site {
+                    light_frame.update_sky(site);
+                }
+                self.imaging_frame_list.light_frames.insert(light_frame.id, light_frame);
+            }
+            self.imaging_sessions.insert(session.id, session.clone());
+            let project_id = self.assign_project(&session.id);
+            added.push((session, project_id));
+        }
+
+        let result = ImagingFrameList::save(root_directory.clone(), &self.imaging_frame_list)
+            .and_then(|_| ImagingSessionList::save(root_directory.clone(), &self.imaging_sessions))
+            .and_then(|_| match added.iter().any(|(_, project_id)| project_id.is_some()) {
+                true => ProjectList::save(root_directory, &self.projects),
+                false => Ok(()),
+            });
+
+        if let Err(err) = result {
+            // Revert the change if save fails
+            for (session, project_id) in &added {
+                for id in &session.light_frame_ids {
+                    self.imaging_frame_list.light_frames.remove(id);
+                }
+                self.imaging_sessions.remove(&session.id);
+                if let Some(project) = project_id.and_then(|id| self.projects.get_mut(&id)) {
+                    project.session_ids.retain(|id| *id != session.id);
+                }
+            }
+            return Err(err);
+        }
+
+        Ok(added.iter().map(|(session, _)| session.id).collect())
+    }
+
+    // the weather request for the site over the time the light frames were taken, with the provider
+    // in the preferences. none without a provider or timestamps
+    pub fn weather_request(&self, site: &ObservingSite, light_frames: &[&LightFrame]) -> Option<WeatherRequest> {
+        let spans: Vec<_> = light_frames.iter().filter_map(|light_frame| light_frame.time_span()).collect();
+        let start = spans.iter().map(|(start, _)| *start).min()?;
+        let end = spans.iter().map(|(_, end)| *end).max()?;
+        let provider = weather::provider(&self.preferences)?;
+
+        Some(WeatherRequest::new(provider, site.latitude, site.longitude, start, end))
+    }
+
+    // the hours of the request if all of them were fetched before
+    pub fn cached_weather(&self, request: &WeatherRequest) -> Option<Vec<HourlyWeather>> {
+        if !request.cached() {
+            return None;
+        }
+        let (latitude, longitude) = request.position();
+        let (start, end) = request.span();
+        self.weather_cache.get(latitude, longitude, &start, &end)
+    }
+
+    pub fn cache_weather(&mut self, request: &WeatherRequest, hours: &[HourlyWeather]) {
+        if !request.cached() || hours.is_empty() {
+            return;
+        }
+        let (latitude, longitude) = request.position();
+        self.weather_cache.insert(latitude, longitude, hours);
+        if let Err(err) = self.weather_cache.save(self.preferences.storage.root_directory.clone()) {
+            // the cache only saves requests, the weather is kept with the light frames
+            eprintln!("Error saving weather_cache: {}", err);
+        }
+    }
+
+    // adds a session that is in no project yet to the project collecting its target and optical train
+    // automatically, returns the id of that project. sessions matching several projects are left for the user
+    pub fn assign_project(&mut self, session_id: &Uuid) -> Option<Uuid> {
+        if self.projects.values().any(|project| project.session_ids.contains(session_id)) {
+            return None;
+        }
+        let light_frames: Vec<&LightFrame> = self
+            .imaging_sessions
+            .get(session_id)?
+            .light_frame_ids
+            .iter()
+            .filter_map(|id| self.imaging_frame_list.light_frames.get(id))
+            .collect();
+
+        let mut candidates = self
+            .projects
+            .values_mut()
+            .filter(|project| project.auto_assign && project.matches(light_frames.iter().copied()));
+        let project = candidates.next()?;
+        if candidates.next().is_some() {
+            return None;
+        }
+        project.session_ids.push(*session_id);
+        Some(project.id)
+    }
+
+    // the site of the imaging session a light frame belongs to
+    pub fn site_of(&self, light_frame_id: &Uuid) -> Option<&ObservingSite> {
+        self.imaging_sessions
+            .values()
+            .find(|session| session.light_frame_ids.contains(light_frame_id))
+            .and_then(|session| session.site_id)
+            .and_then(|id| self.sites.get(&id))
+    }
+
+    // the most likely main target of a frame pointing, e.g. from RA/DEC or WCS headers
+    pub fn field_target(&self, light_frame: &LightFrame) -> Option<&DeepSkyObject> {
+        let pointing = light_frame.target_position()?;
+        let field_of_view = light_frame.field_of_view.unwrap_or(DEFAULT_FIELD_OF_VIEW);
+        Some(self.dso_catalog.in_field(&pointing, field_of_view).first()?.object)
+    }
+
+    // the field of view of an optical train, the default if it has no camera with a known sensor
+    pub fn train_field_of_view(&self, optical_train_id: Option<Uuid>) -> (f64, f64) {
+        let equipment_list = &self.equipment_list;
+        optical_train_id
+            .and_then(|id| equipment_list.optical_trains.get(&id))
+            .and_then(|train| {
+                OpticsMetrics::new(
+                    equipment_list.telescopes.get(&train.telescope_id)?,
+                    equipment_list.flatteners.get(&train.flattener_id),
+                    equipment_list.cameras.get(&train.camera_id),
+                    None,
+                )
+                .field_of_view
+            })
+            .unwrap_or(DEFAULT_FIELD_OF_VIEW)
+    }
+
+    // the catalog object of a target name, names linked by hand first
+    pub fn resolve_target(&self, target: &str) -> Option<&DeepSkyObject> {
+        self.target_aliases
+            .aliases
+            .get(&designation_key(target))
+            .and_then(|id| self.dso_catalog.object(id))
+            .or_else(|| self.dso_catalog.resolve(target))
+    }
+
+    // renames the target to its catalog designation and takes its coordinates if the headers had none.
+    // frames without a target name get the main object in their field, or else the name of their folder.
+    // returns whether anything changed
+    pub fn normalize_target(&self, light_frame: &mut LightFrame, folder: Option<&Path>) -> bool {
+        let mut named = false;
+        let object = if light_frame.target.trim().is_empty() {
+            match self.field_target(light_frame) {
+                Some(object) => Some(object),
+                None => {
+                    let name = folder
+                        .and_then(|folder| folder.file_name())
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    named = !name.is_empty();
+                    light_frame.target = name;
+                    named.then(|| self.resolve_target(&light_frame.target)).flatten()
+                }
+            }
+        } else {
+            self.resolve_target(&light_frame.target)
+        };
+        let Some(object) = object else {
+            return named;
+        };
+        let changed = light_frame.target != object.id || light_frame.ra.is_none() || light_frame.dec.is_none();
+
+        light_frame.target = object.id.clone();
+        if light_frame.ra.is_none() || light_frame.dec.is_none() {
+            light_frame.ra = Some(object.ra);
+            light_frame.dec = Some(object.dec);
         }
+        changed
     }
 }
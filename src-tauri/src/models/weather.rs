@@ -0,0 +1,138 @@
+use crate::file_store;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HourlyWeather {
+    // start of the hour
+    pub time: DateTime<Utc>,
+    // °C
+    pub temperature: Option<f64>,
+    // %
+    pub humidity: Option<f64>,
+    // °C
+    pub dew_point: Option<f64>,
+    // m/s
+    pub wind_speed: Option<f64>,
+    // %
+    pub cloud_cover: Option<f64>,
+    // arcsec, only some providers forecast it
+    pub seeing: Option<f64>,
+}
+
+// the start of the hour the time falls in
+pub fn hour_of(time: &DateTime<Utc>) -> DateTime<Utc> {
+    let timestamp = time.timestamp();
+    DateTime::from_timestamp(timestamp - timestamp.rem_euclid(3600), 0).unwrap_or(*time)
+}
+
+// every hour from the hour of start through the hour of end
+pub fn hours_between(start: &DateTime<Utc>, end: &DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut hours = vec![];
+    let mut hour = hour_of(start);
+    while hour <= *end {
+        hours.push(hour);
+        hour += Duration::hours(1);
+    }
+    hours
+}
+
+// weather fetched before, by position rounded to about a kilometer, so re-imports stay offline
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WeatherCache {
+    pub locations: HashMap<String, Vec<HourlyWeather>>,
+}
+
+impl WeatherCache {
+    pub fn load(dir: PathBuf) -> Result<WeatherCache, Box<dyn Error>> {
+        let mut filename = dir;
+        filename.push(".astrolog");
+        filename.push("weather_cache.json");
+        Ok(file_store::load(&filename)?)
+    }
+
+    pub fn save(&self, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+        let mut filename = dir.canonicalize()?;
+        filename.push(".astrolog");
+        filename.push("weather_cache.json");
+
+        Ok(file_store::save(&filename, &serde_json::to_string(self)?)?)
+    }
+
+    fn key(latitude: f64, longitude: f64) -> String {
+        format!("{:.2},{:.2}", latitude, longitude)
+    }
+
+    // the cached hours of the span, none if any of them is missing
+    pub fn get(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+    ) -> Option<Vec<HourlyWeather>> {
+        let cached = self.locations.get(&WeatherCache::key(latitude, longitude))?;
+        hours_between(start, end)
+            .iter()
+            .map(|hour| cached.iter().find(|weather| weather.time == *hour).cloned())
+            .collect()
+    }
+
+    // newer records replace cached ones of the same hour
+    pub fn insert(&mut self, latitude: f64, longitude: f64, hours: &[HourlyWeather]) {
+        let cached = self.locations.entry(WeatherCache::key(latitude, longitude)).or_default();
+        cached.retain(|weather| !hours.iter().any(|hour| hour.time == weather.time));
+        cached.extend(hours.iter().cloned());
+        cached.sort_by_key(|weather| weather.time);
+    }
+}
+
+// averages over the hours a light frame set was taken in
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WeatherConditions {
+    pub hours: Vec<HourlyWeather>,
+    pub temperature: Option<f64>,
+    pub humidity: Option<f64>,
+    pub dew_point: Option<f64>,
+    pub wind_speed: Option<f64>,
+    pub cloud_cover: Option<f64>,
+    pub seeing: Option<f64>,
+}
+
+fn mean(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    let values: Vec<f64> = values.flatten().collect();
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+impl WeatherConditions {
+    pub fn new(hours: Vec<HourlyWeather>) -> Option<WeatherConditions> {
+        if hours.is_empty() {
+            return None;
+        }
+        Some(WeatherConditions {
+            temperature: mean(hours.iter().map(|h| h.temperature)),
+            humidity: mean(hours.iter().map(|h| h.humidity)),
+            dew_point: mean(hours.iter().map(|h| h.dew_point)),
+            wind_speed: mean(hours.iter().map(|h| h.wind_speed)),
+            cloud_cover: mean(hours.iter().map(|h| h.cloud_cover)),
+            seeing: mean(hours.iter().map(|h| h.seeing)),
+            hours,
+        })
+    }
+
+    // the newer conditions, with the values they don't have taken from the older ones
+    pub fn or(self, old: WeatherConditions) -> WeatherConditions {
+        WeatherConditions {
+            temperature: self.temperature.or(old.temperature),
+            humidity: self.humidity.or(old.humidity),
+            dew_point: self.dew_point.or(old.dew_point),
+            wind_speed: self.wind_speed.or(old.wind_speed),
+            cloud_cover: self.cloud_cover.or(old.cloud_cover),
+            seeing: self.seeing.or(old.seeing),
+            hours: self.hours,
+        }
+    }
+}
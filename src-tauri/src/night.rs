@@ -0,0 +1,127 @@
+use crate::astronomy::separation;
+use crate::image::FrameMetadata;
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, Offset, TimeZone, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+// frames pointing further apart than this share of the field are separate mosaic panels,
+// dithering only moves a few pixels
+const PANEL_SEPARATION: f64 = 0.2;
+// arcmin, used when the headers don't tell the field of view
+const DEFAULT_PANEL_SEPARATION: f64 = 10.0;
+
+// local offset at the site: mean solar time from the longitude, else the timezone of this computer
+pub fn site_offset(longitude: Option<f64>, timestamp: &DateTime<Utc>) -> FixedOffset {
+    longitude
+        .and_then(|longitude| FixedOffset::east_opt((longitude / 15.0 * 3600.0).round() as i32))
+        .unwrap_or_else(|| Local.offset_from_utc_datetime(&timestamp.naive_utc()).fix())
+}
+
+// nights run from noon to noon, so frames taken after midnight belong to the previous date
+pub fn observing_night(timestamp: &DateTime<Utc>, offset: FixedOffset) -> NaiveDate {
+    (timestamp.with_timezone(&offset) - Duration::hours(12)).date_naive()
+}
+
+// the night of the timestamp, else the date of a header without a time, else the night the file was written
+pub fn night_of(metadata: &FrameMetadata) -> Option<NaiveDate> {
+    if let Some(timestamp) = metadata.timestamp {
+        return Some(observing_night(&timestamp, site_offset(metadata.longitude, &timestamp)));
+    }
+    if metadata.night.is_some() {
+        return metadata.night;
+    }
+    let modified = metadata.modified?;
+    Some(observing_night(&modified, site_offset(metadata.longitude, &modified)))
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct LightSetKey {
+    night: Option<NaiveDate>,
+    target: Option<String>,
+    filter: Option<String>,
+    camera: Option<String>,
+}
+
+// splits a batch of lights into observing nights, and within a night by target, filter and camera
+pub fn group_lights(
+    frames: Vec<PathBuf>,
+    metadata: Vec<FrameMetadata>,
+) -> Vec<(Vec<PathBuf>, Vec<FrameMetadata>)> {
+    let mut groups: HashMap<LightSetKey, (Vec<PathBuf>, Vec<FrameMetadata>)> = HashMap::new();
+
+    for (frame, data) in frames.into_iter().zip(metadata) {
+        let key = LightSetKey {
+            night: night_of(&data),
+            target: data.target.as_ref().map(|t| t.trim().to_lowercase()),
+            filter: data.filter.as_ref().map(|f| f.trim().to_lowercase()),
+            camera: data.camera.as_ref().map(|c| c.trim().to_lowercase()),
+        };
+
+        let group = groups.entry(key).or_default();
+        group.0.push(frame);
+        group.1.push(data);
+    }
+
+    let mut groups: Vec<(Vec<PathBuf>, Vec<FrameMetadata>)> = groups
+        .into_values()
+        .flat_map(|(frames, metadata)| split_panels(frames, metadata))
+        .collect();
+    groups.sort_by_key(|group| group.1.iter().filter_map(|m| m.timestamp).min());
+    groups
+}
+
+// splits a light set by pointing, so every mosaic panel becomes its own set
+fn split_panels(frames: Vec<PathBuf>, metadata: Vec<FrameMetadata>) -> Vec<(Vec<PathBuf>, Vec<FrameMetadata>)> {
+    let mut panels: Vec<(Vec<PathBuf>, Vec<FrameMetadata>)> = vec![];
+
+    for (frame, data) in frames.into_iter().zip(metadata) {
+        let limit = data
+            .field_of_view
+            .map_or(DEFAULT_PANEL_SEPARATION, |(width, height)| width.min(height) * PANEL_SEPARATION)
+            / 60.0;
+
+        // frames without coordinates stay together
+        let panel = panels.iter_mut().find(|(_, panel)| {
+            match (panel[0].pointing(), data.pointing()) {
+                (Some(a), Some(b)) => separation(&a, &b) <= limit,
+                (None, None) => true,
+                _ => false,
+            }
+        });
+        match panel {
+            Some(panel) => {
+                panel.0.push(frame);
+                panel.1.push(data);
+            }
+            None => panels.push((vec![frame], vec![data])),
+        }
+    }
+
+    panels
+}
+
+// bundles light sets of the same night, target and camera into one imaging session
+pub fn group_sessions(
+    sets: Vec<(Vec<PathBuf>, Vec<FrameMetadata>)>,
+) -> Vec<Vec<(Vec<PathBuf>, Vec<FrameMetadata>)>> {
+    let mut sessions: Vec<(LightSetKey, Vec<(Vec<PathBuf>, Vec<FrameMetadata>)>)> = vec![];
+
+    for set in sets {
+        let key = match set.1.first() {
+            Some(data) => LightSetKey {
+                night: night_of(data),
+                target: data.target.as_ref().map(|t| t.trim().to_lowercase()),
+                filter: None,
+                camera: data.camera.as_ref().map(|c| c.trim().to_lowercase()),
+            },
+            None => continue,
+        };
+
+        match sessions.iter_mut().find(|(k, _)| k == &key) {
+            Some((_, session)) => session.push(set),
+            None => sessions.push((key, vec![set])),
+        }
+    }
+
+    sessions.into_iter().map(|(_, session)| session).collect()
+}
@@ -0,0 +1,217 @@
+use crate::astronomy::{moon_horizontal, moon_illumination, moon_position, separation, sun_position, to_horizontal};
+use crate::models::dso_catalog::{DeepSkyObject, ObjectType};
+use crate::models::observing_sites::ObservingSite;
+use crate::night::site_offset;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+// minutes between two samples of the altitude curves
+const STEP_MINUTES: i64 = 10;
+// samples from noon to noon
+const SAMPLES: i64 = 24 * 60 / STEP_MINUTES;
+// sun altitude at the start and end of astronomical twilight
+const ASTRONOMICAL_TWILIGHT: f64 = -18.0;
+// degrees, below this the air mass spoils most subs
+pub const DEFAULT_MIN_ALTITUDE: f64 = 30.0;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NightPlan {
+    pub date: NaiveDate,
+    // time of the first sample of the curves, local noon at the site
+    pub start: DateTime<Utc>,
+    pub step_minutes: i64,
+    // end and start of astronomical twilight, none if it never gets fully dark
+    pub dusk: Option<DateTime<Utc>>,
+    pub dawn: Option<DateTime<Utc>>,
+    // astronomical darkness with the moon below the horizon
+    pub dark_windows: Vec<TimeWindow>,
+    // percent
+    pub moon_illumination: f64,
+    pub moon_altitudes: Vec<f64>,
+    // best target first
+    pub targets: Vec<TargetPlan>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TargetPlan {
+    pub object_id: String,
+    pub name: String,
+    pub object_type: ObjectType,
+    pub wishlist: bool,
+    pub altitudes: Vec<f64>,
+    // highest altitude during darkness
+    pub max_altitude: f64,
+    pub transit: DateTime<Utc>,
+    // hours in darkness above the minimum altitude, and how many of them with the moon down
+    pub dark_hours: f64,
+    pub moon_free_hours: f64,
+    // longest stretch in darkness above the minimum altitude
+    pub best_window: Option<TimeWindow>,
+    // degrees at midnight
+    pub moon_separation: f64,
+    pub framing: f64,
+    pub score: f64,
+}
+
+// everything about a night that doesn't depend on the target
+struct Sky {
+    times: Vec<DateTime<Utc>>,
+    dark: Vec<bool>,
+    moon_altitudes: Vec<f64>,
+    moon_illumination: f64,
+}
+
+impl Sky {
+    fn new(site: &ObservingSite, start: DateTime<Utc>) -> Sky {
+        let times: Vec<DateTime<Utc>> = (0..SAMPLES)
+            .map(|i| start + Duration::minutes(i * STEP_MINUTES))
+            .collect();
+        let dark = times
+            .iter()
+            .map(|time| {
+                to_horizontal(&sun_position(time), time, site.latitude, site.longitude).altitude <= ASTRONOMICAL_TWILIGHT
+            })
+            .collect();
+        let moon_altitudes = times
+            .iter()
+            .map(|time| moon_horizontal(time, site.latitude, site.longitude).altitude)
+            .collect();
+
+        Sky {
+            moon_illumination: moon_illumination(&times[times.len() / 2]),
+            times,
+            dark,
+            moon_altitudes,
+        }
+    }
+
+    fn windows(&self, mask: &[bool]) -> Vec<TimeWindow> {
+        let mut windows: Vec<TimeWindow> = vec![];
+        let mut open: Option<usize> = None;
+        for (i, usable) in mask.iter().chain(std::iter::once(&false)).enumerate() {
+            match (usable, open) {
+                (true, None) => open = Some(i),
+                (false, Some(start)) => {
+                    windows.push(TimeWindow {
+                        start: self.times[start],
+                        end: self.times[i - 1] + Duration::minutes(STEP_MINUTES),
+                    });
+                    open = None;
+                }
+                _ => {}
+            }
+        }
+        windows
+    }
+}
+
+// twilight, moon-free darkness and the targets worth imaging in the night starting at the date.
+// targets are ranked by their hours in darkness above the minimum altitude, hours with the moon up
+// count less the brighter it is, times how well they fit the field of view
+pub fn plan_night<'a>(
+    site: &ObservingSite,
+    date: NaiveDate,
+    objects: impl IntoIterator<Item = (&'a DeepSkyObject, bool)>,
+    field_of_view: (f64, f64),
+    min_altitude: f64,
+) -> NightPlan {
+    let noon = date.and_hms_opt(12, 0, 0).unwrap().and_utc();
+    let start = noon - Duration::seconds(site_offset(Some(site.longitude), &noon).local_minus_utc() as i64);
+    let sky = Sky::new(site, start);
+    let hours_per_sample = STEP_MINUTES as f64 / 60.0;
+
+    let darkness = sky.windows(&sky.dark);
+    let moon_free: Vec<bool> = sky
+        .dark
+        .iter()
+        .zip(&sky.moon_altitudes)
+        .map(|(dark, moon)| *dark && *moon < 0.0)
+        .collect();
+    let midnight = sky.times[sky.times.len() / 2];
+    let moon = moon_position(&midnight);
+
+    let mut targets: Vec<TargetPlan> = objects
+        .into_iter()
+        .filter_map(|(object, wishlist)| {
+            let position = object.position();
+            let altitudes: Vec<f64> = sky
+                .times
+                .iter()
+                .map(|time| to_horizontal(&position, time, site.latitude, site.longitude).altitude)
+                .collect();
+            let usable: Vec<bool> = altitudes
+                .iter()
+                .zip(&sky.dark)
+                .map(|(altitude, dark)| *dark && *altitude >= min_altitude)
+                .collect();
+            if !usable.contains(&true) {
+                return None;
+            }
+
+            let mut dark_hours = 0.0;
+            let mut moon_free_hours = 0.0;
+            let mut weighted_hours = 0.0;
+            for i in (0..usable.len()).filter(|i| usable[*i]) {
+                dark_hours += hours_per_sample;
+                if moon_free[i] {
+                    moon_free_hours += hours_per_sample;
+                    weighted_hours += hours_per_sample;
+                } else {
+                    weighted_hours += hours_per_sample * (1.0 - sky.moon_illumination);
+                }
+            }
+
+            let max_altitude = altitudes
+                .iter()
+                .zip(&sky.dark)
+                .filter(|(_, dark)| **dark)
+                .map(|(altitude, _)| *altitude)
+                .fold(f64::MIN, f64::max);
+            let transit = altitudes
+                .iter()
+                .zip(&sky.times)
+                .max_by(|a, b| a.0.total_cmp(b.0))
+                .map(|(_, time)| *time)?;
+            let best_window = sky
+                .windows(&usable)
+                .into_iter()
+                .max_by_key(|window| window.end - window.start);
+            let framing = object.framing(field_of_view);
+
+            Some(TargetPlan {
+                object_id: object.id.clone(),
+                name: object.name.clone(),
+                object_type: object.object_type,
+                wishlist,
+                altitudes,
+                max_altitude,
+                transit,
+                dark_hours,
+                moon_free_hours,
+                best_window,
+                moon_separation: separation(&moon, &position),
+                framing,
+                score: weighted_hours * framing,
+            })
+        })
+        .collect();
+    targets.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    NightPlan {
+        date,
+        start,
+        step_minutes: STEP_MINUTES,
+        dusk: darkness.first().map(|window| window.start),
+        dawn: darkness.last().map(|window| window.end),
+        dark_windows: sky.windows(&moon_free),
+        moon_illumination: sky.moon_illumination * 100.0,
+        moon_altitudes: sky.moon_altitudes,
+        targets,
+    }
+}
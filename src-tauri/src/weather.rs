@@ -0,0 +1,496 @@
+use crate::file_store;
+use crate::models::imaging_frames::{ImagingFrameList, LightFrame};
+use crate::models::preferences::Preferences;
+use crate::models::state::AppState;
+use crate::models::weather::{hour_of, HourlyWeather};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder};
+use uuid::Uuid;
+
+// OpenWeather history, up to a week of hours per request
+const HISTORY_URL: &str = "https://history.openweathermap.org/data/2.5/history/city";
+const MAX_REQUEST_HOURS: i64 = 7 * 24;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub trait WeatherProvider {
+    // weather for every hour from the hour of start through the hour of end at the position
+    fn hourly(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+    ) -> Result<Vec<HourlyWeather>, Box<dyn Error>>;
+
+    // whether the results are kept in the weather cache
+    fn cached(&self) -> bool {
+        true
+    }
+}
+
+// the provider set up in the preferences: a local json file of hourly records if one is set,
+// else OpenWeather with the api key. none without either
+pub fn provider(preferences: &Preferences) -> Option<Box<dyn WeatherProvider>> {
+    let weather_file = preferences.weather_file().trim();
+    let api_key = preferences.weather_api_key().trim();
+    if !weather_file.is_empty() {
+        Some(Box::new(FileWeatherProvider {
+            path: PathBuf::from(weather_file),
+        }))
+    } else if !api_key.is_empty() {
+        Some(Box::new(HttpWeatherProvider {
+            api_key: api_key.to_string(),
+            agent: AgentBuilder::new()
+                .timeout_connect(CONNECT_TIMEOUT)
+                .timeout_read(READ_TIMEOUT)
+                .build(),
+        }))
+    } else {
+        None
+    }
+}
+
+// what to fetch for a site and time span, gathered while holding the state lock and fetched without it
+pub struct WeatherRequest {
+    latitude: f64,
+    longitude: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    provider: Box<dyn WeatherProvider>,
+}
+
+impl WeatherRequest {
+    pub fn new(
+        provider: Box<dyn WeatherProvider>,
+        latitude: f64,
+        longitude: f64,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> WeatherRequest {
+        WeatherRequest {
+            latitude,
+            longitude,
+            start,
+            end,
+            provider,
+        }
+    }
+
+    pub fn fetch(&self) -> Result<Vec<HourlyWeather>, Box<dyn Error>> {
+        self.provider.hourly(self.latitude, self.longitude, &self.start, &self.end)
+    }
+
+    pub fn cached(&self) -> bool {
+        self.provider.cached()
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.latitude, self.longitude)
+    }
+
+    pub fn span(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (self.start, self.end)
+    }
+}
+
+// fetches the weather of an imaging session at its site and fills it in, the state is only locked
+// to read the request and to apply the result. returns false if there is no weather to fill in
+pub fn update_session_weather(state: &Mutex<AppState>, session_id: &Uuid) -> Result<bool, Box<dyn Error>> {
+    let request = {
+        let app_state = state.lock().unwrap();
+        let session = app_state
+            .imaging_sessions
+            .get(session_id)
+            .ok_or("Imaging session not found")?;
+        let site = session
+            .site_id
+            .and_then(|id| app_state.sites.get(&id))
+            .ok_or("The imaging session has no observing site.")?;
+        let light_frames: Vec<&LightFrame> = session
+            .light_frame_ids
+            .iter()
+            .filter_map(|id| app_state.imaging_frame_list.light_frames.get(id))
+            .collect();
+
+        let Some(request) = app_state.weather_request(site, &light_frames) else {
+            return Ok(false);
+        };
+        request
+    };
+
+    fill_session_weather(state, session_id, &request)
+}
+
+// fills in the weather of the request from the cache, else from the provider without holding the lock
+fn fill_session_weather(
+    state: &Mutex<AppState>,
+    session_id: &Uuid,
+    request: &WeatherRequest,
+) -> Result<bool, Box<dyn Error>> {
+    {
+        let mut app_state = state.lock().unwrap();
+        if let Some(hours) = app_state.cached_weather(request) {
+            return apply_session_weather(&mut app_state, session_id, &hours);
+        }
+    }
+
+    let hours = request.fetch()?;
+
+    let mut app_state = state.lock().unwrap();
+    app_state.cache_weather(request, &hours);
+    apply_session_weather(&mut app_state, session_id, &hours)
+}
+
+fn apply_session_weather(
+    app_state: &mut AppState,
+    session_id: &Uuid,
+    hours: &[HourlyWeather],
+) -> Result<bool, Box<dyn Error>> {
+    if hours.is_empty() {
+        return Ok(false);
+    }
+    let root_directory = app_state.preferences.storage.root_directory.clone();
+
+    // the session can be gone after fetching without the lock
+    let light_frame_ids = app_state
+        .imaging_sessions
+        .get(session_id)
+        .ok_or("Imaging session not found")?
+        .light_frame_ids
+        .clone();
+
+    let old_frames = app_state.imaging_frame_list.light_frames.clone();
+    for id in &light_frame_ids {
+        if let Some(light_frame) = app_state.imaging_frame_list.light_frames.get_mut(id) {
+            light_frame.update_weather(hours);
+        }
+    }
+
+    if let Err(err) = ImagingFrameList::save(root_directory, &app_state.imaging_frame_list) {
+        // Revert the change if save fails
+        app_state.imaging_frame_list.light_frames = old_frames;
+        return Err(err);
+    }
+
+    Ok(true)
+}
+
+// OpenWeather history api, one request per week of hours. it has no seeing, the hours leave it empty
+pub struct HttpWeatherProvider {
+    api_key: String,
+    agent: Agent,
+}
+
+#[derive(Deserialize)]
+struct HistoryResponse {
+    list: Vec<HistoryHour>,
+}
+
+#[derive(Deserialize)]
+struct HistoryHour {
+    dt: i64,
+    main: Option<HistoryMain>,
+    wind: Option<HistoryWind>,
+    clouds: Option<HistoryClouds>,
+}
+
+#[derive(Deserialize)]
+struct HistoryMain {
+    temp: Option<f64>,
+    humidity: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct HistoryWind {
+    speed: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct HistoryClouds {
+    all: Option<f64>,
+}
+
+// Magnus formula, the history api has no dew point
+fn dew_point(temperature: f64, humidity: f64) -> Option<f64> {
+    if humidity <= 0.0 {
+        return None;
+    }
+    let gamma = (humidity / 100.0).ln() + 17.625 * temperature / (243.04 + temperature);
+    Some(243.04 * gamma / (17.625 - gamma))
+}
+
+impl WeatherProvider for HttpWeatherProvider {
+    fn hourly(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+    ) -> Result<Vec<HourlyWeather>, Box<dyn Error>> {
+        let (first, last) = (hour_of(start), hour_of(end));
+        let mut hours = vec![];
+        let mut from = first;
+        while from <= last {
+            let to = (from + chrono::Duration::hours(MAX_REQUEST_HOURS - 1)).min(last);
+            let response: HistoryResponse = self
+                .agent
+                .get(HISTORY_URL)
+                .query("lat", &latitude.to_string())
+                .query("lon", &longitude.to_string())
+                .query("type", "hour")
+                .query("start", &from.timestamp().to_string())
+                .query("end", &to.timestamp().to_string())
+                .query("units", "metric")
+                .query("appid", &self.api_key)
+                .call()?
+                .into_json()?;
+
+            hours.extend(response.list.into_iter().filter_map(|data| {
+                let temperature = data.main.as_ref().and_then(|main| main.temp);
+                let humidity = data.main.as_ref().and_then(|main| main.humidity);
+                Some(HourlyWeather {
+                    time: hour_of(&DateTime::from_timestamp(data.dt, 0)?),
+                    temperature,
+                    humidity,
+                    dew_point: temperature.zip(humidity).and_then(|(t, h)| dew_point(t, h)),
+                    wind_speed: data.wind.and_then(|wind| wind.speed),
+                    cloud_cover: data.clouds.and_then(|clouds| clouds.all),
+                    seeing: None,
+                })
+            }));
+            from = to + chrono::Duration::hours(1);
+        }
+        hours.retain(|hour| hour.time >= first && hour.time <= last);
+        Ok(hours)
+    }
+}
+
+// hourly records of one place from a local json file, for working offline and testing. the file
+// can change between fetches, so nothing of it is cached
+pub struct FileWeatherProvider {
+    path: PathBuf,
+}
+
+impl WeatherProvider for FileWeatherProvider {
+    fn hourly(
+        &self,
+        _latitude: f64,
+        _longitude: f64,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+    ) -> Result<Vec<HourlyWeather>, Box<dyn Error>> {
+        let records: Vec<HourlyWeather> = file_store::load(&self.path)?;
+        let (first, last) = (hour_of(start), hour_of(end));
+        Ok(records
+            .into_iter()
+            .map(|record| HourlyWeather {
+                time: hour_of(&record.time),
+                ..record
+            })
+            .filter(|record| record.time >= first && record.time <= last)
+            .collect())
+    }
+
+    fn cached(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::FrameMetadata;
+    use crate::models::catalog::EquipmentCatalog;
+    use crate::models::dso_catalog::{DsoCatalog, TargetAliases, Wishlist};
+    use crate::models::equipment::EquipmentList;
+    use crate::models::imaging_session_list::ImagingSession;
+    use crate::models::weather::WeatherCache;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+    use std::fs;
+
+    const LATITUDE: f64 = 50.0;
+    const LONGITUDE: f64 = 10.0;
+
+    // the file provider with its results kept in the cache, like OpenWeather
+    struct CachedFileProvider(FileWeatherProvider);
+
+    impl WeatherProvider for CachedFileProvider {
+        fn hourly(
+            &self,
+            latitude: f64,
+            longitude: f64,
+            start: &DateTime<Utc>,
+            end: &DateTime<Utc>,
+        ) -> Result<Vec<HourlyWeather>, Box<dyn Error>> {
+            self.0.hourly(latitude, longitude, start, end)
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 10, 20, 0, 0).unwrap()
+    }
+
+    // six hours of 10 °C and 25 % clouds from the start
+    fn hours() -> Vec<HourlyWeather> {
+        (0..6)
+            .map(|hour| HourlyWeather {
+                time: start() + chrono::Duration::hours(hour),
+                temperature: Some(10.0),
+                humidity: Some(80.0),
+                dew_point: None,
+                wind_speed: None,
+                cloud_cover: Some(25.0),
+                seeing: None,
+            })
+            .collect()
+    }
+
+    // a state in a new root directory with one session of two subs in the second hour,
+    // the weather file of the root directory holds hours()
+    fn session_state() -> (Mutex<AppState>, Uuid, PathBuf) {
+        let root_directory = std::env::temp_dir().join(format!("astrolog-weather-{}", Uuid::new_v4()));
+        fs::create_dir_all(&root_directory).unwrap();
+        fs::write(root_directory.join("weather.json"), serde_json::to_string(&hours()).unwrap()).unwrap();
+
+        let frames: Vec<PathBuf> = (0..2).map(|i| root_directory.join(format!("light_{}.fits", i))).collect();
+        let metadata: Vec<FrameMetadata> = (0..2)
+            .map(|i| FrameMetadata {
+                timestamp: Some(start() + chrono::Duration::minutes(65 + 10 * i)),
+                sub_length: Some(300.0),
+                ..FrameMetadata::default()
+            })
+            .collect();
+        let light_frame = LightFrame::from_metadata(&frames, &metadata, &EquipmentList::new()).unwrap();
+        let session = ImagingSession {
+            id: Uuid::new_v4(),
+            folder_dir: root_directory.to_string_lossy().to_string(),
+            light_frame_ids: vec![light_frame.id],
+            flat_frame_id: Uuid::nil(),
+            dark_frame_id: Uuid::nil(),
+            bias_frame_id: Uuid::nil(),
+            site_id: None,
+        };
+
+        let mut preferences = Preferences::new();
+        preferences.storage.root_directory = root_directory.clone();
+        let mut imaging_frame_list = ImagingFrameList::new();
+        imaging_frame_list.light_frames.insert(light_frame.id, light_frame);
+
+        let session_id = session.id;
+        let app_state = AppState {
+            preferences,
+            equipment_list: EquipmentList::new(),
+            equipment_catalog: EquipmentCatalog::bundled(),
+            dso_catalog: DsoCatalog::bundled(),
+            target_aliases: TargetAliases::default(),
+            wishlist: Wishlist::default(),
+            imaging_frame_list,
+            imaging_sessions: HashMap::from([(session_id, session)]),
+            image_list: HashMap::new(),
+            sites: HashMap::new(),
+            projects: HashMap::new(),
+            weather_cache: WeatherCache::default(),
+            close_lock: false,
+            unclassified_frames: vec![],
+            library_watcher: None,
+            ingest_proposals: vec![],
+            ambiguous_matches: vec![],
+            source_watcher: None,
+        };
+        (Mutex::new(app_state), session_id, root_directory)
+    }
+
+    fn request(state: &Mutex<AppState>, session_id: &Uuid, provider: Box<dyn WeatherProvider>) -> WeatherRequest {
+        let app_state = state.lock().unwrap();
+        let light_frame_id = app_state.imaging_sessions[session_id].light_frame_ids[0];
+        let (start, end) = app_state.imaging_frame_list.light_frames[&light_frame_id].time_span().unwrap();
+        WeatherRequest::new(provider, LATITUDE, LONGITUDE, start, end)
+    }
+
+    fn session_light_frame(state: &Mutex<AppState>, session_id: &Uuid) -> LightFrame {
+        let app_state = state.lock().unwrap();
+        let light_frame_id = app_state.imaging_sessions[session_id].light_frame_ids[0];
+        app_state.imaging_frame_list.light_frames[&light_frame_id].clone()
+    }
+
+    #[test]
+    fn fills_weather_from_the_cache() {
+        let (state, session_id, root_directory) = session_state();
+        state.lock().unwrap().weather_cache.insert(LATITUDE, LONGITUDE, &hours());
+
+        // the file is missing, so the weather can only come from the cache
+        let provider = CachedFileProvider(FileWeatherProvider {
+            path: root_directory.join("missing.json"),
+        });
+        let request = request(&state, &session_id, Box::new(provider));
+        assert!(fill_session_weather(&state, &session_id, &request).unwrap());
+
+        let light_frame = session_light_frame(&state, &session_id);
+        assert_eq!(light_frame.outside_temp, 10.0);
+        assert_eq!(light_frame.average_cloud_cover, 25.0);
+        assert_eq!(light_frame.weather.unwrap().hours.len(), 1);
+        fs::remove_dir_all(root_directory).unwrap();
+    }
+
+    #[test]
+    fn fetches_and_caches_weather_missing_from_the_cache() {
+        let (state, session_id, root_directory) = session_state();
+
+        let provider = CachedFileProvider(FileWeatherProvider {
+            path: root_directory.join("weather.json"),
+        });
+        let request = request(&state, &session_id, Box::new(provider));
+        assert!(state.lock().unwrap().cached_weather(&request).is_none());
+        assert!(fill_session_weather(&state, &session_id, &request).unwrap());
+
+        assert_eq!(session_light_frame(&state, &session_id).outside_temp, 10.0);
+        assert_eq!(state.lock().unwrap().cached_weather(&request).unwrap().len(), 1);
+        fs::remove_dir_all(root_directory).unwrap();
+    }
+
+    #[test]
+    fn keeps_weather_of_the_file_out_of_the_cache() {
+        let (state, session_id, root_directory) = session_state();
+
+        let provider = FileWeatherProvider {
+            path: root_directory.join("weather.json"),
+        };
+        let request = request(&state, &session_id, Box::new(provider));
+        assert!(fill_session_weather(&state, &session_id, &request).unwrap());
+
+        assert_eq!(session_light_frame(&state, &session_id).outside_temp, 10.0);
+        assert!(state.lock().unwrap().weather_cache.locations.is_empty());
+        fs::remove_dir_all(root_directory).unwrap();
+    }
+
+    #[test]
+    fn keeps_hand_entered_conditions() {
+        let (state, session_id, root_directory) = session_state();
+        {
+            let mut app_state = state.lock().unwrap();
+            let light_frame_id = app_state.imaging_sessions[&session_id].light_frame_ids[0];
+            let light_frame = app_state.imaging_frame_list.light_frames.get_mut(&light_frame_id).unwrap();
+            light_frame.outside_temp = -3.0;
+            light_frame.entered_conditions.outside_temp = true;
+        }
+
+        let provider = FileWeatherProvider {
+            path: root_directory.join("weather.json"),
+        };
+        let request = request(&state, &session_id, Box::new(provider));
+        assert!(fill_session_weather(&state, &session_id, &request).unwrap());
+
+        let light_frame = session_light_frame(&state, &session_id);
+        assert_eq!(light_frame.outside_temp, -3.0);
+        assert_eq!(light_frame.average_cloud_cover, 25.0);
+        assert_eq!(light_frame.weather.unwrap().temperature, Some(10.0));
+        fs::remove_dir_all(root_directory).unwrap();
+    }
+}
@@ -10,6 +10,8 @@ import SessionsChart from '@/components/statistics/sessionsChart';
 import { EquipmentChart } from '@/components/statistics/equipmentChart';
 import { IntegrationChart } from '@/components/statistics/integrationChart';
 import InfoCard from '@/components/statistics/infoCard';
+import { MaintenanceTimeline } from '@/components/statistics/maintenanceTimeline';
+import { AltitudeQualityChart } from '@/components/statistics/altitudeQualityChart';
 
 export function Analytics() {
   return (
@@ -39,6 +41,8 @@ export function Analytics() {
           <InfoCard className={styles.infoCard} />
           <InfoCard className={styles.infoCard} />
         </div>
+        <AltitudeQualityChart />
+        <MaintenanceTimeline />
       </div>
     </Tab>
   );
@@ -17,14 +17,21 @@ import {
 } from '@/components/ui/resizable';
 import { useModal } from '@/context/modalProvider';
 import EquipmentModal from '@/components/modals/equipment/equipment';
+import CatalogModal from '@/components/modals/catalog/catalog';
 import { EquipmentType } from '@/enums/equipmentType';
 import EquipmentDetails from '@/components/equipmentDetails';
 import EquipmentListView from '@/components/equipmentListView';
+import AmbiguousMatches from '@/components/ambiguousMatches';
+import OpticalTrains from '@/components/opticalTrains';
+import FilterAliases from '@/components/filterAliases';
+import OpticalTrainModal from '@/components/modals/opticalTrain/opticalTrain';
 import { useState } from 'react';
 import { EquipmentItem } from '@/interfaces/equipment';
+import { useAppState } from '@/context/stateProvider';
 
 export default function Equipment() {
   const { openModal } = useModal();
+  const { appState } = useAppState();
 
   const [selectedItem, setSelectedItem] = useState<EquipmentItem | undefined>(
     undefined
@@ -46,6 +53,57 @@ export default function Equipment() {
           >
             Add Equipment Item
           </Button>
+          <Button
+            variant="secondary"
+            className={styles.catalogButton}
+            onClick={() => openModal(<CatalogModal />)}
+          >
+            Add From Catalog
+          </Button>
+          <Button
+            variant="secondary"
+            className={styles.catalogButton}
+            onClick={() => openModal(<OpticalTrainModal />)}
+          >
+            Add Optical Train
+          </Button>
+        </CardContent>
+      </Card>
+      {appState.ambiguous_matches.length > 0 && (
+        <Card className={styles.review}>
+          <CardHeader>
+            <CardTitle>Review Matches</CardTitle>
+            <CardDescription>
+              These header values fit more than one equipment item.
+            </CardDescription>
+          </CardHeader>
+          <CardContent>
+            <AmbiguousMatches />
+          </CardContent>
+        </Card>
+      )}
+      <Card className={styles.review}>
+        <CardHeader>
+          <CardTitle>Optical Trains</CardTitle>
+          <CardDescription>
+            Rigs you reuse across sessions, used by the planner and projects
+            for their field of view.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <OpticalTrains />
+        </CardContent>
+      </Card>
+      <Card className={styles.review}>
+        <CardHeader>
+          <CardTitle>Filter Aliases</CardTitle>
+          <CardDescription>
+            Filter names your capture software writes and the filter type they
+            stand for.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <FilterAliases />
         </CardContent>
       </Card>
       <ResizablePanelGroup className={styles.content} direction="horizontal">
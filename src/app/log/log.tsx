@@ -23,10 +23,15 @@ import {
   ResizablePanelGroup,
 } from '@/components/ui/resizable';
 import { useModal } from '@/context/modalProvider';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
 import SelectImagingFrames from '@/components/modals/selectImagingFrames';
+import SessionTrack from '@/components/sessionTrack';
+import UnclassifiedFrames from '@/components/unclassifiedFrames';
+import IngestProposals from '@/components/ingestProposals';
 
 export default function Log() {
   const { openModal } = useModal();
+  const { appState, setAppState } = useAppState();
 
   const [selectedSessionId, setSelectedSessionId] = useState<UUID | undefined>(
     undefined,
@@ -57,6 +62,24 @@ export default function Log() {
       });
   }
 
+  // links free form target names like "Andromeda" or "ngc224" to their catalog designation
+  function normalizeTargets() {
+    invoke<number>('normalize_targets')
+      .then((changed) => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Updated the targets of ' + changed + ' light frame sets.',
+        });
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
   return (
     <Tab className={styles.page}>
       <Card>
@@ -79,11 +102,42 @@ export default function Log() {
           >
             Add Calibration Frame
           </Button>
+          <Button variant="ghost" onClick={normalizeTargets}>
+            Normalize Targets
+          </Button>
           <Button variant="ghost" onClick={exportCSV}>
             Export CSV
           </Button>
         </CardContent>
       </Card>
+      {appState.ingest_proposals.length > 0 && (
+        <Card className={styles.review}>
+          <CardHeader>
+            <CardTitle>New Captures</CardTitle>
+            <CardDescription>
+              These frames were found in your source directory. Accept light
+              frames to create an imaging session from them.
+            </CardDescription>
+          </CardHeader>
+          <CardContent>
+            <IngestProposals />
+          </CardContent>
+        </Card>
+      )}
+      {appState.unclassified_frames.length > 0 && (
+        <Card className={styles.review}>
+          <CardHeader>
+            <CardTitle>Unclassified Frames</CardTitle>
+            <CardDescription>
+              These frames were added to the library but don&apos;t belong to
+              an imaging session or calibration set yet.
+            </CardDescription>
+          </CardHeader>
+          <CardContent>
+            <UnclassifiedFrames />
+          </CardContent>
+        </Card>
+      )}
       <ResizablePanelGroup className={styles.content} direction="horizontal">
         <ResizablePanel defaultSize={70}>
           <Card className={styles.tableCard}>
@@ -103,6 +157,17 @@ export default function Log() {
               <div className={styles.imagePreview}></div>
             </CardContent>
           </Card>
+          {selectedSessionId && (
+            <Card className={styles.trackCard}>
+              <CardHeader>
+                <CardTitle>Target Altitude</CardTitle>
+                <CardDescription>Altitude and HFR of every sub</CardDescription>
+              </CardHeader>
+              <CardContent>
+                <SessionTrack sessionId={selectedSessionId} />
+              </CardContent>
+            </Card>
+          )}
         </ResizablePanel>
       </ResizablePanelGroup>
     </Tab>
@@ -4,10 +4,14 @@ import styles from './page.module.scss';
 import { Analytics } from './analytics/analytics';
 import Log from './log/log';
 import Gallery from './gallery/gallery';
+import Sites from './sites/sites';
+import Planner from './planner/planner';
+import Projects from './projects/projects';
 import { TopBar } from '@/components/topBar';
 import SideNav from '@/components/sideNav';
 import React, { useCallback, useEffect, useState } from 'react';
 import { AnimatePresence, motion } from 'framer-motion';
+import { FolderKanban, MapPin, Telescope } from 'lucide-react';
 import { AnalyticsSVG, EquipmentSVG, GallerySVG, LogSVG } from '@/public/svgs';
 import Equipment from '@/app/equipment/equipment';
 import License from '@/components/modals/license';
@@ -44,6 +48,24 @@ export default function Home() {
       tooltip: 'Gallery',
       icon: <GallerySVG />,
     },
+    {
+      component: <Projects />,
+      key: 'projects',
+      tooltip: 'Projects',
+      icon: <FolderKanban />,
+    },
+    {
+      component: <Sites />,
+      key: 'sites',
+      tooltip: 'Observing Sites',
+      icon: <MapPin />,
+    },
+    {
+      component: <Planner />,
+      key: 'planner',
+      tooltip: 'Night Planner',
+      icon: <Telescope />,
+    },
     {
       component: <Analytics />,
       key: 'analytics',
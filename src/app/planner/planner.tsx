@@ -0,0 +1,323 @@
+'use client';
+
+import styles from './planner.module.scss';
+import { useState } from 'react';
+import { UUID } from 'crypto';
+import { invoke } from '@tauri-apps/api/core';
+import { CartesianGrid, Line, LineChart, XAxis, YAxis } from 'recharts';
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from '@/components/ui/card';
+import {
+  ChartConfig,
+  ChartContainer,
+  ChartTooltip,
+  ChartTooltipContent,
+} from '@/components/ui/chart';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from '@/components/ui/table';
+import { Tab } from '@/components/ui/custom/tab';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { Switch } from '@/components/ui/switch';
+import { toast } from '@/components/ui/use-toast';
+import { objectTypeNames } from '@/components/modals/linkTarget/linkTarget';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { NightPlan, TimeWindow } from '@/interfaces/planner';
+
+// targets drawn in the altitude chart
+const CHART_TARGETS = 5;
+
+function formatTime(time: string | null): string {
+  return time === null
+    ? '-'
+    : new Date(time).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' });
+}
+
+function formatWindow(window: TimeWindow | null): string {
+  return window === null ? '-' : formatTime(window.start) + ' - ' + formatTime(window.end);
+}
+
+export default function Planner() {
+  const { appState, setAppState } = useAppState();
+
+  const [siteId, setSiteId] = useState<UUID | undefined>(appState.sites[0]?.id);
+  const [opticalTrainId, setOpticalTrainId] = useState<string>('none');
+  const [startDate, setStartDate] = useState<string>(
+    new Date().toISOString().slice(0, 10),
+  );
+  const [nights, setNights] = useState<number>(7);
+  const [minAltitude, setMinAltitude] = useState<number>(30);
+  const [wishlistOnly, setWishlistOnly] = useState<boolean>(false);
+  const [plans, setPlans] = useState<NightPlan[]>([]);
+  const [selectedNight, setSelectedNight] = useState<number>(0);
+
+  function showError(error: unknown) {
+    toast({
+      variant: 'destructive',
+      title: 'Uh oh! Something went wrong.',
+      description: 'Error: ' + error,
+    });
+  }
+
+  function plan() {
+    invoke<NightPlan[]>('plan_nights', {
+      siteId,
+      startDate,
+      nights,
+      opticalTrainId: opticalTrainId === 'none' ? null : opticalTrainId,
+      wishlistOnly,
+      minAltitude,
+    })
+      .then((plans) => {
+        setPlans(plans);
+        setSelectedNight(0);
+      })
+      .catch(showError);
+  }
+
+  function toggleWishlist(objectId: string, wishlist: boolean) {
+    invoke(wishlist ? 'remove_from_wishlist' : 'add_to_wishlist', { objectId })
+      .then(() => {
+        fetchAppState(setAppState);
+        setPlans(
+          plans.map((night) => ({
+            ...night,
+            targets: night.targets.map((target) =>
+              target.object_id === objectId
+                ? { ...target, wishlist: !wishlist }
+                : target,
+            ),
+          })),
+        );
+      })
+      .catch(showError);
+  }
+
+  const night = plans[selectedNight];
+  const chartTargets = night?.targets.slice(0, CHART_TARGETS) ?? [];
+
+  const chartConfig: ChartConfig = {
+    moon: { label: 'Moon', color: 'hsl(var(--muted-foreground))' },
+    ...Object.fromEntries(
+      chartTargets.map((target, i) => [
+        'target' + i,
+        { label: target.object_id, color: 'hsl(var(--chart-' + (i + 1) + '))' },
+      ]),
+    ),
+  };
+
+  // only the night around astronomical darkness is interesting, the whole evening to morning if
+  // it never gets fully dark
+  const hour = 3600000;
+  const chartStart = night?.dusk
+    ? new Date(night.dusk).getTime() - 2 * hour
+    : new Date(night?.start).getTime() + 4 * hour;
+  const chartEnd = night?.dawn
+    ? new Date(night.dawn).getTime() + 2 * hour
+    : new Date(night?.start).getTime() + 20 * hour;
+  const chartData = (night?.moon_altitudes ?? [])
+    .map((moon, i) => ({
+      time: new Date(new Date(night.start).getTime() + i * night.step_minutes * 60000),
+      moon: Math.round(moon),
+      ...Object.fromEntries(
+        chartTargets.map((target, j) => ['target' + j, Math.round(target.altitudes[i])]),
+      ),
+    }))
+    .filter((point) => point.time.getTime() >= chartStart && point.time.getTime() <= chartEnd)
+    .map((point) => ({
+      ...point,
+      time: point.time.toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' }),
+    }));
+
+  return (
+    <Tab>
+      <Card className={styles.card}>
+        <CardHeader>
+          <CardTitle>Night Planner</CardTitle>
+          <CardDescription>
+            Darkness, moon and the best targets for the upcoming nights, computed offline from the
+            catalog.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className={styles.controls}>
+          <div>
+            <Label>Site</Label>
+            <Select value={siteId} onValueChange={(value) => setSiteId(value as UUID)}>
+              <SelectTrigger className={styles.select}>
+                <SelectValue placeholder="Select a site" />
+              </SelectTrigger>
+              <SelectContent>
+                {appState.sites.map((site) => (
+                  <SelectItem key={site.id} value={site.id}>
+                    {site.name}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div>
+            <Label>Optical Train</Label>
+            <Select value={opticalTrainId} onValueChange={setOpticalTrainId}>
+              <SelectTrigger className={styles.select}>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="none">Default field</SelectItem>
+                {appState.equipment_list.optical_train_list.map((train) => (
+                  <SelectItem key={train.id} value={train.id}>
+                    {train.name}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div>
+            <Label>First Night</Label>
+            <Input
+              type="date"
+              value={startDate}
+              onChange={(event) => setStartDate(event.target.value)}
+            />
+          </div>
+          <div>
+            <Label>Nights</Label>
+            <Input
+              type="number"
+              min={1}
+              max={31}
+              className={styles.number}
+              value={nights}
+              onChange={(event) => setNights(Number(event.target.value))}
+            />
+          </div>
+          <div>
+            <Label>Min. Altitude</Label>
+            <Input
+              type="number"
+              min={0}
+              max={90}
+              className={styles.number}
+              value={minAltitude}
+              onChange={(event) => setMinAltitude(Number(event.target.value))}
+            />
+          </div>
+          <div>
+            <Label>Wishlist only</Label>
+            <Switch checked={wishlistOnly} onCheckedChange={setWishlistOnly} />
+          </div>
+          <Button disabled={siteId === undefined} onClick={plan}>
+            Plan
+          </Button>
+        </CardContent>
+      </Card>
+      {night && (
+        <Card>
+          <CardContent>
+            <div className={styles.nights}>
+              {plans.map((plan, i) => (
+                <Button
+                  key={plan.date}
+                  variant={i === selectedNight ? 'default' : 'secondary'}
+                  onClick={() => setSelectedNight(i)}
+                >
+                  {new Date(plan.date).toLocaleDateString()}
+                </Button>
+              ))}
+            </div>
+            <div className={styles.summary}>
+              <div>Dusk: {formatTime(night.dusk)}</div>
+              <div>Dawn: {formatTime(night.dawn)}</div>
+              <div>Moon: {Math.round(night.moon_illumination)}%</div>
+              <div>
+                Moon-free: {night.dark_windows.map(formatWindow).join(', ') || 'None'}
+              </div>
+            </div>
+            <ChartContainer config={chartConfig} className="max-h-[300px] w-full">
+              <LineChart data={chartData}>
+                <CartesianGrid vertical={false} />
+                <XAxis dataKey="time" tickLine={false} axisLine={false} minTickGap={32} />
+                <YAxis domain={[0, 90]} tickLine={false} axisLine={false} />
+                <ChartTooltip content={<ChartTooltipContent />} />
+                <Line
+                  dataKey="moon"
+                  stroke="var(--color-moon)"
+                  strokeDasharray="4 4"
+                  dot={false}
+                />
+                {chartTargets.map((_, i) => (
+                  <Line
+                    key={i}
+                    dataKey={'target' + i}
+                    stroke={'var(--color-target' + i + ')'}
+                    dot={false}
+                  />
+                ))}
+              </LineChart>
+            </ChartContainer>
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Target</TableHead>
+                  <TableHead>Type</TableHead>
+                  <TableHead>Best Window</TableHead>
+                  <TableHead>Dark Hours</TableHead>
+                  <TableHead>Moon-free Hours</TableHead>
+                  <TableHead>Max. Altitude</TableHead>
+                  <TableHead>Transit</TableHead>
+                  <TableHead>Moon Distance</TableHead>
+                  <TableHead>Framing</TableHead>
+                  <TableHead />
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {night.targets.map((target) => (
+                  <TableRow key={target.object_id}>
+                    <TableCell>
+                      {target.object_id}
+                      {target.name && ' (' + target.name + ')'}
+                    </TableCell>
+                    <TableCell>{objectTypeNames[target.object_type]}</TableCell>
+                    <TableCell>{formatWindow(target.best_window)}</TableCell>
+                    <TableCell>{target.dark_hours.toFixed(1)} h</TableCell>
+                    <TableCell>{target.moon_free_hours.toFixed(1)} h</TableCell>
+                    <TableCell>{Math.round(target.max_altitude)}°</TableCell>
+                    <TableCell>{formatTime(target.transit)}</TableCell>
+                    <TableCell>{Math.round(target.moon_separation)}°</TableCell>
+                    <TableCell>{Math.round(target.framing * 100)}%</TableCell>
+                    <TableCell>
+                      <Button
+                        variant="secondary"
+                        onClick={() => toggleWishlist(target.object_id, target.wishlist)}
+                      >
+                        {target.wishlist ? 'Remove from Wishlist' : 'Add to Wishlist'}
+                      </Button>
+                    </TableCell>
+                  </TableRow>
+                ))}
+              </TableBody>
+            </Table>
+          </CardContent>
+        </Card>
+      )}
+    </Tab>
+  );
+}
@@ -0,0 +1,109 @@
+'use client';
+
+import styles from './projects.module.scss';
+import { invoke } from '@tauri-apps/api/core';
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from '@/components/ui/card';
+import { Tab } from '@/components/ui/custom/tab';
+import { Button } from '@/components/ui/button';
+import { Progress } from '@/components/ui/progress';
+import { toast } from '@/components/ui/use-toast';
+import ProjectModal from '@/components/modals/project/project';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { filterTypeNames } from '@/enums/filterType';
+import { ProjectProgress } from '@/interfaces/projects';
+
+function hours(seconds: number): string {
+  return Math.round((seconds / 3600) * 10) / 10 + ' h';
+}
+
+export default function Projects() {
+  const { appState, setAppState } = useAppState();
+  const { openModal } = useModal();
+
+  function deleteProject(progress: ProjectProgress) {
+    invoke('delete_project', { id: progress.project.id })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  function trainName(progress: ProjectProgress): string {
+    return (
+      appState.equipment_list.optical_train_list.find(
+        (train) => train.id === progress.project.optical_train_id,
+      )?.name ?? 'Any optical train'
+    );
+  }
+
+  return (
+    <Tab>
+      <Card className={styles.card}>
+        <CardHeader>
+          <CardTitle>Projects</CardTitle>
+          <CardDescription>
+            Targets you image over many nights, with an integration goal per filter.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <Button variant="secondary" onClick={() => openModal(<ProjectModal />)}>
+            New Project
+          </Button>
+        </CardContent>
+      </Card>
+      {appState.projects.map((progress) => (
+        <Card key={progress.project.id} className={styles.card}>
+          <CardHeader>
+            <CardTitle>{progress.project.name}</CardTitle>
+            <CardDescription>
+              {progress.project.target} · {trainName(progress)} ·{' '}
+              {progress.project.session_ids.length} sessions over {progress.nights} nights
+            </CardDescription>
+          </CardHeader>
+          <CardContent className={styles.goals}>
+            {progress.goals.map((goal) => (
+              <div key={goal.filter_type} className={styles.goal}>
+                <div>{filterTypeNames[goal.filter_type]}</div>
+                <Progress value={goal.percent} />
+                <div>
+                  {hours(goal.integration_time)} / {hours(goal.goal)}
+                </div>
+              </div>
+            ))}
+          </CardContent>
+          <CardFooter className={styles.actions}>
+            <div className="mr-auto text-sm text-muted-foreground">
+              {hours(progress.integration_time)} integrated ·{' '}
+              {progress.nights_remaining === null
+                ? 'No nights toward the goals yet'
+                : progress.nights_remaining === 0
+                  ? 'All goals reached'
+                  : 'About ' + progress.nights_remaining + ' nights remaining'}
+            </div>
+            <Button
+              variant="secondary"
+              onClick={() => openModal(<ProjectModal project={progress.project} />)}
+            >
+              Edit
+            </Button>
+            <Button variant="secondary" onClick={() => deleteProject(progress)}>
+              Delete
+            </Button>
+          </CardFooter>
+        </Card>
+      ))}
+    </Tab>
+  );
+}
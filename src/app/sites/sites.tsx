@@ -0,0 +1,110 @@
+'use client';
+
+import styles from './sites.module.scss';
+import { invoke } from '@tauri-apps/api/core';
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from '@/components/ui/card';
+import { Tab } from '@/components/ui/custom/tab';
+import { Button } from '@/components/ui/button';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from '@/components/ui/table';
+import SiteModal from '@/components/modals/site/site';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { ObservingSite } from '@/interfaces/state';
+
+export default function Sites() {
+  const { appState, setAppState } = useAppState();
+  const { openModal } = useModal();
+
+  function deleteSite(site: ObservingSite) {
+    invoke('delete_site', { id: site.id })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <Tab>
+      <Card className={styles.card}>
+        <CardHeader>
+          <CardTitle>Observing Sites</CardTitle>
+          <CardDescription>
+            Locations you image from. New sessions are linked to the nearest site automatically.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <Button variant="secondary" onClick={() => openModal(<SiteModal />)}>
+            Add Site
+          </Button>
+        </CardContent>
+      </Card>
+      <Card>
+        <CardContent>
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Name</TableHead>
+                <TableHead>Latitude</TableHead>
+                <TableHead>Longitude</TableHead>
+                <TableHead>Bortle</TableHead>
+                <TableHead>SQM</TableHead>
+                <TableHead>Sessions</TableHead>
+                <TableHead>Nights</TableHead>
+                <TableHead>Integration</TableHead>
+                <TableHead />
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {appState.sites.map((site) => {
+                const usage = appState.analytics.sites.find((s) => s.id === site.id);
+                return (
+                  <TableRow key={site.id}>
+                    <TableCell>{site.name}</TableCell>
+                    <TableCell>{site.latitude.toFixed(4)}°</TableCell>
+                    <TableCell>{site.longitude.toFixed(4)}°</TableCell>
+                    <TableCell>{site.bortle ?? '-'}</TableCell>
+                    <TableCell>{site.sqm ?? '-'}</TableCell>
+                    <TableCell>{usage?.sessions ?? 0}</TableCell>
+                    <TableCell>{usage?.nights ?? 0}</TableCell>
+                    <TableCell>
+                      {Math.round(((usage?.integration_time ?? 0) / 3600) * 10) / 10} h
+                    </TableCell>
+                    <TableCell className={styles.actions}>
+                      <Button
+                        variant="secondary"
+                        onClick={() => openModal(<SiteModal site={site} />)}
+                      >
+                        Edit
+                      </Button>
+                      <Button variant="secondary" onClick={() => deleteSite(site)}>
+                        Delete
+                      </Button>
+                    </TableCell>
+                  </TableRow>
+                );
+              })}
+            </TableBody>
+          </Table>
+        </CardContent>
+      </Card>
+    </Tab>
+  );
+}
@@ -0,0 +1,122 @@
+'use client';
+
+import styles from './ambiguousMatches.module.scss';
+import { useState } from 'react';
+import { UUID } from 'crypto';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { AmbiguousMatch } from '@/interfaces/state';
+import { EquipmentItem } from '@/interfaces/equipment';
+import { equipmentListKeys, getViewName } from '@/utils/equipment';
+import { Button } from '@/components/ui/button';
+import { Switch } from '@/components/ui/switch';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue
+} from '@/components/ui/select';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+// one row per header, resolving it settles every light frame set that used it
+export default function AmbiguousMatches() {
+  const { appState, setAppState } = useAppState();
+  const [selected, setSelected] = useState<Record<string, UUID>>({});
+  const [remember, setRemember] = useState<Record<string, boolean>>({});
+
+  const matches = appState.ambiguous_matches.filter(
+    (match, index, all) =>
+      all.findIndex((m) => m.equipment_type === match.equipment_type && m.header === match.header) === index
+  );
+
+  if (matches.length === 0) {
+    return null;
+  }
+
+  function candidateName(match: AmbiguousMatch, id: UUID): string {
+    const list = appState.equipment_list[equipmentListKeys[match.equipment_type]] as EquipmentItem[];
+    const item = list.find((i) => i.id === id);
+    return item ? getViewName(item) : id;
+  }
+
+  function onError(error: unknown) {
+    toast({
+      variant: 'destructive',
+      title: 'Uh oh! Something went wrong.',
+      description: 'Error: ' + error,
+    });
+  }
+
+  function resolve(match: AmbiguousMatch) {
+    invoke('resolve_ambiguous_match', {
+      id: match.id,
+      equipmentId: selected[match.id],
+      remember: remember[match.id] ?? false,
+    })
+      .then(() => fetchAppState(setAppState))
+      .catch(onError);
+  }
+
+  function dismiss(match: AmbiguousMatch) {
+    const ids = appState.ambiguous_matches
+      .filter((m) => m.equipment_type === match.equipment_type && m.header === match.header)
+      .map((m) => m.id);
+    Promise.all(ids.map((id) => invoke('dismiss_ambiguous_match', { id })))
+      .then(() => fetchAppState(setAppState))
+      .catch(onError);
+  }
+
+  return (
+    <Table className={styles.component}>
+      <TableHeader>
+        <TableRow>
+          <TableHead>Header</TableHead>
+          <TableHead>Type</TableHead>
+          <TableHead>Equipment</TableHead>
+          <TableHead>Remember</TableHead>
+          <TableHead />
+        </TableRow>
+      </TableHeader>
+      <TableBody>
+        {matches.map((match) => (
+          <TableRow key={match.id}>
+            <TableCell>{match.header}</TableCell>
+            <TableCell>{match.equipment_type}</TableCell>
+            <TableCell>
+              <Select
+                onValueChange={(value) => setSelected({ ...selected, [match.id]: value as UUID })}
+              >
+                <SelectTrigger>
+                  <SelectValue placeholder="Select equipment" />
+                </SelectTrigger>
+                <SelectContent>
+                  {match.candidates.map((id) => (
+                    <SelectItem key={id} value={id}>
+                      {candidateName(match, id)}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </TableCell>
+            <TableCell>
+              <Switch
+                checked={remember[match.id] ?? false}
+                onCheckedChange={(checked) => setRemember({ ...remember, [match.id]: checked })}
+              />
+            </TableCell>
+            <TableCell className={styles.actions}>
+              <Button disabled={!selected[match.id]} onClick={() => resolve(match)}>
+                Resolve
+              </Button>
+              <Button variant="secondary" onClick={() => dismiss(match)}>
+                Dismiss
+              </Button>
+            </TableCell>
+          </TableRow>
+        ))}
+      </TableBody>
+    </Table>
+  );
+}
@@ -1,23 +1,56 @@
 'use client';
 
 import styles from './equipmentDetails.module.scss';
-import { Camera, EquipmentItem, Filter, Flattener, getEquipmentType, Telescope } from '@/interfaces/equipment';
+import {
+  Camera,
+  DewHeater,
+  EquipmentItem,
+  FieldRotator,
+  Filter,
+  FilterWheel,
+  Flattener,
+  Focuser,
+  GuideCamera,
+  GuideScope,
+  OffAxisGuider,
+  Rotator,
+  Telescope
+} from '@/interfaces/equipment';
+import { useAppState } from '@/context/stateProvider';
+import { filterTypeNames } from '@/enums/filterType';
+import { getEquipmentType, getViewName } from '@/utils/equipment';
 import { ScrollArea } from '@/components/ui/scroll-area';
 import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
 import { EquipmentType } from '@/enums/equipmentType';
+import MaintenanceLog from '@/components/maintenanceLog';
+import MergeEquipment from '@/components/mergeEquipment';
+import OpticsDetails from '@/components/opticsDetails';
+import SensorDetails from '@/components/sensorDetails';
 
 interface EquipmentDetailsProps {
   selectedItem: EquipmentItem | undefined;
 }
 
 export default function EquipmentDetails({ selectedItem }: EquipmentDetailsProps) {
+  const { appState } = useAppState();
+
   if (selectedItem === undefined) {
     return (
       <div>No Item Selected.</div>
     );
   }
 
-  const type: EquipmentType = getEquipmentType(selectedItem);
+  const type: EquipmentType | undefined = getEquipmentType(selectedItem, appState.equipment_list);
+  const usage = appState.analytics.equipment_usage.find((u) => u.id === selectedItem.id);
+
+  function row(label: string, value: string | number | null | undefined, unit = '') {
+    return (
+      <TableRow>
+        <TableCell>{label}</TableCell>
+        <TableCell>{value === null || value === undefined ? 'N/A' : value + unit}</TableCell>
+      </TableRow>
+    );
+  }
 
   return (
     <ScrollArea className={styles.component}>
@@ -37,6 +70,10 @@ export default function EquipmentDetails({ selectedItem }: EquipmentDetailsProps
             <TableCell>Name</TableCell>
             <TableCell>{selectedItem.name}</TableCell>
           </TableRow>
+          {row('Header Aliases', selectedItem.aliases?.map((alias) => alias.pattern).join(', ') || null)}
+          {row('Purchase Date', selectedItem.lifecycle?.purchase_date)}
+          {row('Price', selectedItem.lifecycle?.price)}
+          {row('Serial Number', selectedItem.lifecycle?.serial_number || null)}
 
           {/* Additional Rows Based on Type */}
           {type === EquipmentType.TELESCOPE && (
@@ -65,13 +102,37 @@ export default function EquipmentDetails({ selectedItem }: EquipmentDetailsProps
                 <TableCell>RGB</TableCell>
                 <TableCell>{(selectedItem as Camera).rgb ? "Yes" : "No"}</TableCell>
               </TableRow>
+              {(selectedItem as Camera).sensor?.pixel_size && (
+                <TableRow>
+                  <TableCell>Pixel Size</TableCell>
+                  <TableCell>{(selectedItem as Camera).sensor.pixel_size?.toFixed(2)} µm</TableCell>
+                </TableRow>
+              )}
+              {(selectedItem as Camera).sensor?.resolution && (
+                <TableRow>
+                  <TableCell>Resolution</TableCell>
+                  <TableCell>{(selectedItem as Camera).sensor.resolution?.join(' x ')} px</TableCell>
+                </TableRow>
+              )}
+              <TableRow>
+                <TableCell>Cooled</TableCell>
+                <TableCell>{(selectedItem as Camera).sensor?.cooled ? "Yes" : "No"}</TableCell>
+              </TableRow>
             </>
           )}
           {type === EquipmentType.FILTER && (
-            <TableRow>
-              <TableCell>Filter Type</TableCell>
-              <TableCell>{(selectedItem as Filter).filter_type}</TableCell>
-            </TableRow>
+            <>
+              <TableRow>
+                <TableCell>Filter Type</TableCell>
+                <TableCell>
+                  {filterTypeNames[(selectedItem as Filter).filter_type]}
+                  {(selectedItem as Filter).filter_type_label && ` (${(selectedItem as Filter).filter_type_label})`}
+                </TableCell>
+              </TableRow>
+              {row('Central Wavelength', (selectedItem as Filter).central_wavelength, ' nm')}
+              {row('Bandwidth', (selectedItem as Filter).bandwidth, ' nm')}
+              {row('Size', (selectedItem as Filter).size)}
+            </>
           )}
           {type === EquipmentType.FLATTENER && (
             <TableRow>
@@ -79,8 +140,55 @@ export default function EquipmentDetails({ selectedItem }: EquipmentDetailsProps
               <TableCell>{(selectedItem as Flattener).factor}</TableCell>
             </TableRow>
           )}
+          {type === EquipmentType.FILTERWHEEL &&
+            (selectedItem as FilterWheel).slots.map((slot) => {
+              const filter = appState.equipment_list.filter_list.find((f) => f.id === slot.filter_id);
+              return (
+                <TableRow key={slot.position}>
+                  <TableCell>Position {slot.position}</TableCell>
+                  <TableCell>{filter ? getViewName(filter) : 'N/A'}</TableCell>
+                </TableRow>
+              );
+            })}
+          {type === EquipmentType.FOCUSER && (
+            <>
+              {row('Max Position', (selectedItem as Focuser).max_position, ' steps')}
+              {row('Step Size', (selectedItem as Focuser).step_size, ' µm')}
+            </>
+          )}
+          {type === EquipmentType.GUIDESCOPE && (
+            <>
+              {row('Focal Length', (selectedItem as GuideScope).focal_length, ' mm')}
+              {row('Aperture', (selectedItem as GuideScope).aperture, ' mm')}
+            </>
+          )}
+          {type === EquipmentType.GUIDECAMERA && row('Pixel Size', (selectedItem as GuideCamera).pixel_size, ' µm')}
+          {type === EquipmentType.OFFAXISGUIDER && row('Prism Size', (selectedItem as OffAxisGuider).prism_size, ' mm')}
+          {type === EquipmentType.ROTATOR && row('Step Size', (selectedItem as Rotator).step_size, '°')}
+          {type === EquipmentType.DEWHEATER && row('Channels', (selectedItem as DewHeater).channels)}
+          {type === EquipmentType.FIELDROTATOR && row('Max Rotation', (selectedItem as FieldRotator).max_rotation, '°')}
+          {usage && (
+            <>
+              {row('Nights', usage.nights)}
+              {row('Integration', (usage.integration_time / 3600).toFixed(1), ' h')}
+              {row('Targets', usage.targets.join(', ') || null)}
+              {row('First Use', usage.first_use)}
+              {row('Last Use', usage.last_use)}
+              {row('Average HFR', usage.average_hfr?.toFixed(2))}
+              {row('Average Stars', usage.average_star_count?.toFixed(0))}
+              {row('Accepted Subs', usage.acceptance_rate === null ? null : (usage.acceptance_rate * 100).toFixed(0), ' %')}
+            </>
+          )}
         </TableBody>
       </Table>
+      {type === EquipmentType.TELESCOPE && <OpticsDetails telescope={selectedItem as Telescope} />}
+      {type === EquipmentType.CAMERA && <SensorDetails camera={selectedItem as Camera} />}
+      {type !== undefined && (
+        <>
+          <MaintenanceLog item={selectedItem} type={type} />
+          <MergeEquipment item={selectedItem} type={type} />
+        </>
+      )}
     </ScrollArea>
   );
 }
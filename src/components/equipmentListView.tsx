@@ -31,7 +31,15 @@ export default function EquipmentListView({ selectedItem, setSelectedItem }: Equ
     { name: 'Cameras', type: appState.equipment_list.camera_list },
     { name: 'Mounts', type: appState.equipment_list.mount_list },
     { name: 'Filters', type: appState.equipment_list.filter_list },
-    { name: 'Flatteners', type: appState.equipment_list.flattener_list }
+    { name: 'Flatteners', type: appState.equipment_list.flattener_list },
+    { name: 'Filter Wheels', type: appState.equipment_list.filter_wheel_list },
+    { name: 'Focusers', type: appState.equipment_list.focuser_list },
+    { name: 'Guide Scopes', type: appState.equipment_list.guide_scope_list },
+    { name: 'Guide Cameras', type: appState.equipment_list.guide_camera_list },
+    { name: 'Off-Axis Guiders', type: appState.equipment_list.off_axis_guider_list },
+    { name: 'Rotators', type: appState.equipment_list.rotator_list },
+    { name: 'Dew Heaters', type: appState.equipment_list.dew_heater_list },
+    { name: 'Field Rotators', type: appState.equipment_list.field_rotator_list }
   ];
 
   const [openItems, setOpenItems] = useState<string[]>(
@@ -0,0 +1,113 @@
+'use client';
+
+import styles from './filterAliases.module.scss';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { FilterAlias } from '@/interfaces/equipment';
+import { FilterType, filterTypeNames } from '@/enums/filterType';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue
+} from '@/components/ui/select';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+// header values of the capture software and the filter type they stand for, checked before the built-in names
+export default function FilterAliases() {
+  const { appState, setAppState } = useAppState();
+  const [aliases, setAliases] = useState<FilterAlias[]>(appState.equipment_list.filter_aliases);
+
+  useEffect(() => {
+    setAliases(appState.equipment_list.filter_aliases);
+  }, [appState.equipment_list.filter_aliases]);
+
+  function update(index: number, alias: FilterAlias) {
+    setAliases(aliases.map((a, i) => (i === index ? alias : a)));
+  }
+
+  function save() {
+    invoke('save_filter_aliases', {
+      aliases: aliases.filter((alias) => alias.alias.trim() !== ''),
+    })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Saved the filter aliases.',
+        });
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <div className={styles.component}>
+      <Table>
+        <TableHeader>
+          <TableRow>
+            <TableHead>Header Value</TableHead>
+            <TableHead>Filter Type</TableHead>
+            <TableHead />
+          </TableRow>
+        </TableHeader>
+        <TableBody>
+          {aliases.map((alias, index) => (
+            <TableRow key={index}>
+              <TableCell>
+                <Input
+                  placeholder="Ha 7nm"
+                  value={alias.alias}
+                  onChange={(e) => update(index, { ...alias, alias: e.target.value })}
+                />
+              </TableCell>
+              <TableCell>
+                <Select
+                  value={alias.filter_type}
+                  onValueChange={(value) => update(index, { ...alias, filter_type: value as FilterType })}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {Object.values(FilterType).map((type) => (
+                      <SelectItem key={type} value={type}>
+                        {filterTypeNames[type]}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              </TableCell>
+              <TableCell>
+                <Button
+                  variant="secondary"
+                  onClick={() => setAliases(aliases.filter((_, i) => i !== index))}
+                >
+                  Remove
+                </Button>
+              </TableCell>
+            </TableRow>
+          ))}
+        </TableBody>
+      </Table>
+      <div className={styles.actions}>
+        <Button
+          variant="secondary"
+          onClick={() => setAliases([...aliases, { alias: '', filter_type: FilterType.L }])}
+        >
+          Add Alias
+        </Button>
+        <Button onClick={save}>Save</Button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,74 @@
+'use client';
+
+import styles from './ingestProposals.module.scss';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { IngestGroup } from '@/interfaces/state';
+import { Button } from '@/components/ui/button';
+import { toast } from '@/components/ui/use-toast';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+// captures picked up in the source directory, lights become a new imaging session when accepted
+export default function IngestProposals() {
+  const { appState, setAppState } = useAppState();
+
+  if (appState.ingest_proposals.length === 0) {
+    return null;
+  }
+
+  function onError(error: unknown) {
+    toast({
+      variant: 'destructive',
+      title: 'Uh oh! Something went wrong.',
+      description: 'Error: ' + error,
+    });
+  }
+
+  function accept(group: IngestGroup) {
+    invoke('accept_ingest_proposal', { id: group.id })
+      .then(() => fetchAppState(setAppState))
+      .catch(onError);
+  }
+
+  function dismiss(group: IngestGroup) {
+    invoke('dismiss_ingest_proposal', { id: group.id })
+      .then(() => fetchAppState(setAppState))
+      .catch(onError);
+  }
+
+  return (
+    <Table className={styles.component}>
+      <TableHeader>
+        <TableRow>
+          <TableHead>Type</TableHead>
+          <TableHead>Folder</TableHead>
+          <TableHead>Date</TableHead>
+          <TableHead>Frames</TableHead>
+          <TableHead>Gain</TableHead>
+          <TableHead>Sub Length</TableHead>
+          <TableHead />
+        </TableRow>
+      </TableHeader>
+      <TableBody>
+        {appState.ingest_proposals.map((group) => (
+          <TableRow key={group.id}>
+            <TableCell>{group.frame_type}</TableCell>
+            <TableCell className={styles.folder}>{group.folder}</TableCell>
+            <TableCell>{group.date ?? ''}</TableCell>
+            <TableCell>{group.frames.length}</TableCell>
+            <TableCell>{group.gain ?? ''}</TableCell>
+            <TableCell>{group.sub_length !== null ? group.sub_length + 's' : ''}</TableCell>
+            <TableCell className={styles.actions}>
+              <Button disabled={group.frame_type !== 'LIGHT'} onClick={() => accept(group)}>
+                Accept
+              </Button>
+              <Button variant="secondary" onClick={() => dismiss(group)}>
+                Dismiss
+              </Button>
+            </TableCell>
+          </TableRow>
+        ))}
+      </TableBody>
+    </Table>
+  );
+}
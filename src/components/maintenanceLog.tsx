@@ -0,0 +1,160 @@
+'use client';
+
+import styles from './maintenanceLog.module.scss';
+import { useState } from 'react';
+import { v4 as uuidv4 } from 'uuid';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { EquipmentType } from '@/enums/equipmentType';
+import {
+  EquipmentItem,
+  EquipmentStatus,
+  MaintenanceEntry,
+  MaintenanceKind
+} from '@/interfaces/equipment';
+import { equipmentListKeys } from '@/utils/equipment';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue
+} from '@/components/ui/select';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+const statusNames: Record<EquipmentStatus, string> = {
+  ACTIVE: 'Active',
+  RETIRED: 'Retired',
+  SOLD: 'Sold',
+};
+
+export const maintenanceKindNames: Record<MaintenanceKind, string> = {
+  COLLIMATION: 'Collimation',
+  CLEANING: 'Cleaning',
+  FIRMWARE: 'Firmware Update',
+  TILT: 'Tilt Adjustment',
+  OTHER: 'Other',
+};
+
+interface MaintenanceLogProps {
+  item: EquipmentItem;
+  type: EquipmentType;
+}
+
+export default function MaintenanceLog({ item, type }: MaintenanceLogProps) {
+  const { appState, setAppState } = useAppState();
+  const [date, setDate] = useState('');
+  const [kind, setKind] = useState<MaintenanceKind>('CLEANING');
+  const [notes, setNotes] = useState('');
+
+  // the selected item is a copy, the list has the latest lifecycle
+  const list = appState.equipment_list[equipmentListKeys[type]] as EquipmentItem[];
+  const lifecycle = (list.find((i) => i.id === item.id) ?? item).lifecycle;
+  const status = lifecycle?.status ?? 'ACTIVE';
+  const entries = lifecycle?.maintenance ?? [];
+
+  function run(command: string, args: Record<string, unknown>) {
+    invoke(command, { equipmentType: type, ...args })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  function setStatus(value: EquipmentStatus) {
+    run('set_equipment_status', {
+      id: item.id,
+      status: value,
+      date: value === 'ACTIVE' ? null : new Date().toISOString().slice(0, 10),
+    });
+  }
+
+  function addEntry() {
+    const entry: MaintenanceEntry = { id: uuidv4(), date, kind, notes };
+    run('save_maintenance_entry', { equipmentId: item.id, entry });
+    setDate('');
+    setNotes('');
+  }
+
+  return (
+    <div className={styles.component}>
+      <div className={styles.status}>
+        <span>Status</span>
+        <Select value={status} onValueChange={(value) => setStatus(value as EquipmentStatus)}>
+          <SelectTrigger>
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {Object.entries(statusNames).map(([value, name]) => (
+              <SelectItem key={value} value={value}>
+                {name}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+        {lifecycle?.status_date && <span>since {lifecycle.status_date}</span>}
+      </div>
+      <Table>
+        <TableHeader>
+          <TableRow>
+            <TableHead>Date</TableHead>
+            <TableHead>Maintenance</TableHead>
+            <TableHead>Notes</TableHead>
+            <TableHead />
+          </TableRow>
+        </TableHeader>
+        <TableBody>
+          {entries.map((entry) => (
+            <TableRow key={entry.id}>
+              <TableCell>{entry.date}</TableCell>
+              <TableCell>{maintenanceKindNames[entry.kind]}</TableCell>
+              <TableCell>{entry.notes}</TableCell>
+              <TableCell>
+                <Button
+                  variant="secondary"
+                  onClick={() => run('delete_maintenance_entry', { equipmentId: item.id, entryId: entry.id })}
+                >
+                  Delete
+                </Button>
+              </TableCell>
+            </TableRow>
+          ))}
+          <TableRow>
+            <TableCell>
+              <Input type="date" value={date} onChange={(e) => setDate(e.target.value)} />
+            </TableCell>
+            <TableCell>
+              <Select value={kind} onValueChange={(value) => setKind(value as MaintenanceKind)}>
+                <SelectTrigger>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {Object.entries(maintenanceKindNames).map(([value, name]) => (
+                    <SelectItem key={value} value={value}>
+                      {name}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </TableCell>
+            <TableCell>
+              <Input placeholder="Notes" value={notes} onChange={(e) => setNotes(e.target.value)} />
+            </TableCell>
+            <TableCell>
+              <Button disabled={!date} onClick={addEntry}>
+                Add
+              </Button>
+            </TableCell>
+          </TableRow>
+        </TableBody>
+      </Table>
+    </div>
+  );
+}
@@ -0,0 +1,76 @@
+'use client';
+
+import styles from './mergeEquipment.module.scss';
+import { useState } from 'react';
+import { UUID } from 'crypto';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { EquipmentType } from '@/enums/equipmentType';
+import { EquipmentItem } from '@/interfaces/equipment';
+import { equipmentListKeys, getViewName } from '@/utils/equipment';
+import { Button } from '@/components/ui/button';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue
+} from '@/components/ui/select';
+
+interface MergeEquipmentProps {
+  item: EquipmentItem;
+  type: EquipmentType;
+}
+
+// folds a duplicate of the same type into this item, its frames then point here
+export default function MergeEquipment({ item, type }: MergeEquipmentProps) {
+  const { appState, setAppState } = useAppState();
+  const [duplicateId, setDuplicateId] = useState<UUID>();
+
+  const others = (appState.equipment_list[equipmentListKeys[type]] as EquipmentItem[])
+    .filter((i) => i.id !== item.id)
+    .sort((a, b) => getViewName(a).localeCompare(getViewName(b)));
+
+  if (others.length === 0) {
+    return null;
+  }
+
+  function merge() {
+    invoke('merge_equipment_items', { equipmentType: type, keepId: item.id, duplicateId })
+      .then(() => {
+        setDuplicateId(undefined);
+        fetchAppState(setAppState);
+        toast({
+          description: 'Merged into ' + getViewName(item) + ' successfully!',
+        });
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <div className={styles.component}>
+      <Select value={duplicateId ?? ''} onValueChange={(value) => setDuplicateId(value as UUID)}>
+        <SelectTrigger>
+          <SelectValue placeholder="Select duplicate" />
+        </SelectTrigger>
+        <SelectContent>
+          {others.map((other) => (
+            <SelectItem key={other.id} value={other.id}>
+              {getViewName(other)}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+      <Button variant="secondary" disabled={!duplicateId} onClick={merge}>
+        Merge Into This
+      </Button>
+    </div>
+  );
+}
@@ -0,0 +1,169 @@
+'use client';
+
+import styles from './catalog.module.scss';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { open } from '@tauri-apps/plugin-dialog';
+import { Modal } from '@/components/ui/custom/modal';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { ScrollArea } from '@/components/ui/scroll-area';
+import { toast } from '@/components/ui/use-toast';
+import { Table, TableBody, TableCell, TableRow } from '@/components/ui/table';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { CatalogEntry } from '@/interfaces/equipment';
+
+type Specs = Record<string, unknown>;
+
+// editable spec values as dotted paths, e.g. "sensor.pixel_size"
+function flatten(specs: Specs, prefix = ''): [string, string | number][] {
+  return Object.entries(specs).flatMap(([key, value]) => {
+    const path = prefix + key;
+    if (typeof value === 'number' || typeof value === 'string') {
+      return [[path, value] as [string, string | number]];
+    }
+    if (value && typeof value === 'object' && !Array.isArray(value)) {
+      return flatten(value as Specs, path + '.');
+    }
+    return [];
+  });
+}
+
+function toOverrides(values: Record<string, string | number>): Specs {
+  const overrides: Specs = {};
+  Object.entries(values).forEach(([path, value]) => {
+    const keys = path.split('.');
+    let target = overrides;
+    keys.slice(0, -1).forEach((key) => {
+      target[key] = (target[key] as Specs) ?? {};
+      target = target[key] as Specs;
+    });
+    target[keys[keys.length - 1]] = value;
+  });
+  return overrides;
+}
+
+function onError(error: unknown) {
+  toast({
+    variant: 'destructive',
+    title: 'Uh oh! Something went wrong.',
+    description: 'Error: ' + error,
+  });
+}
+
+export default function CatalogModal() {
+  const { setAppState } = useAppState();
+  const { closeModal } = useModal();
+
+  const [query, setQuery] = useState('');
+  const [entries, setEntries] = useState<CatalogEntry[]>([]);
+  const [selected, setSelected] = useState<CatalogEntry>();
+  const [overrides, setOverrides] = useState<Record<string, string | number>>({});
+  const [version, setVersion] = useState<number>();
+
+  useEffect(() => {
+    invoke<CatalogEntry[]>('search_equipment_catalog', { query })
+      .then(setEntries)
+      .catch(onError);
+  }, [query, version]);
+
+  function select(entry: CatalogEntry) {
+    setSelected(entry);
+    setOverrides({});
+  }
+
+  function add() {
+    if (!selected) {
+      return;
+    }
+    invoke('add_catalog_item', { key: selected.key, overrides: toOverrides(overrides) })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Added ' + selected.brand + ' ' + selected.name + ' successfully!',
+        });
+        closeModal();
+      })
+      .catch(onError);
+  }
+
+  function importCatalog() {
+    open({ multiple: false, filters: [{ name: 'Equipment Catalog', extensions: ['json'] }] })
+      .then((path) => {
+        if (!path) {
+          return;
+        }
+        invoke<number>('import_equipment_catalog', { path })
+          .then((version) => {
+            toast({
+              description: 'Updated the equipment catalog to version ' + version + '.',
+            });
+            setVersion(version);
+          })
+          .catch(onError);
+      })
+      .catch(onError);
+  }
+
+  return (
+    <Modal
+      title="Add From Catalog"
+      subtitle="Search common cameras, telescopes, reducers and filters."
+      separator
+      className={styles.modal}
+    >
+      <div className={styles.content}>
+        <Input
+          placeholder="Search catalog"
+          value={query}
+          onChange={(e) => setQuery(e.target.value)}
+        />
+        <ScrollArea className={styles.results}>
+          <Table>
+            <TableBody>
+              {entries.map((entry) => (
+                <TableRow
+                  key={entry.key}
+                  className={entry.key === selected?.key ? styles.selected : undefined}
+                  onClick={() => select(entry)}
+                >
+                  <TableCell>{entry.brand} {entry.name}</TableCell>
+                  <TableCell>{entry.equipment_type}</TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </ScrollArea>
+        {selected && (
+          <>
+            {flatten({ name: selected.name, ...selected.specs }).map(([path, value]) => (
+              <div key={path}>
+                <Label>{path}</Label>
+                <Input
+                  type={typeof value === 'number' ? 'number' : 'text'}
+                  value={overrides[path] ?? value}
+                  onChange={(e) =>
+                    setOverrides({
+                      ...overrides,
+                      [path]: typeof value === 'number' ? parseFloat(e.target.value) : e.target.value,
+                    })
+                  }
+                />
+              </div>
+            ))}
+          </>
+        )}
+        <div className={styles.actions}>
+          <Button variant="secondary" onClick={importCatalog}>
+            Import Catalog
+          </Button>
+          <Button disabled={!selected} onClick={add}>
+            Add {selected ? selected.equipment_type : 'Item'}
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
@@ -3,6 +3,7 @@
 import styles from './equipment.module.scss';
 import { Modal } from '@/components/ui/custom/modal';
 import { EquipmentType } from '@/enums/equipmentType';
+import { FilterType, filterTypeNames } from '@/enums/filterType';
 import { Button } from '@/components/ui/button';
 import {
   Form,
@@ -25,18 +26,31 @@ import { useForm } from 'react-hook-form';
 import * as z from 'zod';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { Input } from '@/components/ui/input';
-import { EquipmentItem } from '@/interfaces/equipment';
+import {
+  DuplicateCandidate,
+  EquipmentItem,
+  FilterSlot,
+  HeaderAlias,
+  Lifecycle,
+  Sensor
+} from '@/interfaces/equipment';
+import { useState } from 'react';
+import { UUID } from 'crypto';
 import { v4 as uuidv4 } from 'uuid';
 import { invoke } from '@tauri-apps/api/core';
 import { Switch } from '@/components/ui/switch';
 import { toast } from '@/components/ui/use-toast';
-import { getViewName } from '@/utils/equipment';
+import { equipmentListKeys, getViewName, isActive } from '@/utils/equipment';
 import { useAppState } from '@/context/stateProvider';
 import { useModal } from '@/context/modalProvider';
 
 const baseEquipmentSchema = z.object({
   brand: z.string().min(1, 'Brand is required'),
-  name: z.string().min(1, 'Name is required')
+  name: z.string().min(1, 'Name is required'),
+  aliases: z.string().optional(),
+  purchase_date: z.string().optional(),
+  price: z.number().nonnegative('Price must not be negative').optional(),
+  serial_number: z.string().optional()
 });
 
 export const telescopeSchema = baseEquipmentSchema.extend({
@@ -45,38 +59,147 @@ export const telescopeSchema = baseEquipmentSchema.extend({
 });
 
 export const cameraSchema = baseEquipmentSchema.extend({
-  chip_size: z.string().min(1, 'Chip size is required'),
+  chip_size: z.string(),
   mega_pixel: z.number().positive('Mega pixel must be a positive number'),
-  rgb: z.boolean()
+  rgb: z.boolean(),
+  pixel_size: z.number().positive('Pixel size must be a positive number').optional(),
+  resolution_x: z.number().int().positive('Resolution must be a positive number').optional(),
+  resolution_y: z.number().int().positive('Resolution must be a positive number').optional(),
+  bit_depth: z.number().int().positive('Bit depth must be a positive number').optional(),
+  cooled: z.boolean()
 });
 
 export const mountSchema = baseEquipmentSchema;
 
 export const filterSchema = baseEquipmentSchema.extend({
-  filter_type: z.string().min(1, 'Filter type is required')
+  filter_type: z.nativeEnum(FilterType, { required_error: 'Filter type is required' }),
+  central_wavelength: z.number().positive('Central wavelength must be a positive number').optional(),
+  bandwidth: z.number().positive('Bandwidth must be a positive number').optional(),
+  size: z.string().optional()
 });
 
 export const flattenerSchema = baseEquipmentSchema.extend({
   factor: z.number().positive('Factor must be a positive number')
 });
 
+export const filterWheelSchema = baseEquipmentSchema.extend({
+  positions: z.number().int().positive('Positions must be a positive number'),
+  slot_filters: z.array(z.string())
+});
+
+export const focuserSchema = baseEquipmentSchema.extend({
+  max_position: z.number().int().positive('Max position must be a positive number').optional(),
+  step_size: z.number().positive('Step size must be a positive number').optional()
+});
+
+export const guideScopeSchema = telescopeSchema;
+
+export const guideCameraSchema = baseEquipmentSchema.extend({
+  pixel_size: z.number().positive('Pixel size must be a positive number').optional()
+});
+
+export const offAxisGuiderSchema = baseEquipmentSchema.extend({
+  prism_size: z.number().positive('Prism size must be a positive number').optional()
+});
+
+export const rotatorSchema = baseEquipmentSchema.extend({
+  step_size: z.number().positive('Step size must be a positive number').optional()
+});
+
+export const dewHeaterSchema = baseEquipmentSchema.extend({
+  channels: z.number().int().positive('Channels must be a positive number').optional()
+});
+
+export const fieldRotatorSchema = baseEquipmentSchema.extend({
+  max_rotation: z.number().positive('Max rotation must be a positive number').optional()
+});
+
 export const equipmentSchema = z.discriminatedUnion('type', [
   z.object({ type: z.literal(EquipmentType.TELESCOPE), ...telescopeSchema.shape }),
   z.object({ type: z.literal(EquipmentType.CAMERA), ...cameraSchema.shape }),
   z.object({ type: z.literal(EquipmentType.MOUNT), ...mountSchema.shape }),
   z.object({ type: z.literal(EquipmentType.FILTER), ...filterSchema.shape }),
-  z.object({ type: z.literal(EquipmentType.FLATTENER), ...flattenerSchema.shape })
+  z.object({ type: z.literal(EquipmentType.FLATTENER), ...flattenerSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.FILTERWHEEL), ...filterWheelSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.FOCUSER), ...focuserSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.GUIDESCOPE), ...guideScopeSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.GUIDECAMERA), ...guideCameraSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.OFFAXISGUIDER), ...offAxisGuiderSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.ROTATOR), ...rotatorSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.DEWHEATER), ...dewHeaterSchema.shape }),
+  z.object({ type: z.literal(EquipmentType.FIELDROTATOR), ...fieldRotatorSchema.shape })
 ]);
 
 export type EquipmentFormValues = z.infer<typeof equipmentSchema>
 
+// the form keeps the sensor fields flat, the backend expects them nested
+function toCamera(values: Extract<EquipmentFormValues, { type: EquipmentType.CAMERA }>) {
+  const { pixel_size, resolution_x, resolution_y, bit_depth, cooled, ...camera } = values;
+  const sensor: Sensor = {
+    pixel_size: pixel_size ?? null,
+    resolution: resolution_x && resolution_y ? [resolution_x, resolution_y] : null,
+    size: null,
+    bayer_pattern: null,
+    bit_depth: bit_depth ?? null,
+    cooled,
+    full_well: [],
+    read_noise: [],
+  };
+  return { ...camera, sensor };
+}
+
+// positions without a filter are left out
+function toFilterWheel(values: Extract<EquipmentFormValues, { type: EquipmentType.FILTERWHEEL }>) {
+  const { positions, slot_filters, ...filterWheel } = values;
+  const slots: FilterSlot[] = slot_filters
+    .slice(0, positions)
+    .map((filterId, index) => ({ position: index + 1, filter_id: filterId as UUID }))
+    .filter((slot) => slot.filter_id);
+  return { ...filterWheel, slots };
+}
+
+// comma separated header values, matched exactly
+function toAliases(aliases: string | undefined): HeaderAlias[] {
+  return (aliases ?? '')
+    .split(',')
+    .map((alias) => alias.trim())
+    .filter((alias) => alias)
+    .map((pattern) => ({ pattern, rule: 'EXACT' }));
+}
+
+function toLifecycle(values: EquipmentFormValues): Lifecycle {
+  return {
+    purchase_date: values.purchase_date || null,
+    price: values.price ?? null,
+    serial_number: values.serial_number ?? '',
+    status: 'ACTIVE',
+    status_date: null,
+    maintenance: [],
+  };
+}
+
+function toItem(values: EquipmentFormValues) {
+  const aliases = toAliases(values.aliases);
+  const lifecycle = toLifecycle(values);
+  switch (values.type) {
+    case EquipmentType.CAMERA:
+      return { ...toCamera(values), aliases, lifecycle };
+    case EquipmentType.FILTERWHEEL:
+      return { ...toFilterWheel(values), aliases, lifecycle };
+    default:
+      return { ...values, aliases, lifecycle };
+  }
+}
+
 interface EquipmentProps {
   type: EquipmentType;
 }
 
 export default function EquipmentModal({ type }: EquipmentProps) {
-  const { setAppState } = useAppState();
+  const { appState, setAppState } = useAppState();
   const { closeModal } = useModal();
+  // similar items found for this name, saving again adds the item anyway
+  const [similar, setSimilar] = useState<{ viewName: string; candidates: DuplicateCandidate[] }>();
 
   const form = useForm<EquipmentFormValues>({
     resolver: zodResolver(equipmentSchema),
@@ -84,53 +207,76 @@ export default function EquipmentModal({ type }: EquipmentProps) {
       type: type,
       brand: '',
       name: '',
+      aliases: '',
+      serial_number: '',
       rgb: false,
+      cooled: false,
+      slot_filters: [],
     }
   });
 
   const equipmentType = form.watch('type');
+  const positions = form.watch('positions') ?? 0;
+
+  function numberField(name: string, label: string, placeholder: string) {
+    return (
+      <FormField
+        control={form.control}
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+        name={name as any}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel>{label}</FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                placeholder={placeholder}
+                {...field}
+                onChange={(e) => field.onChange(e.target.value === '' ? undefined : parseFloat(e.target.value))}
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+    );
+  }
 
   function onSubmit(values: EquipmentFormValues) {
     const item: EquipmentItem = {
       id: uuidv4(),
-      ...values
+      ...toItem(values)
     } as EquipmentItem;
 
-    invoke('check_equipment_duplicate', { viewName: getViewName(item) })
-      .then(() => {
-          switch (equipmentType) {
-            case EquipmentType.TELESCOPE:
-              invoke('save_telescope', { telescope: item })
-                .then(() => {
-                  setAppState(prevState => ({
-                    ...prevState,
-                    equipmentList: {
-                      ...prevState.equipment_list,
-                      telescope_list: [...prevState.equipment_list.telescope_list, item]
-                    }
-                  }));
-                  toast({
-                    description: 'Added Telescope successfully!',
-                  });
-                  closeModal();
-                })
-                .catch((error) => {
-                  toast({
-                    variant: 'destructive',
-                    title: 'Uh oh! Something went wrong.',
-                    description: 'Error: ' + error,
-                  });
-                });
-              break;
-            case EquipmentType.CAMERA:
-              break;
-            case EquipmentType.MOUNT:
-              break;
-            case EquipmentType.FILTER:
-              break;
-            case EquipmentType.FLATTENER:
-              break;
+    const viewName = getViewName(item);
+    invoke<DuplicateCandidate[]>('check_equipment_duplicate', { equipmentType, viewName })
+      .then((candidates) => {
+          if (candidates.length > 0 && similar?.viewName !== viewName) {
+            setSimilar({ viewName, candidates });
+            return;
           }
+          invoke('add_equipment_item', { equipmentType, item })
+            .then(() => {
+              const listKey = equipmentListKeys[equipmentType];
+              setAppState((prevState) => ({
+                ...prevState,
+                equipment_list: {
+                  ...prevState.equipment_list,
+                  [listKey]: [...prevState.equipment_list[listKey], item],
+                },
+              }));
+              toast({
+                description: 'Added ' + equipmentType + ' successfully!',
+              });
+              closeModal();
+            })
+            .catch((error) => {
+              toast({
+                variant: 'destructive',
+                title: 'Uh oh! Something went wrong.',
+                description: 'Error: ' + error,
+              });
+            });
         }
       )
       .catch((error) => {
@@ -201,6 +347,19 @@ export default function EquipmentModal({ type }: EquipmentProps) {
               </FormItem>
             )}
           />
+          <FormField
+            control={form.control}
+            name="aliases"
+            render={({ field }) => (
+              <FormItem>
+                <FormLabel>Header Aliases</FormLabel>
+                <FormControl>
+                  <Input placeholder="e.g. ZWO ASI2600MM Pro, Filter 3" {...field} />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
           {equipmentType === EquipmentType.TELESCOPE && (
             <>
               <FormField
@@ -250,7 +409,7 @@ export default function EquipmentModal({ type }: EquipmentProps) {
                   <FormItem>
                     <FormLabel>Chip Size</FormLabel>
                     <FormControl>
-                      <Input placeholder="Enter chip size" {...field} />
+                      <Input placeholder="Enter chip size, e.g. 23.5 x 15.7 mm" {...field} />
                     </FormControl>
                     <FormMessage />
                   </FormItem>
@@ -274,6 +433,78 @@ export default function EquipmentModal({ type }: EquipmentProps) {
                   </FormItem>
                 )}
               />
+              <FormField
+                control={form.control}
+                name="pixel_size"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Pixel Size (µm)</FormLabel>
+                    <FormControl>
+                      <Input
+                        type="number"
+                        placeholder="Enter pixel size"
+                        {...field}
+                        onChange={(e) => field.onChange(e.target.value === '' ? undefined : parseFloat(e.target.value))}
+                      />
+                    </FormControl>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
+              <FormField
+                control={form.control}
+                name="resolution_x"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Resolution Width (px)</FormLabel>
+                    <FormControl>
+                      <Input
+                        type="number"
+                        placeholder="Enter horizontal resolution"
+                        {...field}
+                        onChange={(e) => field.onChange(e.target.value === '' ? undefined : parseFloat(e.target.value))}
+                      />
+                    </FormControl>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
+              <FormField
+                control={form.control}
+                name="resolution_y"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Resolution Height (px)</FormLabel>
+                    <FormControl>
+                      <Input
+                        type="number"
+                        placeholder="Enter vertical resolution"
+                        {...field}
+                        onChange={(e) => field.onChange(e.target.value === '' ? undefined : parseFloat(e.target.value))}
+                      />
+                    </FormControl>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
+              <FormField
+                control={form.control}
+                name="bit_depth"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Bit Depth</FormLabel>
+                    <FormControl>
+                      <Input
+                        type="number"
+                        placeholder="Enter bit depth"
+                        {...field}
+                        onChange={(e) => field.onChange(e.target.value === '' ? undefined : parseFloat(e.target.value))}
+                      />
+                    </FormControl>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
               <FormField
                 control={form.control}
                 name="rgb"
@@ -294,22 +525,70 @@ export default function EquipmentModal({ type }: EquipmentProps) {
                   </FormItem>
                 )}
               />
+              <FormField
+                control={form.control}
+                name="cooled"
+                render={({ field }) => (
+                  <FormItem className="flex flex-row items-center justify-between rounded-lg border p-4">
+                    <div className="space-y-0.5">
+                      <FormLabel className="text-base">Cooled</FormLabel>
+                      <FormDescription>
+                        Does this camera have a regulated cooler?
+                      </FormDescription>
+                    </div>
+                    <FormControl>
+                      <Switch
+                        checked={field.value}
+                        onCheckedChange={field.onChange}
+                      />
+                    </FormControl>
+                  </FormItem>
+                )}
+              />
             </>
           )}
           {equipmentType === EquipmentType.FILTER && (
-            <FormField
-              control={form.control}
-              name="filter_type"
-              render={({ field }) => (
-                <FormItem>
-                  <FormLabel>Filter Type</FormLabel>
-                  <FormControl>
-                    <Input placeholder="Enter filter type" {...field} />
-                  </FormControl>
-                  <FormMessage />
-                </FormItem>
-              )}
-            />
+            <>
+              <FormField
+                control={form.control}
+                name="filter_type"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Filter Type</FormLabel>
+                    <Select onValueChange={field.onChange} defaultValue={field.value}>
+                      <FormControl>
+                        <SelectTrigger>
+                          <SelectValue placeholder="Select filter type" />
+                        </SelectTrigger>
+                      </FormControl>
+                      <SelectContent>
+                        {Object.values(FilterType).map((filterType) => (
+                          <SelectItem key={filterType} value={filterType}>
+                            {filterTypeNames[filterType]}
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
+              {numberField('central_wavelength', 'Central Wavelength (nm)', 'Enter central wavelength')}
+              {numberField('bandwidth', 'Bandwidth (nm)', 'Enter bandwidth')}
+              <FormField
+                control={form.control}
+                name="size"
+                render={({ field }) => (
+                  <FormItem>
+                    <FormLabel>Size</FormLabel>
+                    <FormControl>
+                      <Input placeholder='Enter size and mount, e.g. 1.25" or 36 mm unmounted' {...field} />
+                    </FormControl>
+                    <FormMessage />
+                  </FormItem>
+                )}
+              />
+            </>
           )}
           {equipmentType === EquipmentType.FLATTENER && (
             <FormField
@@ -331,7 +610,90 @@ export default function EquipmentModal({ type }: EquipmentProps) {
               )}
             />
           )}
-          <Button type="submit">Save Equipment</Button>
+          {equipmentType === EquipmentType.FILTERWHEEL && (
+            <>
+              {numberField('positions', 'Positions', 'Enter number of positions')}
+              {Array.from({ length: positions }, (_, index) => (
+                <FormField
+                  key={index}
+                  control={form.control}
+                  name={`slot_filters.${index}`}
+                  render={({ field }) => (
+                    <FormItem>
+                      <FormLabel>Position {index + 1}</FormLabel>
+                      <Select onValueChange={field.onChange} defaultValue={field.value}>
+                        <FormControl>
+                          <SelectTrigger>
+                            <SelectValue placeholder="Select filter" />
+                          </SelectTrigger>
+                        </FormControl>
+                        <SelectContent>
+                          {appState.equipment_list.filter_list.filter(isActive).map((filter) => (
+                            <SelectItem key={filter.id} value={filter.id}>
+                              {getViewName(filter)}
+                            </SelectItem>
+                          ))}
+                        </SelectContent>
+                      </Select>
+                      <FormMessage />
+                    </FormItem>
+                  )}
+                />
+              ))}
+            </>
+          )}
+          {equipmentType === EquipmentType.FOCUSER && (
+            <>
+              {numberField('max_position', 'Max Position (steps)', 'Enter max position')}
+              {numberField('step_size', 'Step Size (µm)', 'Enter step size')}
+            </>
+          )}
+          {equipmentType === EquipmentType.GUIDESCOPE && (
+            <>
+              {numberField('focal_length', 'Focal Length (mm)', 'Enter focal length')}
+              {numberField('aperture', 'Aperture (mm)', 'Enter aperture')}
+            </>
+          )}
+          {equipmentType === EquipmentType.GUIDECAMERA && numberField('pixel_size', 'Pixel Size (µm)', 'Enter pixel size')}
+          {equipmentType === EquipmentType.OFFAXISGUIDER && numberField('prism_size', 'Prism Size (mm)', 'Enter prism size')}
+          {equipmentType === EquipmentType.ROTATOR && numberField('step_size', 'Step Size (°)', 'Enter step size')}
+          {equipmentType === EquipmentType.DEWHEATER && numberField('channels', 'Channels', 'Enter number of channels')}
+          {equipmentType === EquipmentType.FIELDROTATOR && numberField('max_rotation', 'Max Rotation (°)', 'Enter max rotation')}
+          <FormField
+            control={form.control}
+            name="purchase_date"
+            render={({ field }) => (
+              <FormItem>
+                <FormLabel>Purchase Date</FormLabel>
+                <FormControl>
+                  <Input type="date" {...field} />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+          {numberField('price', 'Price', 'Enter price')}
+          <FormField
+            control={form.control}
+            name="serial_number"
+            render={({ field }) => (
+              <FormItem>
+                <FormLabel>Serial Number</FormLabel>
+                <FormControl>
+                  <Input placeholder="Enter serial number" {...field} />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+          {similar && (
+            <FormDescription>
+              Similar {equipmentType} already exists:{' '}
+              {similar.candidates.map((candidate) => candidate.name).join(', ')}.
+              Merge them from the equipment details, or save again to add it anyway.
+            </FormDescription>
+          )}
+          <Button type="submit">{similar ? 'Save Anyway' : 'Save Equipment'}</Button>
         </form>
       </Form>
     </Modal>
@@ -0,0 +1,136 @@
+'use client';
+
+import styles from './linkTarget.module.scss';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { open } from '@tauri-apps/plugin-dialog';
+import { Modal } from '@/components/ui/custom/modal';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { ScrollArea } from '@/components/ui/scroll-area';
+import { toast } from '@/components/ui/use-toast';
+import { Table, TableBody, TableCell, TableRow } from '@/components/ui/table';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { DeepSkyObject, ObjectType } from '@/interfaces/targets';
+
+export const objectTypeNames: Record<ObjectType, string> = {
+  GALAXY: 'Galaxy',
+  GALAXYCLUSTER: 'Galaxy Cluster',
+  EMISSIONNEBULA: 'Emission Nebula',
+  REFLECTIONNEBULA: 'Reflection Nebula',
+  PLANETARYNEBULA: 'Planetary Nebula',
+  SUPERNOVAREMNANT: 'Supernova Remnant',
+  DARKNEBULA: 'Dark Nebula',
+  OPENCLUSTER: 'Open Cluster',
+  GLOBULARCLUSTER: 'Globular Cluster',
+  OTHER: 'Other',
+};
+
+function onError(error: unknown) {
+  toast({
+    variant: 'destructive',
+    title: 'Uh oh! Something went wrong.',
+    description: 'Error: ' + error,
+  });
+}
+
+interface LinkTargetProps {
+  target: string;
+}
+
+export default function LinkTarget({ target }: LinkTargetProps) {
+  const { setAppState } = useAppState();
+  const { closeModal } = useModal();
+
+  const [query, setQuery] = useState(target);
+  const [objects, setObjects] = useState<DeepSkyObject[]>([]);
+  const [selected, setSelected] = useState<DeepSkyObject>();
+  const [version, setVersion] = useState<number>();
+
+  useEffect(() => {
+    invoke<DeepSkyObject[]>('search_dso_catalog', { query })
+      .then(setObjects)
+      .catch(onError);
+  }, [query, version]);
+
+  function link() {
+    if (!selected) {
+      return;
+    }
+    invoke('set_target_object', { target, objectId: selected.id })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Linked ' + target + ' to ' + selected.id + '.',
+        });
+        closeModal();
+      })
+      .catch(onError);
+  }
+
+  function importCatalog() {
+    open({ multiple: false, filters: [{ name: 'Object Catalog', extensions: ['json'] }] })
+      .then((path) => {
+        if (!path) {
+          return;
+        }
+        invoke<number>('import_dso_catalog', { path })
+          .then((version) => {
+            toast({
+              description: 'Updated the object catalog to version ' + version + '.',
+            });
+            setVersion(version);
+          })
+          .catch(onError);
+      })
+      .catch(onError);
+  }
+
+  return (
+    <Modal
+      title="Link Target"
+      subtitle={'Choose the catalog object for "' + target + '". Future imports with this name are linked too.'}
+      separator
+      className={styles.modal}
+    >
+      <div className={styles.content}>
+        <Input
+          placeholder="Search M31, NGC 7000, Sh2-155, Rosette..."
+          value={query}
+          onChange={(e) => setQuery(e.target.value)}
+        />
+        <ScrollArea className={styles.results}>
+          <Table>
+            <TableBody>
+              {objects.map((object) => (
+                <TableRow
+                  key={object.id}
+                  className={object.id === selected?.id ? styles.selected : undefined}
+                  onClick={() => setSelected(object)}
+                >
+                  <TableCell>{object.id}</TableCell>
+                  <TableCell>{object.name}</TableCell>
+                  <TableCell>{objectTypeNames[object.object_type]}</TableCell>
+                  <TableCell>{object.magnitude ?? ''}</TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </ScrollArea>
+        <p className={styles.hint}>
+          The built-in catalog has every Messier and Caldwell object and the popular NGC, IC and Sh2 targets.
+          Import a full NGC, IC or Sh2 catalog to search all of their objects.
+        </p>
+        <div className={styles.actions}>
+          <Button variant="secondary" onClick={importCatalog}>
+            Import Catalog
+          </Button>
+          <Button disabled={!selected} onClick={link}>
+            Link {selected ? selected.id : 'Target'}
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
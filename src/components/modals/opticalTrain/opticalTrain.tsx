@@ -0,0 +1,152 @@
+'use client';
+
+import styles from './opticalTrain.module.scss';
+import { useState } from 'react';
+import { UUID } from 'crypto';
+import { v4 as uuidv4, NIL as NIL_UUID } from 'uuid';
+import { invoke } from '@tauri-apps/api/core';
+import { Modal } from '@/components/ui/custom/modal';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { toast } from '@/components/ui/use-toast';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { EquipmentType } from '@/enums/equipmentType';
+import { EquipmentItem, OpticalTrain } from '@/interfaces/equipment';
+import { equipmentListKeys, getViewName, isActive } from '@/utils/equipment';
+
+type TrainEquipmentKey = Exclude<keyof OpticalTrain, 'id' | 'name' | 'back_focus' | 'notes'>;
+
+// filters change within a night, so they aren't part of a train
+const trainEquipment: [TrainEquipmentKey, EquipmentType][] = [
+  ['telescope_id', EquipmentType.TELESCOPE],
+  ['flattener_id', EquipmentType.FLATTENER],
+  ['camera_id', EquipmentType.CAMERA],
+  ['mount_id', EquipmentType.MOUNT],
+  ['filter_wheel_id', EquipmentType.FILTERWHEEL],
+  ['focuser_id', EquipmentType.FOCUSER],
+  ['guide_scope_id', EquipmentType.GUIDESCOPE],
+  ['guide_camera_id', EquipmentType.GUIDECAMERA],
+  ['off_axis_guider_id', EquipmentType.OFFAXISGUIDER],
+  ['rotator_id', EquipmentType.ROTATOR],
+  ['dew_heater_id', EquipmentType.DEWHEATER],
+  ['field_rotator_id', EquipmentType.FIELDROTATOR],
+];
+
+interface OpticalTrainModalProps {
+  train?: OpticalTrain;
+}
+
+export default function OpticalTrainModal({ train }: OpticalTrainModalProps) {
+  const { appState, setAppState } = useAppState();
+  const { closeModal } = useModal();
+
+  const [name, setName] = useState(train?.name ?? '');
+  const [equipment, setEquipment] = useState<Record<TrainEquipmentKey, UUID>>(
+    Object.fromEntries(
+      trainEquipment.map(([key]) => [key, train?.[key] ?? (NIL_UUID as UUID)]),
+    ) as Record<TrainEquipmentKey, UUID>,
+  );
+  const [backFocus, setBackFocus] = useState(train?.back_focus?.toString() ?? '');
+  const [notes, setNotes] = useState(train?.notes ?? '');
+
+  // retired items stay selectable if the train already uses them
+  function items(key: TrainEquipmentKey, type: EquipmentType): EquipmentItem[] {
+    const list = appState.equipment_list[equipmentListKeys[type]] as EquipmentItem[];
+    return list.filter((item) => isActive(item) || item.id === equipment[key]);
+  }
+
+  function save() {
+    const value = parseFloat(backFocus);
+    const newTrain: OpticalTrain = {
+      id: train?.id ?? (uuidv4() as UUID),
+      name,
+      ...equipment,
+      back_focus: isNaN(value) ? null : value,
+      notes,
+    };
+
+    invoke('save_optical_train', { train: newTrain })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Saved ' + name + ' successfully!',
+        });
+        closeModal();
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <Modal
+      title={train ? 'Edit Optical Train' : 'New Optical Train'}
+      subtitle="A rig you reuse across sessions, new sessions with its camera and telescope take over the rest."
+      separator
+      className={styles.modal}
+    >
+      <div className={styles.content}>
+        <div>
+          <Label>Name</Label>
+          <Input value={name} onChange={(e) => setName(e.target.value)} />
+        </div>
+        <div className={styles.grid}>
+          {trainEquipment.map(([key, type]) => (
+            <div key={key}>
+              <Label>{type}</Label>
+              <Select
+                value={equipment[key]}
+                onValueChange={(value) => setEquipment({ ...equipment, [key]: value as UUID })}
+              >
+                <SelectTrigger>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value={NIL_UUID}>None</SelectItem>
+                  {items(key, type).map((item) => (
+                    <SelectItem key={item.id} value={item.id}>
+                      {getViewName(item)}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          ))}
+        </div>
+        <div className={styles.row}>
+          <div>
+            <Label>Back Focus (mm)</Label>
+            <Input
+              type="number"
+              min={0}
+              value={backFocus}
+              onChange={(e) => setBackFocus(e.target.value)}
+            />
+          </div>
+          <div>
+            <Label>Notes</Label>
+            <Input value={notes} onChange={(e) => setNotes(e.target.value)} />
+          </div>
+        </div>
+        <div className={styles.actions}>
+          <Button disabled={!name} onClick={save}>
+            Save
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
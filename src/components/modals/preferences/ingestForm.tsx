@@ -0,0 +1,147 @@
+import styles from './preferences.module.scss';
+import { Button } from '@/components/ui/button';
+import {
+  Form,
+  FormControl,
+  FormDescription,
+  FormField,
+  FormItem,
+  FormLabel,
+  FormMessage,
+} from '@/components/ui/form';
+import { Input } from '@/components/ui/input';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { useToast } from '@/components/ui/use-toast';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { invoke } from '@tauri-apps/api/core';
+import { zodResolver } from '@hookform/resolvers/zod';
+import { useForm } from 'react-hook-form';
+import { z } from 'zod';
+
+const formSchema = z.object({
+  mode: z.enum(['OFF', 'PROPOSE', 'AUTO']),
+  settleSeconds: z.coerce.number().int().min(1),
+  minLightSubs: z.coerce.number().int().min(1),
+});
+
+export default function IngestForm() {
+  const { toast } = useToast();
+  const { appState, setAppState } = useAppState();
+
+  const form = useForm<z.infer<typeof formSchema>>({
+    resolver: zodResolver(formSchema),
+    defaultValues: {
+      mode: appState.preferences.ingest.mode,
+      settleSeconds: appState.preferences.ingest.settle_seconds,
+      minLightSubs: appState.preferences.ingest.min_light_subs,
+    },
+  });
+
+  function onSubmit(values: z.infer<typeof formSchema>) {
+    const preferences = {
+      ...appState.preferences,
+      ingest: {
+        mode: values.mode,
+        settle_seconds: values.settleSeconds,
+        min_light_subs: values.minLightSubs,
+      },
+    };
+
+    invoke('save_preferences', { preferences })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          title: 'Success',
+          description: 'Your preferences have been saved.',
+        });
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <Form {...form}>
+      <form onSubmit={form.handleSubmit(onSubmit)} className={styles.form}>
+        <FormField
+          control={form.control}
+          name="mode"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>Mode</FormLabel>
+              <Select onValueChange={field.onChange} defaultValue={field.value}>
+                <FormControl>
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                </FormControl>
+                <SelectContent>
+                  <SelectItem value="OFF">Off</SelectItem>
+                  <SelectItem value="PROPOSE">Propose</SelectItem>
+                  <SelectItem value="AUTO">Automatic</SelectItem>
+                </SelectContent>
+              </Select>
+              <FormDescription>
+                What happens to new captures in the source directory. Propose
+                lists them on the log page, automatic creates imaging sessions
+                from light frames right away.
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          control={form.control}
+          name="settleSeconds"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>Settle Time</FormLabel>
+              <FormControl>
+                <Input type="number" {...field} />
+              </FormControl>
+              <FormDescription>
+                Seconds a file has to stay unchanged before it counts as
+                finished.
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          control={form.control}
+          name="minLightSubs"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>Minimum Light Frames</FormLabel>
+              <FormControl>
+                <Input type="number" {...field} />
+              </FormControl>
+              <FormDescription>
+                Smaller groups of light frames are always proposed, even in
+                automatic mode.
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <Button
+          className={styles.updateButton}
+          type="submit"
+          disabled={!form.formState.isDirty}
+        >
+          Update ingest
+        </Button>
+      </form>
+    </Form>
+  );
+}
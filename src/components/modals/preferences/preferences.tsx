@@ -5,6 +5,7 @@ import styles from './preferences.module.scss';
 import AppearanceForm from './appearanceForm';
 import StorageForm from './storageForm';
 import UserForm from './userForm';
+import IngestForm from './ingestForm';
 
 export function Preferences() {
   return (
@@ -13,6 +14,7 @@ export function Preferences() {
         <TabsList className={styles.tabsList}>
           <TabsTrigger value="appearance">Appearance</TabsTrigger>
           <TabsTrigger value="storage">Storage</TabsTrigger>
+          <TabsTrigger value="ingest">Ingest</TabsTrigger>
           <TabsTrigger value="user">User</TabsTrigger>
         </TabsList>
         <TabsContent value="appearance" className={styles.tabsContent}>
@@ -31,6 +33,14 @@ export function Preferences() {
             <StorageForm />
           </Content>
         </TabsContent>
+        <TabsContent value="ingest" className={styles.tabsContent}>
+          <Content
+            title="Ingest"
+            subtitle="Decide how new captures in your source directory are imported."
+          >
+            <IngestForm />
+          </Content>
+        </TabsContent>
         <TabsContent value="user" className={styles.tabsContent}>
           <Content
             title="User"
@@ -10,49 +10,64 @@ import {
   FormMessage,
 } from '@/components/ui/form';
 import { Input } from '@/components/ui/input';
-import { ToastAction } from '@/components/ui/toast';
 import { useToast } from '@/components/ui/use-toast';
-import { useAppState } from '@/context/stateProvider';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { invoke } from '@tauri-apps/api/core';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { useEffect, useState } from 'react';
 import { useForm } from 'react-hook-form';
 import { z } from 'zod';
 
 const formSchema = z.object({
-  rootDirectory: z.string().min(2, {
-    message: 'Username must be at least 2 characters.', // change
-  }),
+  weatherApiKey: z.string(),
+  weatherFile: z.string(),
 });
 
 export default function UserForm() {
   const { toast } = useToast();
-  const { appState } = useAppState();
+  const { appState, setAppState } = useAppState();
   const [isChanged, setIsChanged] = useState(false);
 
   const form = useForm<z.infer<typeof formSchema>>({
     resolver: zodResolver(formSchema),
     defaultValues: {
-      rootDirectory: appState.preferences.storage.root_directory,
+      weatherApiKey: appState.preferences.user.weather_api_key,
+      weatherFile: appState.preferences.user.weather_file,
     },
   });
 
-  const originalValue = appState.preferences.storage.root_directory;
-  const watchedValue = form.watch('rootDirectory');
+  const { weather_api_key, weather_file } = appState.preferences.user;
+  const [watchedApiKey, watchedFile] = form.watch(['weatherApiKey', 'weatherFile']);
 
   useEffect(() => {
-    setIsChanged(watchedValue !== originalValue);
-  }, [watchedValue, originalValue]);
+    setIsChanged(watchedApiKey !== weather_api_key || watchedFile !== weather_file);
+  }, [watchedApiKey, watchedFile, weather_api_key, weather_file]);
 
   function onSubmit(values: z.infer<typeof formSchema>) {
-    toast({
-      title: 'Success',
-      description: 'Your preferences have been saved.',
-      action: (
-        <ToastAction onClick={() => console.log('test')} altText="Undo">
-          Undo
-        </ToastAction>
-      ),
-    });
+    const preferences = {
+      ...appState.preferences,
+      user: {
+        ...appState.preferences.user,
+        weather_api_key: values.weatherApiKey,
+        weather_file: values.weatherFile,
+      },
+    };
+
+    invoke('save_preferences', { preferences })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          title: 'Success',
+          description: 'Your preferences have been saved.',
+        });
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
   }
 
   return (
@@ -60,14 +75,35 @@ export default function UserForm() {
       <form onSubmit={form.handleSubmit(onSubmit)} className={styles.form}>
         <FormField
           control={form.control}
-          name="rootDirectory"
+          name="weatherApiKey"
           render={({ field }) => (
             <FormItem>
               <FormLabel>Weather API Key</FormLabel>
               <FormControl>
                 <Input {...field} />
               </FormControl>
-              <FormDescription>The API key from OpenWeather.</FormDescription>
+              <FormDescription>
+                The API key from OpenWeather with access to the history API, used to fill in the
+                temperature, humidity, dew point, wind and cloud cover of new sessions. Seeing is not
+                available from OpenWeather, enter it by hand or use a weather file.
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          control={form.control}
+          name="weatherFile"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>Weather File</FormLabel>
+              <FormControl>
+                <Input {...field} />
+              </FormControl>
+              <FormDescription>
+                The path of a JSON file of hourly weather records, used instead of OpenWeather for
+                working offline and testing. Leave it empty to use the API key.
+              </FormDescription>
               <FormMessage />
             </FormItem>
           )}
@@ -0,0 +1,170 @@
+'use client';
+
+import styles from './project.module.scss';
+import { useState } from 'react';
+import { UUID } from 'crypto';
+import { v4 as uuidv4 } from 'uuid';
+import { invoke } from '@tauri-apps/api/core';
+import { Modal } from '@/components/ui/custom/modal';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { Switch } from '@/components/ui/switch';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { toast } from '@/components/ui/use-toast';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { FilterType, filterTypeNames } from '@/enums/filterType';
+import { ImagingProject, IntegrationGoal } from '@/interfaces/projects';
+
+interface ProjectModalProps {
+  project?: ImagingProject;
+}
+
+export default function ProjectModal({ project }: ProjectModalProps) {
+  const { appState, setAppState } = useAppState();
+  const { closeModal } = useModal();
+
+  const [name, setName] = useState(project?.name ?? '');
+  const [target, setTarget] = useState(project?.target ?? '');
+  const [opticalTrainId, setOpticalTrainId] = useState<string>(
+    project?.optical_train_id ?? 'any',
+  );
+  const [autoAssign, setAutoAssign] = useState(project?.auto_assign ?? true);
+  const [goals, setGoals] = useState<IntegrationGoal[]>(project?.goals ?? []);
+
+  function updateGoal(index: number, goal: IntegrationGoal) {
+    setGoals(goals.map((g, i) => (i === index ? goal : g)));
+  }
+
+  function save() {
+    const newProject: ImagingProject = {
+      id: project?.id ?? (uuidv4() as UUID),
+      name,
+      target,
+      optical_train_id: opticalTrainId === 'any' ? null : (opticalTrainId as UUID),
+      goals,
+      session_ids: project?.session_ids ?? [],
+      auto_assign: autoAssign,
+      notes: project?.notes ?? '',
+    };
+
+    invoke('save_project', { project: newProject })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Saved ' + name + ' successfully!',
+        });
+        closeModal();
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <Modal
+      title={project ? 'Edit Project' : 'New Project'}
+      subtitle="Collect the sessions of a target until every filter reaches its goal."
+      separator
+      className={styles.modal}
+    >
+      <div className={styles.content}>
+        <div className={styles.row}>
+          <div>
+            <Label>Name</Label>
+            <Input value={name} onChange={(e) => setName(e.target.value)} />
+          </div>
+          <div>
+            <Label>Target</Label>
+            <Input
+              placeholder="NGC 7000"
+              value={target}
+              onChange={(e) => setTarget(e.target.value)}
+            />
+          </div>
+        </div>
+        <div>
+          <Label>Optical Train</Label>
+          <Select value={opticalTrainId} onValueChange={setOpticalTrainId}>
+            <SelectTrigger>
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="any">Any</SelectItem>
+              {appState.equipment_list.optical_train_list.map((train) => (
+                <SelectItem key={train.id} value={train.id}>
+                  {train.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+        {goals.map((goal, index) => (
+          <div key={index} className={styles.row}>
+            <div>
+              <Label>Filter</Label>
+              <Select
+                value={goal.filter_type}
+                onValueChange={(value) =>
+                  updateGoal(index, { ...goal, filter_type: value as FilterType })
+                }
+              >
+                <SelectTrigger>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {Object.values(FilterType).map((type) => (
+                    <SelectItem key={type} value={type}>
+                      {filterTypeNames[type]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div>
+              <Label>Goal (h)</Label>
+              <Input
+                type="number"
+                min={0}
+                value={goal.hours}
+                onChange={(e) => updateGoal(index, { ...goal, hours: Number(e.target.value) })}
+              />
+            </div>
+            <Button
+              variant="secondary"
+              onClick={() => setGoals(goals.filter((_, i) => i !== index))}
+            >
+              Remove
+            </Button>
+          </div>
+        ))}
+        <Button
+          variant="secondary"
+          onClick={() => setGoals([...goals, { filter_type: FilterType.HA, hours: 10 }])}
+        >
+          Add Goal
+        </Button>
+        <div className={styles.switch}>
+          <Switch checked={autoAssign} onCheckedChange={setAutoAssign} />
+          <Label>Add new sessions of this target automatically</Label>
+        </div>
+        <div className={styles.actions}>
+          <Button disabled={!name || !target} onClick={save}>
+            Save
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
@@ -0,0 +1,128 @@
+'use client';
+
+import styles from './site.module.scss';
+import { useState } from 'react';
+import { v4 as uuidv4 } from 'uuid';
+import { invoke } from '@tauri-apps/api/core';
+import { Modal } from '@/components/ui/custom/modal';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { toast } from '@/components/ui/use-toast';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { ObservingSite } from '@/interfaces/state';
+
+// empty inputs are stored as null
+function toNumber(value: string): number | null {
+  return value.trim() === '' ? null : parseFloat(value);
+}
+
+interface SiteModalProps {
+  site?: ObservingSite;
+}
+
+export default function SiteModal({ site }: SiteModalProps) {
+  const { setAppState } = useAppState();
+  const { closeModal } = useModal();
+
+  const [name, setName] = useState(site?.name ?? '');
+  const [latitude, setLatitude] = useState(site?.latitude.toString() ?? '');
+  const [longitude, setLongitude] = useState(site?.longitude.toString() ?? '');
+  const [elevation, setElevation] = useState(site?.elevation?.toString() ?? '');
+  const [timezone, setTimezone] = useState(
+    site?.timezone ?? Intl.DateTimeFormat().resolvedOptions().timeZone,
+  );
+  const [bortle, setBortle] = useState(site?.bortle?.toString() ?? '');
+  const [sqm, setSqm] = useState(site?.sqm?.toString() ?? '');
+
+  function save() {
+    const newSite: ObservingSite = {
+      id: site?.id ?? uuidv4(),
+      name,
+      latitude: parseFloat(latitude),
+      longitude: parseFloat(longitude),
+      elevation: toNumber(elevation),
+      timezone,
+      bortle: toNumber(bortle),
+      sqm: toNumber(sqm),
+    };
+
+    invoke('save_site', { site: newSite })
+      .then(() => {
+        fetchAppState(setAppState);
+        toast({
+          description: 'Saved ' + name + ' successfully!',
+        });
+        closeModal();
+      })
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <Modal
+      title={site ? 'Edit Site' : 'Add Site'}
+      subtitle="Sessions shot within 10 km of a site are linked to it."
+      separator
+      className={styles.modal}
+    >
+      <div className={styles.content}>
+        <div>
+          <Label>Name</Label>
+          <Input value={name} onChange={(e) => setName(e.target.value)} />
+        </div>
+        <div className={styles.row}>
+          <div>
+            <Label>Latitude (°)</Label>
+            <Input type="number" value={latitude} onChange={(e) => setLatitude(e.target.value)} />
+          </div>
+          <div>
+            <Label>Longitude (°)</Label>
+            <Input type="number" value={longitude} onChange={(e) => setLongitude(e.target.value)} />
+          </div>
+        </div>
+        <div className={styles.row}>
+          <div>
+            <Label>Elevation (m)</Label>
+            <Input type="number" value={elevation} onChange={(e) => setElevation(e.target.value)} />
+          </div>
+          <div>
+            <Label>Timezone</Label>
+            <Input
+              placeholder="Europe/Berlin"
+              value={timezone}
+              onChange={(e) => setTimezone(e.target.value)}
+            />
+          </div>
+        </div>
+        <div className={styles.row}>
+          <div>
+            <Label>Bortle</Label>
+            <Input
+              type="number"
+              min={1}
+              max={9}
+              value={bortle}
+              onChange={(e) => setBortle(e.target.value)}
+            />
+          </div>
+          <div>
+            <Label>SQM (mag/arcsec²)</Label>
+            <Input type="number" value={sqm} onChange={(e) => setSqm(e.target.value)} />
+          </div>
+        </div>
+        <div className={styles.actions}>
+          <Button disabled={!name || latitude === '' || longitude === ''} onClick={save}>
+            Save
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
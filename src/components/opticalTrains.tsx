@@ -0,0 +1,80 @@
+'use client';
+
+import styles from './opticalTrains.module.scss';
+import { invoke } from '@tauri-apps/api/core';
+import { UUID } from 'crypto';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { useModal } from '@/context/modalProvider';
+import { EquipmentItem, OpticalTrain } from '@/interfaces/equipment';
+import { getViewName } from '@/utils/equipment';
+import { Button } from '@/components/ui/button';
+import { toast } from '@/components/ui/use-toast';
+import OpticalTrainModal from '@/components/modals/opticalTrain/opticalTrain';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+export default function OpticalTrains() {
+  const { appState, setAppState } = useAppState();
+  const { openModal } = useModal();
+
+  const trains = [...appState.equipment_list.optical_train_list].sort((a, b) =>
+    a.name.localeCompare(b.name),
+  );
+
+  function name(list: EquipmentItem[], id: UUID): string {
+    const item = list.find((i) => i.id === id);
+    return item ? getViewName(item) : 'N/A';
+  }
+
+  function deleteTrain(train: OpticalTrain) {
+    invoke('delete_optical_train', { id: train.id })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  if (trains.length === 0) {
+    return <div>No optical trains yet.</div>;
+  }
+
+  return (
+    <Table className={styles.component}>
+      <TableHeader>
+        <TableRow>
+          <TableHead>Name</TableHead>
+          <TableHead>Telescope</TableHead>
+          <TableHead>Flattener</TableHead>
+          <TableHead>Camera</TableHead>
+          <TableHead>Mount</TableHead>
+          <TableHead />
+        </TableRow>
+      </TableHeader>
+      <TableBody>
+        {trains.map((train) => (
+          <TableRow key={train.id}>
+            <TableCell>{train.name}</TableCell>
+            <TableCell>{name(appState.equipment_list.telescope_list, train.telescope_id)}</TableCell>
+            <TableCell>{name(appState.equipment_list.flattener_list, train.flattener_id)}</TableCell>
+            <TableCell>{name(appState.equipment_list.camera_list, train.camera_id)}</TableCell>
+            <TableCell>{name(appState.equipment_list.mount_list, train.mount_id)}</TableCell>
+            <TableCell className={styles.actions}>
+              <Button
+                variant="secondary"
+                onClick={() => openModal(<OpticalTrainModal train={train} />)}
+              >
+                Edit
+              </Button>
+              <Button variant="secondary" onClick={() => deleteTrain(train)}>
+                Delete
+              </Button>
+            </TableCell>
+          </TableRow>
+        ))}
+      </TableBody>
+    </Table>
+  );
+}
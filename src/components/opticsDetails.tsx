@@ -0,0 +1,112 @@
+'use client';
+
+import styles from './opticsDetails.module.scss';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useAppState } from '@/context/stateProvider';
+import { Telescope } from '@/interfaces/equipment';
+import { OpticsMetrics } from '@/interfaces/state';
+import { getViewName } from '@/utils/equipment';
+import { toast } from '@/components/ui/use-toast';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue
+} from '@/components/ui/select';
+import { Table, TableBody, TableCell, TableRow } from './ui/table';
+
+const NONE = 'none';
+
+export const samplingNames: Record<NonNullable<OpticsMetrics['sampling']>, string> = {
+  UNDERSAMPLED: 'Undersampled',
+  OPTIMAL: 'Optimal',
+  OVERSAMPLED: 'Oversampled',
+};
+
+export function formatFieldOfView(fieldOfView: [number, number] | null): string | null {
+  return fieldOfView && `${fieldOfView[0].toFixed(0)}' x ${fieldOfView[1].toFixed(0)}'`;
+}
+
+interface OpticsDetailsProps {
+  telescope: Telescope;
+}
+
+// focal ratio, image scale and field of view of the telescope with a camera and flattener of the user's choice
+export default function OpticsDetails({ telescope }: OpticsDetailsProps) {
+  const { appState } = useAppState();
+  const [cameraId, setCameraId] = useState(NONE);
+  const [flattenerId, setFlattenerId] = useState(NONE);
+  const [metrics, setMetrics] = useState<OpticsMetrics | undefined>(undefined);
+
+  useEffect(() => {
+    invoke<OpticsMetrics>('calculate_optics', {
+      telescopeId: telescope.id,
+      cameraId: cameraId === NONE ? null : cameraId,
+      flattenerId: flattenerId === NONE ? null : flattenerId,
+    })
+      .then(setMetrics)
+      .catch((error) => {
+        setMetrics(undefined);
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }, [telescope.id, cameraId, flattenerId, appState.equipment_list]);
+
+  function row(label: string, value: string | null | undefined, unit = '') {
+    return (
+      <TableRow>
+        <TableCell>{label}</TableCell>
+        <TableCell>{value === null || value === undefined ? 'N/A' : value + unit}</TableCell>
+      </TableRow>
+    );
+  }
+
+  return (
+    <div className={styles.component}>
+      <div className={styles.selects}>
+        <Select value={cameraId} onValueChange={setCameraId}>
+          <SelectTrigger>
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NONE}>No Camera</SelectItem>
+            {appState.equipment_list.camera_list.map((camera) => (
+              <SelectItem key={camera.id} value={camera.id}>
+                {getViewName(camera)}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+        <Select value={flattenerId} onValueChange={setFlattenerId}>
+          <SelectTrigger>
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NONE}>No Flattener</SelectItem>
+            {appState.equipment_list.flattener_list.map((flattener) => (
+              <SelectItem key={flattener.id} value={flattener.id}>
+                {getViewName(flattener)}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+      {metrics && (
+        <Table>
+          <TableBody>
+            {row('Effective Focal Length', metrics.effective_focal_length.toFixed(0), ' mm')}
+            {row('Focal Ratio', metrics.focal_ratio === null ? null : 'f/' + metrics.focal_ratio.toFixed(1))}
+            {row('Image Scale', metrics.image_scale?.toFixed(2), ' "/px')}
+            {row('Field of View', formatFieldOfView(metrics.field_of_view))}
+            {row('Sampling', metrics.sampling && samplingNames[metrics.sampling])}
+          </TableBody>
+        </Table>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,79 @@
+'use client';
+
+import styles from './sensorDetails.module.scss';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useAppState } from '@/context/stateProvider';
+import { Camera } from '@/interfaces/equipment';
+import { SensorMetrics } from '@/interfaces/state';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { toast } from '@/components/ui/use-toast';
+import { Table, TableBody, TableCell, TableRow } from './ui/table';
+
+interface SensorDetailsProps {
+  camera: Camera;
+}
+
+// read noise and full well of the camera at a gain of the user's choice
+export default function SensorDetails({ camera }: SensorDetailsProps) {
+  const { appState } = useAppState();
+  const [gain, setGain] = useState('');
+  const [metrics, setMetrics] = useState<SensorMetrics | undefined>(undefined);
+
+  useEffect(() => {
+    const value = parseInt(gain);
+    invoke<SensorMetrics>('calculate_sensor_metrics', {
+      cameraId: camera.id,
+      gain: isNaN(value) ? null : value,
+    })
+      .then(setMetrics)
+      .catch((error) => {
+        setMetrics(undefined);
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }, [camera.id, gain, appState.equipment_list]);
+
+  function row(label: string, value: string | null | undefined, unit = '') {
+    return (
+      <TableRow>
+        <TableCell>{label}</TableCell>
+        <TableCell>{value === null || value === undefined ? 'N/A' : value + unit}</TableCell>
+      </TableRow>
+    );
+  }
+
+  return (
+    <div className={styles.component}>
+      <div className={styles.gain}>
+        <Label htmlFor="sensor-gain">Gain</Label>
+        <Input
+          id="sensor-gain"
+          type="number"
+          placeholder={metrics?.unity_gain?.toFixed(0) ?? 'Gain'}
+          value={gain}
+          onChange={(e) => setGain(e.target.value)}
+        />
+      </div>
+      {metrics && (
+        <Table>
+          <TableBody>
+            {row(
+              'Sensor Size',
+              metrics.sensor_size &&
+                `${metrics.sensor_size[0].toFixed(1)} x ${metrics.sensor_size[1].toFixed(1)}`,
+              ' mm',
+            )}
+            {row('Unity Gain', metrics.unity_gain?.toFixed(0))}
+            {row('Read Noise', metrics.read_noise?.toFixed(2), ' e-')}
+            {row('Full Well', metrics.full_well?.toFixed(0), ' e-')}
+          </TableBody>
+        </Table>
+      )}
+    </div>
+  );
+}
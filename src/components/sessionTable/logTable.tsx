@@ -30,7 +30,7 @@ import {
   DropdownMenuTrigger,
 } from '../ui/dropdown-menu';
 import { Button } from '../ui/button';
-import { useAppState } from '@/context/stateProvider';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
 import { sessionsColumns } from './sessionsColumns';
 import { ChevronDown } from 'lucide-react';
 import { UUID } from 'crypto';
@@ -40,12 +40,16 @@ import {
   ContextMenuItem,
   ContextMenuSeparator,
   ContextMenuShortcut,
+  ContextMenuSub,
+  ContextMenuSubContent,
+  ContextMenuSubTrigger,
   ContextMenuTrigger,
 } from '../ui/context-menu';
 import { DeleteSVG } from '@/public/svgs';
 import { invoke } from '@tauri-apps/api/core';
 import { toast } from '@/components/ui/use-toast';
 import { useModal } from '@/context/modalProvider';
+import LinkTarget from '@/components/modals/linkTarget/linkTarget';
 import NewImagingSession from '@/components/modals/newImagingSession/newImagingSession';
 import { calibrationColumns } from '@/components/sessionTable/calibrationColumns';
 import { Tabs, TabsList, TabsTrigger } from '@/components/ui/tabs';
@@ -62,7 +66,7 @@ export function LogTable<TData, TValue>({
   const tabKeys = ['sessions', 'calibration'] as const;
   type TableContent = (typeof tabKeys)[number];
 
-  const { appState } = useAppState();
+  const { appState, setAppState } = useAppState();
   const { openModal } = useModal();
 
   const [content, setContent] = useState<TableContent>('sessions');
@@ -129,6 +133,97 @@ export function LogTable<TData, TValue>({
     setSelectedSessionId(rowData.id);
   };
 
+  function selectedSession(): Session | undefined {
+    return table.getRowModel().rows.find((row) => row.id === selectedRowId)
+      ?.original as Session | undefined;
+  }
+
+  function setSessionSite(siteId: UUID | null): void {
+    const session = selectedSession();
+    if (!session) {
+      return;
+    }
+
+    invoke('set_session_site', { sessionId: session.session_id, siteId })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  function applyOpticalTrain(trainId: UUID): void {
+    const session = selectedSession();
+    if (!session) {
+      return;
+    }
+
+    invoke('apply_optical_train', { sessionId: session.session_id, trainId })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  function setSessionProject(projectId: UUID | null): void {
+    const session = selectedSession();
+    if (!session) {
+      return;
+    }
+
+    invoke('set_session_project', { sessionId: session.session_id, projectId })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  function fetchSessionWeather(): void {
+    const session = selectedSession();
+    if (!session) {
+      return;
+    }
+
+    invoke('fetch_session_weather', { sessionId: session.session_id })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  // older logs only stored a count, from now on the subs that aren't rejected are counted
+  function confirmIntegratedSubs(): void {
+    const session = selectedSession();
+    if (!session) {
+      return;
+    }
+
+    invoke('confirm_integrated_subs', { sessionId: session.session_id })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
   function openImagingSession(): void {
     invoke('open_imaging_session', { id: selectedRowId }).catch((error) => {
       toast({
@@ -277,6 +372,87 @@ export function LogTable<TData, TValue>({
           <ContextMenuItem inset disabled={!rowSelected}>
             Details...
           </ContextMenuItem>
+          <ContextMenuSub>
+            <ContextMenuSubTrigger inset disabled={!rowSelected}>
+              Set Site
+            </ContextMenuSubTrigger>
+            <ContextMenuSubContent className="w-48">
+              {appState.sites.map((site) => (
+                <ContextMenuItem key={site.id} onClick={() => setSessionSite(site.id)}>
+                  {site.name}
+                </ContextMenuItem>
+              ))}
+              <ContextMenuSeparator />
+              <ContextMenuItem onClick={() => setSessionSite(null)}>
+                No Site
+              </ContextMenuItem>
+            </ContextMenuSubContent>
+          </ContextMenuSub>
+          <ContextMenuSub>
+            <ContextMenuSubTrigger
+              inset
+              disabled={!rowSelected || appState.equipment_list.optical_train_list.length === 0}
+            >
+              Apply Optical Train
+            </ContextMenuSubTrigger>
+            <ContextMenuSubContent className="w-48">
+              {appState.equipment_list.optical_train_list.map((train) => (
+                <ContextMenuItem key={train.id} onClick={() => applyOpticalTrain(train.id)}>
+                  {train.name}
+                </ContextMenuItem>
+              ))}
+            </ContextMenuSubContent>
+          </ContextMenuSub>
+          <ContextMenuSub>
+            <ContextMenuSubTrigger inset disabled={!rowSelected}>
+              Set Project
+            </ContextMenuSubTrigger>
+            <ContextMenuSubContent className="w-48">
+              {appState.projects.map(({ project }) => (
+                <ContextMenuItem
+                  key={project.id}
+                  onClick={() => setSessionProject(project.id)}
+                >
+                  {project.name}
+                </ContextMenuItem>
+              ))}
+              <ContextMenuSeparator />
+              <ContextMenuItem onClick={() => setSessionProject(null)}>
+                No Project
+              </ContextMenuItem>
+            </ContextMenuSubContent>
+          </ContextMenuSub>
+          <ContextMenuSub>
+            <ContextMenuSubTrigger inset disabled={!rowSelected}>
+              Link Target
+            </ContextMenuSubTrigger>
+            <ContextMenuSubContent className="w-48">
+              {(selectedSession()?.target.split(', ') ?? [])
+                .filter((target) => target !== '')
+                .map((target) => (
+                  <ContextMenuItem
+                    key={target}
+                    onClick={() => openModal(<LinkTarget target={target} />)}
+                  >
+                    {target}...
+                  </ContextMenuItem>
+                ))}
+            </ContextMenuSubContent>
+          </ContextMenuSub>
+          <ContextMenuItem
+            inset
+            disabled={!rowSelected}
+            onClick={fetchSessionWeather}
+          >
+            Fetch Weather
+          </ContextMenuItem>
+          <ContextMenuItem
+            inset
+            disabled={!selectedSession()?.legacy_integrated_subs}
+            onClick={confirmIntegratedSubs}
+          >
+            Count Accepted Subs
+          </ContextMenuItem>
           <ContextMenuItem
             inset
             disabled={!rowSelected}
@@ -4,6 +4,7 @@ import { ColumnDef } from '@tanstack/react-table';
 import { Button } from '../ui/button';
 import { ArrowUpDown } from 'lucide-react';
 import { Session } from '@/interfaces/state';
+import { formatFieldOfView, samplingNames } from '@/components/opticsDetails';
 
 export const sessionsColumns: ColumnDef<Session>[] = [
   {
@@ -19,6 +20,8 @@ export const sessionsColumns: ColumnDef<Session>[] = [
         </Button>
       );
     },
+    // dates of older logs that couldn't be read are shown as they were entered
+    cell: ({ row }) => row.original.legacy_date ?? row.original.date,
   },
   {
     accessorKey: 'target',
@@ -65,6 +68,11 @@ export const sessionsColumns: ColumnDef<Session>[] = [
   {
     accessorKey: 'integrated_subs',
     header: 'Integrated Subs',
+    // counts of older logs are kept until the accepted subs are confirmed
+    cell: ({ row }) =>
+      row.original.legacy_integrated_subs
+        ? row.original.integrated_subs + ' (logged)'
+        : row.original.integrated_subs,
   },
   {
     accessorKey: 'filter',
@@ -88,15 +96,86 @@ export const sessionsColumns: ColumnDef<Session>[] = [
   },
   {
     accessorKey: 'average_seeing',
-    header: 'Average Seeing',
+    // OpenWeather has no seeing, only hand entered values and weather files fill it in
+    header: () => <span title="Not fetched from OpenWeather, enter it by hand">Average Seeing</span>,
   },
   {
     accessorKey: 'average_cloud_cover',
     header: 'Average Cloud Cover',
   },
+  {
+    accessorKey: 'humidity',
+    header: 'Humidity',
+    cell: ({ row }) => {
+      const humidity = row.original.humidity;
+      return humidity === null ? 'N/A' : Math.round(humidity) + '%';
+    },
+  },
+  {
+    accessorKey: 'dew_point',
+    header: 'Dew Point',
+    cell: ({ row }) => {
+      const dewPoint = row.original.dew_point;
+      return dewPoint === null ? 'N/A' : dewPoint.toFixed(1) + ' °C';
+    },
+  },
+  {
+    accessorKey: 'wind_speed',
+    header: 'Wind',
+    cell: ({ row }) => {
+      const windSpeed = row.original.wind_speed;
+      return windSpeed === null ? 'N/A' : windSpeed.toFixed(1) + ' m/s';
+    },
+  },
   {
     accessorKey: 'average_moon',
-    header: 'Average Moon',
+    header: 'Moon Illumination',
+    cell: ({ row }) => row.original.average_moon + '%',
+  },
+  {
+    accessorKey: 'moon_altitude',
+    header: 'Moon',
+    cell: ({ row }) => {
+      const altitude = row.original.moon_altitude;
+      if (altitude === null) {
+        return 'N/A';
+      }
+      return altitude > 0 ? 'Up (max ' + Math.round(altitude) + '°)' : 'Below Horizon';
+    },
+  },
+  {
+    accessorKey: 'moon_separation',
+    header: 'Moon Distance',
+    cell: ({ row }) => {
+      const separation = row.original.moon_separation;
+      return separation === null ? 'N/A' : Math.round(separation) + '°';
+    },
+  },
+  {
+    accessorKey: 'max_altitude',
+    header: 'Altitude',
+    cell: ({ row }) => {
+      const { min_altitude, max_altitude } = row.original;
+      if (min_altitude === null || max_altitude === null) {
+        return 'N/A';
+      }
+      return Math.round(min_altitude) + '° - ' + Math.round(max_altitude) + '°';
+    },
+  },
+  {
+    accessorKey: 'mean_airmass',
+    header: 'Airmass',
+    cell: ({ row }) => row.original.mean_airmass?.toFixed(2) ?? 'N/A',
+  },
+  {
+    accessorKey: 'meridian_flip',
+    header: 'Meridian Flip',
+    cell: ({ row }) => {
+      const flip = row.original.meridian_flip;
+      return flip === null
+        ? 'None'
+        : new Date(flip).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' });
+    },
   },
   {
     accessorKey: 'telescope',
@@ -114,6 +193,39 @@ export const sessionsColumns: ColumnDef<Session>[] = [
     accessorKey: 'camera',
     header: 'Camera',
   },
+  {
+    id: 'focal_ratio',
+    header: 'Focal Ratio',
+    cell: ({ row }) => {
+      const focalRatio = row.original.optics?.focal_ratio;
+      return focalRatio ? 'f/' + focalRatio.toFixed(1) : 'N/A';
+    },
+  },
+  {
+    id: 'image_scale',
+    header: 'Image Scale',
+    cell: ({ row }) => {
+      const imageScale = row.original.optics?.image_scale;
+      return imageScale ? imageScale.toFixed(2) + ' "/px' : 'N/A';
+    },
+  },
+  {
+    id: 'field_of_view',
+    header: 'Field of View',
+    cell: ({ row }) => formatFieldOfView(row.original.optics?.field_of_view ?? null) ?? 'N/A',
+  },
+  {
+    id: 'sampling',
+    header: 'Sampling',
+    cell: ({ row }) => {
+      const sampling = row.original.optics?.sampling;
+      return sampling ? samplingNames[sampling] : 'N/A';
+    },
+  },
+  {
+    accessorKey: 'site',
+    header: 'Site',
+  },
   {
     accessorKey: 'notes',
     header: 'Notes',
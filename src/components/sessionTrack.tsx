@@ -0,0 +1,77 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { UUID } from 'crypto';
+import { invoke } from '@tauri-apps/api/core';
+import { CartesianGrid, Line, LineChart, XAxis, YAxis } from 'recharts';
+import {
+  ChartConfig,
+  ChartContainer,
+  ChartTooltip,
+  ChartTooltipContent,
+} from '@/components/ui/chart';
+import { toast } from '@/components/ui/use-toast';
+import { TrackPoint } from '@/interfaces/analytics';
+
+const chartConfig = {
+  altitude: {
+    label: 'Altitude (°)',
+    color: 'hsl(var(--chart-1))',
+  },
+  hfr: {
+    label: 'HFR',
+    color: 'hsl(var(--chart-2))',
+  },
+} satisfies ChartConfig;
+
+// target altitude and hfr over the subs of an imaging session
+export default function SessionTrack({ sessionId }: { sessionId: UUID }) {
+  const [points, setPoints] = useState<TrackPoint[]>([]);
+
+  useEffect(() => {
+    invoke<TrackPoint[]>('get_session_track', { sessionId })
+      .then(setPoints)
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }, [sessionId]);
+
+  if (points.length === 0) {
+    return <div>No target positions, set the observing site of this session.</div>;
+  }
+
+  const chartData = points.map((point) => ({
+    time: new Date(point.timestamp).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' }),
+    altitude: Math.round(point.altitude * 10) / 10,
+    hfr: point.hfr,
+  }));
+
+  return (
+    <ChartContainer config={chartConfig} className="max-h-[250px] w-full">
+      <LineChart data={chartData}>
+        <CartesianGrid vertical={false} />
+        <XAxis dataKey="time" tickLine={false} axisLine={false} minTickGap={32} />
+        <YAxis yAxisId="altitude" domain={[0, 90]} tickLine={false} axisLine={false} />
+        <YAxis yAxisId="hfr" orientation="right" tickLine={false} axisLine={false} />
+        <ChartTooltip content={<ChartTooltipContent />} />
+        <Line
+          yAxisId="altitude"
+          dataKey="altitude"
+          stroke="var(--color-altitude)"
+          dot={false}
+        />
+        <Line
+          yAxisId="hfr"
+          dataKey="hfr"
+          stroke="var(--color-hfr)"
+          dot={false}
+          connectNulls
+        />
+      </LineChart>
+    </ChartContainer>
+  );
+}
@@ -0,0 +1,69 @@
+'use client';
+
+import { Bar, BarChart, CartesianGrid, XAxis, YAxis } from 'recharts';
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from '@/components/ui/card';
+import {
+  ChartConfig,
+  ChartContainer,
+  ChartTooltip,
+  ChartTooltipContent,
+} from '@/components/ui/chart';
+import { useAppState } from '@/context/stateProvider';
+
+const chartConfig = {
+  hfr: {
+    label: 'Relative HFR',
+    color: 'hsl(var(--chart-1))',
+  },
+  stars: {
+    label: 'Relative Star Count',
+    color: 'hsl(var(--chart-2))',
+  },
+} satisfies ChartConfig;
+
+// sub quality per 10° of target altitude, 1.0 is the median of each light frame set
+export function AltitudeQualityChart({ className }: { className?: string }) {
+  const { appState } = useAppState();
+  const { bins, degradation_altitude } = appState.analytics.altitude_quality;
+
+  const chartData = bins.map((bin) => ({
+    altitude: bin.altitude + '°',
+    hfr: bin.relative_hfr,
+    stars: bin.relative_star_count,
+  }));
+
+  return (
+    <Card className={className}>
+      <CardHeader>
+        <CardTitle>Quality by Altitude</CardTitle>
+        <CardDescription>
+          HFR and star count of the subs relative to the rest of their set
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        <ChartContainer config={chartConfig} className="max-h-[250px] w-full">
+          <BarChart data={chartData}>
+            <CartesianGrid vertical={false} />
+            <XAxis dataKey="altitude" tickLine={false} axisLine={false} />
+            <YAxis tickLine={false} axisLine={false} />
+            <ChartTooltip content={<ChartTooltipContent />} />
+            <Bar dataKey="hfr" fill="var(--color-hfr)" radius={4} />
+            <Bar dataKey="stars" fill="var(--color-stars)" radius={4} />
+          </BarChart>
+        </ChartContainer>
+      </CardContent>
+      <CardFooter className="text-sm text-muted-foreground">
+        {degradation_altitude === null
+          ? 'Not enough subs to find where your data starts degrading.'
+          : 'Stars start to bloat below ' + degradation_altitude + '° altitude.'}
+      </CardFooter>
+    </Card>
+  );
+}
@@ -0,0 +1,52 @@
+'use client';
+
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from '@/components/ui/card';
+import { useAppState } from '@/context/stateProvider';
+import { maintenanceKindNames } from '@/components/maintenanceLog';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from '@/components/ui/table';
+
+// maintenance of all equipment in date order, to compare with the sessions that followed
+export function MaintenanceTimeline({ className }: { className?: string }) {
+  const { appState } = useAppState();
+
+  return (
+    <Card className={className}>
+      <CardHeader>
+        <CardTitle>Maintenance</CardTitle>
+        <CardDescription>Collimation, cleaning and other work on your equipment.</CardDescription>
+      </CardHeader>
+      <CardContent>
+        {appState.analytics.maintenance_timeline.length === 0 ? (
+          <div>No maintenance logged.</div>
+        ) : (
+          <Table>
+            <TableHeader>
+              <TableRow>
+                <TableHead>Date</TableHead>
+                <TableHead>Equipment</TableHead>
+                <TableHead>Maintenance</TableHead>
+                <TableHead>Notes</TableHead>
+              </TableRow>
+            </TableHeader>
+            <TableBody>
+              {appState.analytics.maintenance_timeline.map((event) => (
+                <TableRow key={event.entry.id}>
+                  <TableCell>{event.entry.date}</TableCell>
+                  <TableCell>{event.equipment_name}</TableCell>
+                  <TableCell>{maintenanceKindNames[event.entry.kind]}</TableCell>
+                  <TableCell>{event.entry.notes}</TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -21,7 +21,8 @@ import {
 import { cn } from '@/utils/classNames';
 import { EquipmentType } from '@/enums/equipmentType';
 import { useAppState } from '@/context/stateProvider';
-import { getViewName } from '@/utils/equipment';
+import { equipmentListKeys, getViewName, isActive } from '@/utils/equipment';
+import { EquipmentItem } from '@/interfaces/equipment';
 
 interface ComboBoxProps {
   type: EquipmentType;
@@ -41,42 +42,15 @@ export default function EquipmentComboBox({
   const title: string = type.toString();
   const { appState } = useAppState();
 
+  const list: EquipmentItem[] = appState.equipment_list[equipmentListKeys[type]];
+
   useEffect(() => {
-    switch (type) {
-      case EquipmentType.CAMERA:
-        setValues(
-          appState.equipment_list.camera_list.map((c) => getViewName(c)),
-        );
-        break;
-      case EquipmentType.TELESCOPE:
-        setValues(
-          appState.equipment_list.telescope_list.map((c) => getViewName(c)),
-        );
-        break;
-      case EquipmentType.MOUNT:
-        setValues(
-          appState.equipment_list.mount_list.map((c) => getViewName(c)),
-        );
-        break;
-      case EquipmentType.FILTER:
-        setValues(
-          appState.equipment_list.filter_list.map((c) => getViewName(c)),
-        );
-        break;
-      case EquipmentType.FLATTENER:
-        setValues(
-          appState.equipment_list.flattener_list.map((c) => getViewName(c)),
-        );
-        break;
-    }
-  }, [
-    appState.equipment_list.camera_list,
-    appState.equipment_list.filter_list,
-    appState.equipment_list.flattener_list,
-    appState.equipment_list.mount_list,
-    appState.equipment_list.telescope_list,
-    type,
-  ]);
+    setValues(
+      list
+        .filter((c) => isActive(c) || getViewName(c) === value)
+        .map((c) => getViewName(c))
+    );
+  }, [list, value]);
 
   return (
     <Popover open={open} onOpenChange={setOpen}>
@@ -0,0 +1,57 @@
+'use client';
+
+import styles from './unclassifiedFrames.module.scss';
+import { invoke } from '@tauri-apps/api/core';
+import { fetchAppState, useAppState } from '@/context/stateProvider';
+import { Button } from '@/components/ui/button';
+import { toast } from '@/components/ui/use-toast';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from './ui/table';
+
+// frames that showed up in the library without belonging to a session, kept until they are imported or dismissed
+export default function UnclassifiedFrames() {
+  const { appState, setAppState } = useAppState();
+
+  if (appState.unclassified_frames.length === 0) {
+    return null;
+  }
+
+  function dismiss(paths: string[]) {
+    invoke('dismiss_unclassified_frames', { paths })
+      .then(() => fetchAppState(setAppState))
+      .catch((error) => {
+        toast({
+          variant: 'destructive',
+          title: 'Uh oh! Something went wrong.',
+          description: 'Error: ' + error,
+        });
+      });
+  }
+
+  return (
+    <div className={styles.component}>
+      <Table>
+        <TableHeader>
+          <TableRow>
+            <TableHead>File</TableHead>
+            <TableHead />
+          </TableRow>
+        </TableHeader>
+        <TableBody>
+          {appState.unclassified_frames.map((path) => (
+            <TableRow key={path}>
+              <TableCell className={styles.path}>{path}</TableCell>
+              <TableCell className={styles.actions}>
+                <Button variant="secondary" onClick={() => dismiss([path])}>
+                  Dismiss
+                </Button>
+              </TableCell>
+            </TableRow>
+          ))}
+        </TableBody>
+      </Table>
+      <Button variant="ghost" onClick={() => dismiss(appState.unclassified_frames)}>
+        Dismiss All
+      </Button>
+    </div>
+  );
+}
@@ -1,6 +1,7 @@
 'use client';
 
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import React, {
   createContext,
   Dispatch,
@@ -24,6 +25,11 @@ const defaultAppState: AppState = {
     user: {
       weather_api_key: '',
     },
+    ingest: {
+      mode: 'PROPOSE',
+      settle_seconds: 10,
+      min_light_subs: 1,
+    },
   },
   table_data: {
     sessions: [],
@@ -35,11 +41,40 @@ const defaultAppState: AppState = {
     telescope_list: [],
     flattener_list: [],
     filter_list: [],
+    filter_wheel_list: [],
+    focuser_list: [],
+    guide_scope_list: [],
+    guide_camera_list: [],
+    off_axis_guider_list: [],
+    rotator_list: [],
+    dew_heater_list: [],
+    field_rotator_list: [],
+    optical_train_list: [],
+    filter_aliases: [],
   },
   image_list: [],
   analytics: {
     total_imaging_sessions: 0,
+    equipment_chart: {
+      telescopes: { slices: [] },
+      cameras: { slices: [] },
+      mounts: { slices: [] },
+    },
+    optical_trains: [],
+    equipment_usage: [],
+    sites: [],
+    maintenance_timeline: [],
+    altitude_quality: {
+      bins: [],
+      degradation_altitude: null,
+    },
   },
+  unclassified_frames: [],
+  ingest_proposals: [],
+  ambiguous_matches: [],
+  sites: [],
+  wishlist: [],
+  projects: [],
 };
 
 interface AppStateContextType {
@@ -77,6 +112,26 @@ export default function StateProvider({ children }: { children: ReactNode }) {
     removeContextMenu();
   }, []);
 
+  useEffect(() => {
+    const unlisten = listen('library_changed', () => {
+      fetchAppState(setAppState);
+    });
+
+    return () => {
+      unlisten.then((dispose) => dispose());
+    };
+  }, []);
+
+  useEffect(() => {
+    const unlisten = listen('ingest', () => {
+      fetchAppState(setAppState);
+    });
+
+    return () => {
+      unlisten.then((dispose) => dispose());
+    };
+  }, []);
+
   return (
     <AppStateContext.Provider value={{ appState, setAppState }}>
       {children}
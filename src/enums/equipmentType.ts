@@ -9,4 +9,20 @@ export enum EquipmentType {
   FILTER = 'Filter',
   // eslint-disable-next-line no-unused-vars
   FLATTENER = 'Flattener',
+  // eslint-disable-next-line no-unused-vars
+  FILTERWHEEL = 'Filter Wheel',
+  // eslint-disable-next-line no-unused-vars
+  FOCUSER = 'Focuser',
+  // eslint-disable-next-line no-unused-vars
+  GUIDESCOPE = 'Guide Scope',
+  // eslint-disable-next-line no-unused-vars
+  GUIDECAMERA = 'Guide Camera',
+  // eslint-disable-next-line no-unused-vars
+  OFFAXISGUIDER = 'Off-Axis Guider',
+  // eslint-disable-next-line no-unused-vars
+  ROTATOR = 'Rotator',
+  // eslint-disable-next-line no-unused-vars
+  DEWHEATER = 'Dew Heater',
+  // eslint-disable-next-line no-unused-vars
+  FIELDROTATOR = 'Field Rotator',
 }
@@ -0,0 +1,27 @@
+export enum FilterType {
+  L = 'L',
+  R = 'R',
+  G = 'G',
+  B = 'B',
+  HA = 'HA',
+  OIII = 'OIII',
+  SII = 'SII',
+  DUALBAND = 'DUALBAND',
+  UVIRCUT = 'UVIRCUT',
+  LIGHTPOLLUTION = 'LIGHTPOLLUTION',
+  OTHER = 'OTHER',
+}
+
+export const filterTypeNames: Record<FilterType, string> = {
+  [FilterType.L]: 'Luminance',
+  [FilterType.R]: 'Red',
+  [FilterType.G]: 'Green',
+  [FilterType.B]: 'Blue',
+  [FilterType.HA]: 'H-alpha',
+  [FilterType.OIII]: 'OIII',
+  [FilterType.SII]: 'SII',
+  [FilterType.DUALBAND]: 'Dual-band',
+  [FilterType.UVIRCUT]: 'UV/IR-cut',
+  [FilterType.LIGHTPOLLUTION]: 'Light pollution',
+  [FilterType.OTHER]: 'Other',
+};
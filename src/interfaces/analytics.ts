@@ -1,3 +1,94 @@
+import { UUID } from 'crypto';
+import { EquipmentType } from '@/enums/equipmentType';
+import { MaintenanceEntry } from '@/interfaces/equipment';
+
 export interface Analytics {
   total_imaging_sessions: number;
+  equipment_chart: EquipmentChart;
+  optical_trains: OpticalTrainUsage[];
+  equipment_usage: EquipmentUsage[];
+  sites: SiteUsage[];
+  maintenance_timeline: MaintenanceEvent[];
+  altitude_quality: AltitudeQuality;
+}
+
+export interface OpticalTrainUsage {
+  id: UUID;
+  name: string;
+  sessions: number;
+  nights: number;
+  light_frames: number;
+  integration_time: number;
+}
+
+export interface MaintenanceEvent {
+  equipment_id: UUID;
+  equipment_type: EquipmentType;
+  equipment_name: string;
+  entry: MaintenanceEntry;
+}
+
+export interface EquipmentChart {
+  telescopes: PieChartData;
+  cameras: PieChartData;
+  mounts: PieChartData;
+}
+
+export interface PieChartData {
+  slices: PieSlice[];
+}
+
+export interface PieSlice {
+  id: UUID;
+  label: string;
+  value: number;
+}
+
+export interface EquipmentUsage {
+  id: UUID;
+  equipment_type: EquipmentType;
+  name: string;
+  nights: number;
+  light_frames: number;
+  integration_time: number;
+  targets: string[];
+  first_use: string | null;
+  last_use: string | null;
+  average_hfr: number | null;
+  average_star_count: number | null;
+  acceptance_rate: number | null;
+}
+
+export interface SiteUsage {
+  id: UUID;
+  name: string;
+  sessions: number;
+  nights: number;
+  integration_time: number;
+}
+
+export interface AltitudeQuality {
+  bins: AltitudeBin[];
+  degradation_altitude: number | null;
+}
+
+export interface AltitudeBin {
+  altitude: number;
+  subs: number;
+  relative_hfr: number | null;
+  relative_star_count: number | null;
+  rejection_rate: number;
+}
+
+export interface TrackPoint {
+  light_frame_id: UUID;
+  filter: string;
+  timestamp: string;
+  altitude: number;
+  azimuth: number;
+  airmass: number | null;
+  hour_angle: number;
+  hfr: number | null;
+  star_count: number | null;
+  rejected: boolean;
 }
@@ -1,10 +1,40 @@
 import { UUID } from 'crypto';
 import { EquipmentType } from '@/enums/equipmentType';
+import { FilterType } from '@/enums/filterType';
 
 export interface EquipmentItem {
   id: UUID;
   brand: string;
   name: string;
+  aliases: HeaderAlias[];
+  lifecycle: Lifecycle;
+}
+
+export type EquipmentStatus = 'ACTIVE' | 'RETIRED' | 'SOLD';
+
+export interface Lifecycle {
+  purchase_date: string | null;
+  price: number | null;
+  serial_number: string;
+  status: EquipmentStatus;
+  status_date: string | null;
+  maintenance: MaintenanceEntry[];
+}
+
+export type MaintenanceKind = 'COLLIMATION' | 'CLEANING' | 'FIRMWARE' | 'TILT' | 'OTHER';
+
+export interface MaintenanceEntry {
+  id: UUID;
+  date: string;
+  kind: MaintenanceKind;
+  notes: string;
+}
+
+export type MatchRule = 'EXACT' | 'PREFIX' | 'CONTAINS';
+
+export interface HeaderAlias {
+  pattern: string;
+  rule: MatchRule;
 }
 
 export interface Telescope extends EquipmentItem {
@@ -16,33 +46,115 @@ export interface Camera extends EquipmentItem {
   chip_size: string;
   mega_pixel: number;
   rgb: boolean;
+  sensor: Sensor;
+}
+
+export type BayerPattern = 'RGGB' | 'BGGR' | 'GRBG' | 'GBRG';
+
+export interface GainValue {
+  gain: number;
+  value: number;
+}
+
+export interface Sensor {
+  pixel_size: number | null;
+  resolution: [number, number] | null;
+  size: [number, number] | null;
+  bayer_pattern: BayerPattern | null;
+  bit_depth: number | null;
+  cooled: boolean;
+  full_well: GainValue[];
+  read_noise: GainValue[];
 }
 
 export interface Mount extends EquipmentItem {}
 
 export interface Filter extends EquipmentItem {
-  filter_type: string;
+  filter_type: FilterType;
+  // the free-form type of older logs that names no known type
+  filter_type_label?: string | null;
+  central_wavelength: number | null;
+  bandwidth: number | null;
+  size: string | null;
+}
+
+export interface FilterAlias {
+  alias: string;
+  filter_type: FilterType;
 }
 
 export interface Flattener extends EquipmentItem {
   factor: number;
 }
 
-export const getEquipmentType = (
-  item: EquipmentItem
-): EquipmentType => {
-  if ("focal_length" in item) {
-    return EquipmentType.TELESCOPE;
-  }
-  if ("chip_size" in item) {
-    return EquipmentType.CAMERA;
-  }
-  if ("filter_type" in item) {
-    return EquipmentType.FILTER;
-  }
-  if ("factor" in item) {
-    return EquipmentType.FLATTENER;
-  }
-
-  return EquipmentType.MOUNT;
-};
+export interface OpticalTrain {
+  id: UUID;
+  name: string;
+  telescope_id: UUID;
+  flattener_id: UUID;
+  camera_id: UUID;
+  mount_id: UUID;
+  filter_wheel_id: UUID;
+  focuser_id: UUID;
+  guide_scope_id: UUID;
+  guide_camera_id: UUID;
+  off_axis_guider_id: UUID;
+  rotator_id: UUID;
+  dew_heater_id: UUID;
+  field_rotator_id: UUID;
+  back_focus: number | null;
+  notes: string;
+}
+
+export interface FilterSlot {
+  position: number;
+  filter_id: UUID;
+}
+
+export interface FilterWheel extends EquipmentItem {
+  slots: FilterSlot[];
+}
+
+export interface Focuser extends EquipmentItem {
+  max_position: number | null;
+  step_size: number | null;
+}
+
+export interface GuideScope extends EquipmentItem {
+  focal_length: number;
+  aperture: number;
+}
+
+export interface GuideCamera extends EquipmentItem {
+  pixel_size: number | null;
+}
+
+export interface OffAxisGuider extends EquipmentItem {
+  prism_size: number | null;
+}
+
+export interface Rotator extends EquipmentItem {
+  step_size: number | null;
+}
+
+export interface DewHeater extends EquipmentItem {
+  channels: number | null;
+}
+
+export interface FieldRotator extends EquipmentItem {
+  max_rotation: number | null;
+}
+
+export interface DuplicateCandidate {
+  id: UUID;
+  name: string;
+  similarity: number;
+}
+
+export interface CatalogEntry {
+  key: string;
+  equipment_type: EquipmentType;
+  brand: string;
+  name: string;
+  specs: Record<string, unknown>;
+}
@@ -0,0 +1,34 @@
+import { ObjectType } from '@/interfaces/targets';
+
+export interface TimeWindow {
+  start: string;
+  end: string;
+}
+
+export interface NightPlan {
+  date: string;
+  start: string;
+  step_minutes: number;
+  dusk: string | null;
+  dawn: string | null;
+  dark_windows: TimeWindow[];
+  moon_illumination: number;
+  moon_altitudes: number[];
+  targets: TargetPlan[];
+}
+
+export interface TargetPlan {
+  object_id: string;
+  name: string;
+  object_type: ObjectType;
+  wishlist: boolean;
+  altitudes: number[];
+  max_altitude: number;
+  transit: string;
+  dark_hours: number;
+  moon_free_hours: number;
+  best_window: TimeWindow | null;
+  moon_separation: number;
+  framing: number;
+  score: number;
+}
@@ -0,0 +1,34 @@
+import { UUID } from 'crypto';
+import { FilterType } from '@/enums/filterType';
+
+export interface ImagingProject {
+  id: UUID;
+  name: string;
+  target: string;
+  optical_train_id: UUID | null;
+  goals: IntegrationGoal[];
+  session_ids: UUID[];
+  auto_assign: boolean;
+  notes: string;
+}
+
+export interface IntegrationGoal {
+  filter_type: FilterType;
+  hours: number;
+}
+
+export interface ProjectProgress {
+  project: ImagingProject;
+  goals: GoalProgress[];
+  integration_time: number;
+  nights: number;
+  nights_remaining: number | null;
+}
+
+export interface GoalProgress {
+  filter_type: FilterType;
+  goal: number;
+  integration_time: number;
+  integrated_subs: number;
+  percent: number;
+}
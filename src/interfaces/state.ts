@@ -2,12 +2,24 @@ import { UUID } from 'crypto';
 import { CalibrationType } from '@/enums/calibrationType';
 import {
   Camera,
+  DewHeater,
+  FieldRotator,
   Filter,
+  FilterAlias,
+  FilterWheel,
   Flattener,
+  Focuser,
+  GuideCamera,
+  GuideScope,
   Mount,
+  OffAxisGuider,
+  OpticalTrain,
+  Rotator,
   Telescope,
 } from '@/interfaces/equipment';
+import { EquipmentType } from '@/enums/equipmentType';
 import { Analytics } from '@/interfaces/analytics';
+import { ProjectProgress } from '@/interfaces/projects';
 
 export interface AppState {
   preferences: Preferences;
@@ -15,11 +27,18 @@ export interface AppState {
   equipment_list: EquipmentList;
   image_list: Image[];
   analytics: Analytics;
+  unclassified_frames: string[];
+  ingest_proposals: IngestGroup[];
+  ambiguous_matches: AmbiguousMatch[];
+  sites: ObservingSite[];
+  wishlist: string[];
+  projects: ProjectProgress[];
 }
 
 export interface Preferences {
   storage: Storage;
   user: User;
+  ingest: IngestRules;
 }
 
 interface Storage {
@@ -30,6 +49,13 @@ interface Storage {
 
 interface User {
   weather_api_key: string;
+  weather_file: string;
+}
+
+interface IngestRules {
+  mode: 'OFF' | 'PROPOSE' | 'AUTO';
+  settle_seconds: number;
+  min_light_subs: number;
 }
 
 interface TableData {
@@ -39,11 +65,15 @@ interface TableData {
 
 export interface Session {
   id: UUID;
+  session_id: UUID;
   date: string;
+  legacy_date: string | null;
   target: string;
   sub_length: number;
   total_subs: number;
   integrated_subs: number;
+  legacy_integrated_subs: boolean;
+  integration_time: number;
   filter: string;
   gain: number;
   offset: number;
@@ -51,11 +81,43 @@ export interface Session {
   outside_temp: number;
   average_seeing: number;
   average_cloud_cover: number;
+  average_moon: number;
+  moon_altitude: number | null;
+  moon_separation: number | null;
+  min_altitude: number | null;
+  max_altitude: number | null;
+  mean_airmass: number | null;
+  meridian_flip: string | null;
+  humidity: number | null;
+  dew_point: number | null;
+  wind_speed: number | null;
   telescope: string;
   flattener: string;
   mount: string;
   camera: string;
+  accessories: string;
   notes: string;
+  optics: OpticsMetrics | null;
+  optical_train: string;
+  site: string;
+  train_overrides: EquipmentType[];
+}
+
+export interface OpticsMetrics {
+  effective_focal_length: number;
+  focal_ratio: number | null;
+  pixel_size: number | null;
+  image_scale: number | null;
+  field_of_view: [number, number] | null;
+  sampling: 'UNDERSAMPLED' | 'OPTIMAL' | 'OVERSAMPLED' | null;
+}
+
+export interface SensorMetrics {
+  pixel_size: number | null;
+  sensor_size: [number, number] | null;
+  unity_gain: number | null;
+  read_noise: number | null;
+  full_well: number | null;
 }
 
 export interface CalibrationFrame {
@@ -68,12 +130,51 @@ export interface CalibrationFrame {
   total_subs: number;
 }
 
-interface EquipmentList {
+export interface EquipmentList {
   camera_list: Camera[];
   telescope_list: Telescope[];
   mount_list: Mount[];
   filter_list: Filter[];
   flattener_list: Flattener[];
+  filter_wheel_list: FilterWheel[];
+  focuser_list: Focuser[];
+  guide_scope_list: GuideScope[];
+  guide_camera_list: GuideCamera[];
+  off_axis_guider_list: OffAxisGuider[];
+  rotator_list: Rotator[];
+  dew_heater_list: DewHeater[];
+  field_rotator_list: FieldRotator[];
+  optical_train_list: OpticalTrain[];
+  filter_aliases: FilterAlias[];
+}
+
+export interface IngestGroup {
+  id: UUID;
+  frame_type: 'LIGHT' | 'DARK' | 'BIAS' | 'FLAT';
+  folder: string;
+  frames: string[];
+  date: string | null;
+  gain: number | null;
+  sub_length: number | null;
+}
+
+export interface AmbiguousMatch {
+  id: UUID;
+  light_frame_id: UUID;
+  equipment_type: EquipmentType;
+  header: string;
+  candidates: UUID[];
+}
+
+export interface ObservingSite {
+  id: UUID;
+  name: string;
+  latitude: number;
+  longitude: number;
+  elevation: number | null;
+  timezone: string;
+  bortle: number | null;
+  sqm: number | null;
 }
 
 export interface Image {
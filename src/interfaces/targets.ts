@@ -0,0 +1,22 @@
+export type ObjectType =
+  | 'GALAXY'
+  | 'GALAXYCLUSTER'
+  | 'EMISSIONNEBULA'
+  | 'REFLECTIONNEBULA'
+  | 'PLANETARYNEBULA'
+  | 'SUPERNOVAREMNANT'
+  | 'DARKNEBULA'
+  | 'OPENCLUSTER'
+  | 'GLOBULARCLUSTER'
+  | 'OTHER';
+
+export interface DeepSkyObject {
+  id: string;
+  name: string;
+  aliases: string[];
+  object_type: ObjectType;
+  ra: number;
+  dec: number;
+  size: [number, number] | null;
+  magnitude: number | null;
+}
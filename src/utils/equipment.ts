@@ -1,5 +1,38 @@
 import { EquipmentItem } from '@/interfaces/equipment';
+import { EquipmentList } from '@/interfaces/state';
+import { EquipmentType } from '@/enums/equipmentType';
+
+export const equipmentListKeys = {
+  [EquipmentType.TELESCOPE]: 'telescope_list',
+  [EquipmentType.CAMERA]: 'camera_list',
+  [EquipmentType.MOUNT]: 'mount_list',
+  [EquipmentType.FILTER]: 'filter_list',
+  [EquipmentType.FLATTENER]: 'flattener_list',
+  [EquipmentType.FILTERWHEEL]: 'filter_wheel_list',
+  [EquipmentType.FOCUSER]: 'focuser_list',
+  [EquipmentType.GUIDESCOPE]: 'guide_scope_list',
+  [EquipmentType.GUIDECAMERA]: 'guide_camera_list',
+  [EquipmentType.OFFAXISGUIDER]: 'off_axis_guider_list',
+  [EquipmentType.ROTATOR]: 'rotator_list',
+  [EquipmentType.DEWHEATER]: 'dew_heater_list',
+  [EquipmentType.FIELDROTATOR]: 'field_rotator_list',
+} as const;
 
 export function getViewName(item: EquipmentItem): string {
   return item.brand + ' ' + item.name;
 }
+
+// retired and sold items only show up where they were already used
+export function isActive(item: EquipmentItem): boolean {
+  return (item.lifecycle?.status ?? 'ACTIVE') === 'ACTIVE';
+}
+
+// items don't carry their type, so look up the list they are in
+export function getEquipmentType(
+  item: EquipmentItem,
+  equipmentList: EquipmentList
+): EquipmentType | undefined {
+  return Object.values(EquipmentType).find((type) =>
+    (equipmentList[equipmentListKeys[type]] as EquipmentItem[]).some((i) => i.id === item.id)
+  );
+}